@@ -1,4 +1,5 @@
 //! Handle events of a user interface.
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
@@ -11,10 +12,14 @@ use crate::window;
 ///
 /// [open an issue]: https://github.com/iced-rs/iced/issues
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
 
+    /// An input method event
+    InputMethod(input_method::Event),
+
     /// A mouse event
     Mouse(mouse::Event),
 
@@ -30,6 +35,7 @@ pub enum Event {
 
 /// A platform specific event
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlatformSpecific {
     /// A MacOS specific event
     MacOS(MacOS),
@@ -37,6 +43,7 @@ pub enum PlatformSpecific {
 
 /// Describes an event specific to MacOS
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MacOS {
     /// Triggered when the app receives an URL from the system
     ///
@@ -109,3 +109,18 @@ pub enum Style {
     Italic,
     Oblique,
 }
+
+/// The description of a font face available in a font system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Face {
+    /// The name of the family of the [`Face`].
+    pub family: String,
+    /// The [`Weight`] of the [`Face`].
+    pub weight: Weight,
+    /// The [`Stretch`] of the [`Face`].
+    pub stretch: Stretch,
+    /// The [`Style`] of the [`Face`].
+    pub style: Style,
+    /// Whether all the glyphs of the [`Face`] have the same width.
+    pub is_monospace: bool,
+}
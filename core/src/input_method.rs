@@ -0,0 +1,64 @@
+//! Compose text with input methods, like the ones used for CJK languages.
+use crate::Rectangle;
+
+use std::ops::Range;
+
+/// An input method event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// The input method was enabled and text can be composed.
+    Opened,
+
+    /// The pre-edit text being composed changed.
+    ///
+    /// The range is the byte range of the cursor in the text, if any. An
+    /// empty text means the composition was cleared.
+    Preedit(String, Option<Range<usize>>),
+
+    /// The composed text was committed and should be inserted.
+    Commit(String),
+
+    /// The input method was disabled.
+    Closed,
+}
+
+/// The input method requested by a widget.
+///
+/// Widgets request an input method with
+/// [`Shell::request_input_method`](crate::Shell::request_input_method)
+/// while they are focused.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InputMethod {
+    /// Input methods are not allowed.
+    #[default]
+    Disabled,
+
+    /// Input methods are allowed.
+    Enabled {
+        /// The area of the cursor where text is being composed, so the
+        /// input method can place its candidate window next to it.
+        cursor: Rectangle,
+    },
+}
+
+impl InputMethod {
+    /// Merges the [`InputMethod`] with another one.
+    ///
+    /// The first enabled request takes precedence.
+    pub fn merge(&mut self, other: &Self) {
+        if let InputMethod::Disabled = self {
+            *self = *other;
+        }
+    }
+}
+
+/// The text being composed by an input method, before it is committed.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Preedit {
+    /// The text being composed.
+    pub content: String,
+
+    /// The byte range of the cursor in the text, if any.
+    pub cursor: Option<Range<usize>>,
+}
@@ -1,5 +1,6 @@
 /// The location of a key on the keyboard.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Location {
     /// The standard group of keys on the keyboard.
     Standard,
@@ -53,6 +53,17 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns the [`Length`] sizing strategy of the [`Node`], if it was
+    /// recorded during layout.
+    pub fn sizing(&self) -> Option<Size<Length>> {
+        self.node.sizing()
+    }
+
+    /// Returns the [`Padding`] the [`Node`] applied to its children.
+    pub fn padding(&self) -> Padding {
+        self.node.padding()
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl DoubleEndedIterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
@@ -108,6 +119,7 @@ pub fn atomic(
     let height = height.into();
 
     Node::new(limits.resolve(width, height, Size::ZERO))
+        .with_sizing(width, height)
 }
 
 /// Computes the resulting [`Node`] that fits the [`Limits`] given
@@ -126,6 +138,7 @@ pub fn sized(
     let intrinsic_size = f(&limits);
 
     Node::new(limits.resolve(width, height, intrinsic_size))
+        .with_sizing(width, height)
 }
 
 /// Computes the resulting [`Node`] that fits the [`Limits`] given
@@ -147,6 +160,7 @@ pub fn contained(
         limits.resolve(width, height, content.size()),
         vec![content],
     )
+    .with_sizing(width, height)
 }
 
 /// Computes the [`Node`] that fits the [`Limits`] given some width, height, and
@@ -187,4 +201,6 @@ pub fn positioned(
         size.expand(padding),
         vec![position(content.move_to((padding.left, padding.top)), size)],
     )
+    .with_sizing(width, height)
+    .with_padding(padding)
 }
@@ -177,6 +177,15 @@ where
     let pad = axis.pack(padding.left, padding.top);
     let mut main = pad.0;
 
+    #[cfg(feature = "debug")]
+    for (node, child) in nodes.iter_mut().zip(items) {
+        if node.sizing().is_none() {
+            let size = child.as_widget().size();
+
+            *node = std::mem::take(node).with_sizing(size.width, size.height);
+        }
+    }
+
     for (i, node) in nodes.iter_mut().enumerate() {
         if i > 0 {
             main += spacing;
@@ -216,4 +225,6 @@ where
     );
 
     Node::with_children(size.expand(padding), nodes)
+        .with_sizing(width, height)
+        .with_padding(padding)
 }
@@ -1,10 +1,14 @@
-use crate::{Alignment, Padding, Point, Rectangle, Size, Vector};
+use crate::{Alignment, Length, Padding, Point, Rectangle, Size, Vector};
 
 /// The bounds of an element and its children.
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    #[cfg(feature = "debug")]
+    sizing: Option<Size<Length>>,
+    #[cfg(feature = "debug")]
+    padding: Padding,
 }
 
 impl Node {
@@ -23,6 +27,10 @@ impl Node {
                 height: size.height,
             },
             children,
+            #[cfg(feature = "debug")]
+            sizing: None,
+            #[cfg(feature = "debug")]
+            padding: Padding::ZERO,
         }
     }
 
@@ -32,6 +40,35 @@ impl Node {
             child.bounds.size().expand(padding),
             vec![child.move_to(Point::new(padding.left, padding.top))],
         )
+        .with_padding(padding)
+    }
+
+    /// Records the [`Length`] sizing strategy the [`Node`] was laid out with.
+    ///
+    /// This information is only used for debugging purposes and it is
+    /// discarded unless the `debug` feature is enabled.
+    #[cfg_attr(not(feature = "debug"), allow(unused_mut, unused_variables))]
+    pub fn with_sizing(mut self, width: Length, height: Length) -> Self {
+        #[cfg(feature = "debug")]
+        {
+            self.sizing = Some(Size::new(width, height));
+        }
+
+        self
+    }
+
+    /// Records the [`Padding`] the [`Node`] applied to its children.
+    ///
+    /// This information is only used for debugging purposes and it is
+    /// discarded unless the `debug` feature is enabled.
+    #[cfg_attr(not(feature = "debug"), allow(unused_mut, unused_variables))]
+    pub fn with_padding(mut self, padding: Padding) -> Self {
+        #[cfg(feature = "debug")]
+        {
+            self.padding = padding;
+        }
+
+        self
     }
 
     /// Returns the [`Size`] of the [`Node`].
@@ -49,6 +86,37 @@ impl Node {
         &self.children
     }
 
+    /// Returns the [`Length`] sizing strategy the [`Node`] was laid out with,
+    /// if it was recorded.
+    ///
+    /// It is always `None` unless the `debug` feature is enabled.
+    pub fn sizing(&self) -> Option<Size<Length>> {
+        #[cfg(feature = "debug")]
+        {
+            self.sizing
+        }
+
+        #[cfg(not(feature = "debug"))]
+        {
+            None
+        }
+    }
+
+    /// Returns the [`Padding`] the [`Node`] applied to its children.
+    ///
+    /// It is always [`Padding::ZERO`] unless the `debug` feature is enabled.
+    pub fn padding(&self) -> Padding {
+        #[cfg(feature = "debug")]
+        {
+            self.padding
+        }
+
+        #[cfg(not(feature = "debug"))]
+        {
+            Padding::ZERO
+        }
+    }
+
     /// Aligns the [`Node`] in the given space.
     pub fn align(
         mut self,
@@ -9,6 +9,7 @@ use super::Button;
 ///
 /// [open an issue]: https://github.com/iced-rs/iced/issues
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// The mouse cursor entered the window.
     CursorEntered,
@@ -37,6 +38,7 @@ pub enum Event {
 
 /// A scroll movement.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScrollDelta {
     /// A line-based scroll movement
     Lines {
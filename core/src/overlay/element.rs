@@ -170,8 +170,9 @@ where
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
                 id: Option<&widget::Id>,
+                bounds: Rectangle,
             ) {
-                self.operation.focusable(state, id);
+                self.operation.focusable(state, id, bounds);
             }
 
             fn scrollable(
@@ -188,8 +189,18 @@ where
                 &mut self,
                 state: &mut dyn widget::operation::TextInput,
                 id: Option<&widget::Id>,
+                bounds: Rectangle,
+            ) {
+                self.operation.text_input(state, id, bounds);
+            }
+
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
             ) {
-                self.operation.text_input(state, id);
+                self.operation.text(id, bounds, text);
             }
 
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
@@ -3,7 +3,8 @@
 mod null;
 
 use crate::{
-    Background, Border, Color, Rectangle, Shadow, Size, Transformation, Vector,
+    Background, Border, Color, Font, Pixels, Rectangle, Shadow, Size,
+    Transformation, Vector,
 };
 
 /// A component that can be used by widgets to draw themselves on a screen.
@@ -63,6 +64,27 @@ pub trait Renderer {
     fn clear(&mut self);
 }
 
+/// A [`Renderer`] that can be created without a window.
+///
+/// This is useful to run a user interface in headless mode; for instance,
+/// in automated tests.
+pub trait Headless {
+    /// Creates a new [`Headless`] renderer with the given default font and
+    /// text size.
+    fn new(default_font: Font, default_text_size: Pixels) -> Self;
+
+    /// Draws the recorded primitives offscreen and returns the resulting
+    /// pixels of the given physical `size`.
+    ///
+    /// The returned bytes are ordered as `RGBA` in the `sRGB` color space.
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8>;
+}
+
 /// A polygon with four sides.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Quad {
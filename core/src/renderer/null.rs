@@ -8,6 +8,8 @@ use crate::{
     Transformation,
 };
 
+use std::ops::Range;
+
 impl Renderer for () {
     fn start_layer(&mut self, _bounds: Rectangle) {}
 
@@ -27,6 +29,19 @@ impl Renderer for () {
     }
 }
 
+impl renderer::Headless for () {
+    fn new(_default_font: Font, _default_text_size: Pixels) -> Self {}
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        _scale_factor: f32,
+        _background_color: Color,
+    ) -> Vec<u8> {
+        vec![0; size.width as usize * size.height as usize * 4]
+    }
+}
+
 impl text::Renderer for () {
     type Font = Font;
     type Paragraph = ();
@@ -77,6 +92,11 @@ impl text::Paragraph for () {
 
     fn with_text(_text: Text<&str>) -> Self {}
 
+    fn with_spans<Link>(
+        _text: Text<&[text::Span<'_, Link, Self::Font>], Self::Font>,
+    ) -> Self {
+    }
+
     fn resize(&mut self, _new_bounds: Size) {}
 
     fn compare(&self, _text: Text<&str>) -> text::Difference {
@@ -102,6 +122,18 @@ impl text::Paragraph for () {
     fn hit_test(&self, _point: Point) -> Option<text::Hit> {
         None
     }
+
+    fn hit_span(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
+        vec![]
+    }
+
+    fn range_bounds(&self, _range: Range<usize>) -> Vec<Rectangle> {
+        vec![]
+    }
 }
 
 impl text::Editor for () {
@@ -113,6 +145,10 @@ impl text::Editor for () {
         text::editor::Cursor::Caret(Point::ORIGIN)
     }
 
+    fn cursors(&self) -> Vec<text::editor::Cursor> {
+        vec![self.cursor()]
+    }
+
     fn cursor_position(&self) -> (usize, usize) {
         (0, 0)
     }
@@ -121,6 +157,10 @@ impl text::Editor for () {
         None
     }
 
+    fn selection_range(&self) -> Option<((usize, usize), (usize, usize))> {
+        None
+    }
+
     fn line(&self, _index: usize) -> Option<&str> {
         None
     }
@@ -129,8 +169,20 @@ impl text::Editor for () {
         0
     }
 
+    fn visible_lines(&self) -> Vec<text::editor::LineLayout> {
+        Vec::new()
+    }
+
     fn perform(&mut self, _action: text::editor::Action) {}
 
+    fn can_undo(&self) -> bool {
+        false
+    }
+
+    fn can_redo(&self) -> bool {
+        false
+    }
+
     fn bounds(&self) -> Size {
         Size::ZERO
     }
@@ -145,6 +197,7 @@ impl text::Editor for () {
         _new_font: Self::Font,
         _new_size: Pixels,
         _new_line_height: text::LineHeight,
+        _new_wrapping: text::Wrapping,
         _new_highlighter: &mut impl text::Highlighter,
     ) {
     }
@@ -1,4 +1,5 @@
 use crate::window;
+use crate::InputMethod;
 
 /// A connection to the state of a shell.
 ///
@@ -10,6 +11,7 @@ use crate::window;
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     redraw_request: Option<window::RedrawRequest>,
+    input_method: InputMethod,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
 }
@@ -20,6 +22,7 @@ impl<'a, Message> Shell<'a, Message> {
         Self {
             messages,
             redraw_request: None,
+            input_method: InputMethod::Disabled,
             is_layout_invalid: false,
             are_widgets_invalid: false,
         }
@@ -53,6 +56,19 @@ impl<'a, Message> Shell<'a, Message> {
         self.redraw_request
     }
 
+    /// Requests the given [`InputMethod`] to be enabled.
+    ///
+    /// Focused widgets that accept text should request an input method
+    /// every time they are redrawn. Otherwise, input methods are disabled.
+    pub fn request_input_method(&mut self, input_method: &InputMethod) {
+        self.input_method.merge(input_method);
+    }
+
+    /// Returns the [`InputMethod`] requested, if any.
+    pub fn input_method(&self) -> &InputMethod {
+        &self.input_method
+    }
+
     /// Returns whether the current layout is invalid or not.
     pub fn is_layout_invalid(&self) -> bool {
         self.is_layout_invalid
@@ -99,6 +115,8 @@ impl<'a, Message> Shell<'a, Message> {
             self.request_redraw(at);
         }
 
+        self.request_input_method(&other.input_method);
+
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
@@ -106,3 +124,48 @@ impl<'a, Message> Shell<'a, Message> {
             self.are_widgets_invalid || other.are_widgets_invalid;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rectangle;
+
+    fn enabled(x: f32) -> InputMethod {
+        InputMethod::Enabled {
+            cursor: Rectangle {
+                x,
+                y: 0.0,
+                width: 1.0,
+                height: 10.0,
+            },
+        }
+    }
+
+    #[test]
+    fn it_keeps_the_first_input_method_requested() {
+        let mut messages: Vec<()> = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        shell.request_input_method(&InputMethod::Disabled);
+        assert_eq!(shell.input_method(), &InputMethod::Disabled);
+
+        shell.request_input_method(&enabled(1.0));
+        shell.request_input_method(&enabled(2.0));
+        shell.request_input_method(&InputMethod::Disabled);
+        assert_eq!(shell.input_method(), &enabled(1.0));
+    }
+
+    #[test]
+    fn it_merges_the_input_method_of_other_shells() {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let mut other_messages = vec![1];
+        let mut other = Shell::new(&mut other_messages);
+        other.request_input_method(&enabled(1.0));
+
+        shell.merge(other, |n: i32| n + 1);
+        assert_eq!(shell.input_method(), &enabled(1.0));
+        assert_eq!(messages, [2]);
+    }
+}
@@ -1,6 +1,6 @@
 //! Edit text.
 use crate::text::highlighter::{self, Highlighter};
-use crate::text::LineHeight;
+use crate::text::{LineHeight, Wrapping};
 use crate::{Pixels, Point, Rectangle, Size};
 
 use std::sync::Arc;
@@ -16,6 +16,12 @@ pub trait Editor: Sized + Default {
     /// Returns the current [`Cursor`] of the [`Editor`].
     fn cursor(&self) -> Cursor;
 
+    /// Returns the [`Cursor`]s of all the carets of the [`Editor`].
+    ///
+    /// The first one is the main [`Cursor`], as returned by
+    /// [`Editor::cursor`].
+    fn cursors(&self) -> Vec<Cursor>;
+
     /// Returns the current cursor position of the [`Editor`].
     ///
     /// Line and column, respectively.
@@ -24,15 +30,32 @@ pub trait Editor: Sized + Default {
     /// Returns the current selected text of the [`Editor`].
     fn selection(&self) -> Option<String>;
 
+    /// Returns the start and end positions of the current selection of the
+    /// [`Editor`], if any.
+    ///
+    /// Positions are given as line and column, respectively. The start
+    /// always comes before the end, regardless of where the cursor is.
+    fn selection_range(&self) -> Option<((usize, usize), (usize, usize))>;
+
     /// Returns the text of the given line in the [`Editor`], if it exists.
     fn line(&self, index: usize) -> Option<&str>;
 
     /// Returns the amount of lines in the [`Editor`].
     fn line_count(&self) -> usize;
 
+    /// Returns the [`LineLayout`] of the lines currently visible in the
+    /// [`Editor`], taking wrapping and scrolling into account.
+    fn visible_lines(&self) -> Vec<LineLayout>;
+
     /// Performs an [`Action`] on the [`Editor`].
     fn perform(&mut self, action: Action);
 
+    /// Returns whether the [`Editor`] has edits that can be undone.
+    fn can_undo(&self) -> bool;
+
+    /// Returns whether the [`Editor`] has undone edits that can be redone.
+    fn can_redo(&self) -> bool;
+
     /// Returns the current boundaries of the [`Editor`].
     fn bounds(&self) -> Size;
 
@@ -47,6 +70,7 @@ pub trait Editor: Sized + Default {
         new_font: Self::Font,
         new_size: Pixels,
         new_line_height: LineHeight,
+        new_wrapping: Wrapping,
         new_highlighter: &mut impl Highlighter,
     );
 
@@ -59,34 +83,82 @@ pub trait Editor: Sized + Default {
     );
 }
 
+/// The layout of a line of an [`Editor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineLayout {
+    /// The index of the line.
+    pub index: usize,
+    /// The vertical position of the line, relative to the top of the
+    /// [`Editor`].
+    pub y: f32,
+    /// The height of the line, including all of its wrapped rows.
+    pub height: f32,
+}
+
 /// An interaction with an [`Editor`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Action {
     /// Apply a [`Motion`].
     Move(Motion),
+    /// Move the cursor to the given position, clearing the selection.
+    ///
+    /// The position is given as line and column, respectively, and it is
+    /// clamped like in [`Action::SelectRange`].
+    MoveTo((usize, usize)),
     /// Select text with a given [`Motion`].
     Select(Motion),
     /// Select the word at the current cursor.
     SelectWord,
     /// Select the line at the current cursor.
     SelectLine,
+    /// Select the text between two positions, placing the cursor at the
+    /// `end`.
+    ///
+    /// Positions are given as line and column, respectively. Columns are
+    /// byte offsets in the line and positions out of bounds are clamped.
+    SelectRange {
+        /// The position where the selection starts.
+        start: (usize, usize),
+        /// The position where the selection ends.
+        end: (usize, usize),
+    },
     /// Perform an [`Edit`].
     Edit(Edit),
     /// Click the [`Editor`] at the given [`Point`].
     Click(Point),
     /// Drag the mouse on the [`Editor`] to the given [`Point`].
     Drag(Point),
+    /// Add a caret at the given [`Point`], keeping the current ones.
+    ///
+    /// Motions and edits are applied at every caret.
+    AddCursor(Point),
+    /// Select a rectangular block of text between two [`Point`]s, placing
+    /// a caret at every line.
+    SelectColumn {
+        /// The [`Point`] where the selection starts.
+        from: Point,
+        /// The [`Point`] where the selection ends.
+        to: Point,
+    },
     /// Scroll the [`Editor`] a certain amount of lines.
     Scroll {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Revert the last group of edits, restoring the cursor and selection
+    /// that preceded it.
+    Undo,
+    /// Reapply the last group of edits reverted with [`Action::Undo`].
+    Redo,
 }
 
 impl Action {
     /// Returns whether the [`Action`] is an editing action.
+    ///
+    /// [`Action::Undo`] and [`Action::Redo`] are considered editing
+    /// actions, since they change the text of the [`Editor`].
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(self, Self::Edit(_) | Self::Undo | Self::Redo)
     }
 }
 
@@ -97,12 +169,52 @@ pub enum Edit {
     Insert(char),
     /// Paste the given text.
     Paste(Arc<String>),
-    /// Break the current line.
+    /// Break the current line, keeping the leading whitespace of the
+    /// current line in the new one.
     Enter,
+    /// Break the current line, without any indentation in the new one.
+    LineBreak,
     /// Delete the previous character.
     Backspace,
     /// Delete the next character.
     Delete,
+    /// Indent the selected lines with the given [`Indentation`].
+    ///
+    /// If nothing or only part of a line is selected, the selection is
+    /// replaced by a single level of indentation instead.
+    Indent(Indentation),
+    /// Remove a level of the given [`Indentation`] from the selected lines.
+    Unindent(Indentation),
+    /// Replace the text between two positions with the given text, placing
+    /// the cursor after it.
+    ///
+    /// Positions are clamped like in [`Action::SelectRange`]. Replacing
+    /// with empty text deletes the range.
+    ReplaceRange {
+        /// The position where the replaced range starts.
+        start: (usize, usize),
+        /// The position where the replaced range ends.
+        end: (usize, usize),
+        /// The text that replaces the range.
+        text: Arc<String>,
+    },
+}
+
+/// The kind of whitespace used to indent the lines of an [`Editor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indentation {
+    /// Indent using tab characters.
+    Tabs,
+    /// Indent using the given amount of spaces.
+    ///
+    /// Unindenting also removes leading tab characters.
+    Spaces(usize),
+}
+
+impl Default for Indentation {
+    fn default() -> Self {
+        Self::Spaces(4)
+    }
 }
 
 /// A cursor movement.
@@ -120,7 +232,8 @@ pub enum Motion {
     WordLeft,
     /// Move to the right boundary of a word.
     WordRight,
-    /// Move to the start of the line.
+    /// Move to the first non-whitespace character of the line or, if
+    /// already there, to the start of the line.
     Home,
     /// Move to the end of the line.
     End,
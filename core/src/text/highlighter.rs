@@ -1,4 +1,5 @@
 //! Highlight text.
+use crate::theme::palette;
 use crate::Color;
 
 use std::ops::Range;
@@ -29,6 +30,14 @@ pub trait Highlighter: 'static {
     /// Notifies the [`Highlighter`] that the line at the given index has changed.
     fn change_line(&mut self, line: usize);
 
+    /// Provides the [`Highlighter`] with the lines of the document, starting
+    /// at the [`current_line`](Self::current_line), before some of them are
+    /// highlighted.
+    ///
+    /// Highlighters that need the whole document (e.g. to parse it) can use
+    /// this to keep their own copy in sync. By default, it does nothing.
+    fn sync<'a>(&mut self, _lines: impl Iterator<Item = &'a str>) {}
+
     /// Highlights the given line.
     ///
     /// If a line changed prior to this, the first line provided here will be the
@@ -86,3 +95,34 @@ impl<Font> Default for Format<Font> {
         }
     }
 }
+
+/// The kind of some highlighted text, as determined by its syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// A keyword, like `fn` or `if`.
+    Keyword,
+    /// The name of a type.
+    Type,
+    /// A string literal.
+    String,
+    /// A constant, like a number or a boolean.
+    Constant,
+    /// A comment.
+    Comment,
+    /// Invalid syntax.
+    Error,
+}
+
+impl Kind {
+    /// Returns the color of the [`Kind`] in the given palette.
+    pub fn color(self, palette: &palette::Extended) -> Color {
+        match self {
+            Kind::Keyword => palette.primary.base.color,
+            Kind::Type => palette.primary.strong.color,
+            Kind::String => palette.success.base.color,
+            Kind::Constant => palette.success.strong.color,
+            Kind::Comment => palette.background.base.text.scale_alpha(0.5),
+            Kind::Error => palette.danger.base.color,
+        }
+    }
+}
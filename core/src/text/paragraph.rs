@@ -1,6 +1,8 @@
 use crate::alignment;
-use crate::text::{Difference, Hit, Text};
-use crate::{Point, Size};
+use crate::text::{Difference, Hit, Span, Text};
+use crate::{Point, Rectangle, Size};
+
+use std::ops::Range;
 
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
@@ -10,6 +12,15 @@ pub trait Paragraph: Sized + Default {
     /// Creates a new [`Paragraph`] laid out with the given [`Text`].
     fn with_text(text: Text<&str, Self::Font>) -> Self;
 
+    /// Creates a new [`Paragraph`] laid out with the given [`Span`]s.
+    ///
+    /// The `content` of the [`Text`] is the concatenation of the spans; the
+    /// rest of its attributes are used for the spans that do not override
+    /// them.
+    fn with_spans<Link>(
+        text: Text<&[Span<'_, Link, Self::Font>], Self::Font>,
+    ) -> Self;
+
     /// Lays out the [`Paragraph`] with some new boundaries.
     fn resize(&mut self, new_bounds: Size);
 
@@ -34,6 +45,23 @@ pub trait Paragraph: Sized + Default {
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
+    /// Returns the index of the [`Span`] containing the given point, if any.
+    ///
+    /// The point is relative to the top-left corner of the [`Paragraph`].
+    fn hit_span(&self, point: Point) -> Option<usize>;
+
+    /// Returns the bounds of the [`Span`] with the given index, one for each
+    /// line the [`Span`] occupies.
+    ///
+    /// The bounds are relative to the top-left corner of the [`Paragraph`].
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
+
+    /// Returns the bounds of the text in the given range of byte offsets,
+    /// one for each line the range occupies.
+    ///
+    /// The bounds are relative to the top-left corner of the [`Paragraph`].
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle>;
+
     /// Updates the [`Paragraph`] to match the given [`Text`], if needed.
     fn update(&mut self, text: Text<&str, Self::Font>) {
         match self.compare(text) {
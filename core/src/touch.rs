@@ -4,6 +4,7 @@ use crate::Point;
 /// A touch interaction.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[allow(missing_docs)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A touch interaction was started.
     FingerPressed { id: Finger, position: Point },
@@ -20,4 +21,5 @@ pub enum Event {
 
 /// A unique identifier representing a finger on a touch interaction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Finger(pub u64);
@@ -29,7 +29,13 @@ pub trait Operation<T> {
     );
 
     /// Operates on a widget that can be focused.
-    fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
+    fn focusable(
+        &mut self,
+        _state: &mut dyn Focusable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
 
     /// Operates on a widget that can be scrolled.
     fn scrollable(
@@ -42,7 +48,16 @@ pub trait Operation<T> {
     }
 
     /// Operates on a widget that has text input.
-    fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
+    fn text_input(
+        &mut self,
+        _state: &mut dyn TextInput,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
+
+    /// Operates on a widget that contains some text.
+    fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, _text: &str) {}
 
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
@@ -136,16 +151,27 @@ where
                     &mut self,
                     state: &mut dyn Focusable,
                     id: Option<&Id>,
+                    bounds: Rectangle,
                 ) {
-                    self.operation.focusable(state, id);
+                    self.operation.focusable(state, id, bounds);
                 }
 
                 fn text_input(
                     &mut self,
                     state: &mut dyn TextInput,
                     id: Option<&Id>,
+                    bounds: Rectangle,
+                ) {
+                    self.operation.text_input(state, id, bounds);
+                }
+
+                fn text(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    text: &str,
                 ) {
-                    self.operation.text_input(state, id);
+                    self.operation.text(id, bounds, text);
                 }
 
                 fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
@@ -161,8 +187,13 @@ where
             .container(id, bounds, operate_on_children);
         }
 
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
-            self.operation.focusable(state, id);
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable(state, id, bounds);
         }
 
         fn scrollable(
@@ -175,8 +206,17 @@ where
             self.operation.scrollable(state, id, bounds, translation);
         }
 
-        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
-            self.operation.text_input(state, id);
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.text_input(state, id, bounds);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
         }
 
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
@@ -201,6 +241,73 @@ where
     }
 }
 
+/// Wraps the given [`Operation`] in a black box, hiding its output type.
+///
+/// This allows running an [`Operation`] on a widget tree expecting a
+/// different output type. The result can then be obtained by calling
+/// [`Operation::finish`] on the original [`Operation`].
+pub fn black_box<'a, T, O>(
+    operation: &'a mut dyn Operation<T>,
+) -> impl Operation<O> + 'a
+where
+    T: 'a,
+{
+    struct BlackBox<'a, T> {
+        operation: &'a mut dyn Operation<T>,
+    }
+
+    impl<'a, T, O> Operation<O> for BlackBox<'a, T> {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<O>),
+        ) {
+            self.operation.container(id, bounds, &mut |operation| {
+                operate_on_children(&mut BlackBox { operation });
+            });
+        }
+
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable(state, id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.operation.scrollable(state, id, bounds, translation);
+        }
+
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.text_input(state, id, bounds);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            self.operation.custom(state, id);
+        }
+    }
+
+    BlackBox { operation }
+}
+
 /// Produces an [`Operation`] that applies the given [`Operation`] to the
 /// children of a container with the given [`Id`].
 pub fn scope<T: 'static>(
@@ -0,0 +1,336 @@
+//! Find widgets in a widget tree.
+use crate::widget::operation::{
+    Focusable, Operation, Outcome, Scrollable, TextInput,
+};
+use crate::widget::Id;
+use crate::{Rectangle, Vector};
+
+use std::borrow::Cow;
+
+/// A description of the widgets to look for in a widget tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selector {
+    /// Selects the widgets with the given [`Id`].
+    Id(Id),
+
+    /// Selects the widgets displaying exactly the given text.
+    Text(Cow<'static, str>),
+
+    /// Selects the widgets of the given [`Kind`].
+    Kind(Kind),
+
+    /// Selects the widgets matching `selector` that are descendants of a
+    /// widget matching `ancestor`.
+    Inside {
+        /// The [`Selector`] of the ancestor.
+        ancestor: Box<Selector>,
+
+        /// The [`Selector`] of the descendant.
+        selector: Box<Selector>,
+    },
+}
+
+impl Selector {
+    /// Restricts the [`Selector`] to the descendants of the widgets matching
+    /// the given `ancestor`.
+    pub fn inside(self, ancestor: impl Into<Selector>) -> Self {
+        Self::Inside {
+            ancestor: Box::new(ancestor.into()),
+            selector: Box::new(self),
+        }
+    }
+
+    /// Returns true if the [`Selector`] matches the given [`Target`].
+    ///
+    /// The `ancestors` of the [`Target`] must be ordered from the root of the
+    /// widget tree.
+    pub fn matches(&self, target: &Target, ancestors: &[Target]) -> bool {
+        match self {
+            Self::Id(id) => target.id.as_ref() == Some(id),
+            Self::Text(text) => match &target.state {
+                State::Text { content } => content == text,
+                _ => false,
+            },
+            Self::Kind(kind) => target.kind() == *kind,
+            Self::Inside { ancestor, selector } => {
+                selector.matches(target, ancestors)
+                    && ancestors.iter().enumerate().any(|(i, candidate)| {
+                        ancestor.matches(candidate, &ancestors[..i])
+                    })
+            }
+        }
+    }
+}
+
+impl From<Id> for Selector {
+    fn from(id: Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&'static str> for Selector {
+    fn from(text: &'static str) -> Self {
+        Self::Text(Cow::Borrowed(text))
+    }
+}
+
+impl From<String> for Selector {
+    fn from(text: String) -> Self {
+        Self::Text(Cow::Owned(text))
+    }
+}
+
+impl From<Kind> for Selector {
+    fn from(kind: Kind) -> Self {
+        Self::Kind(kind)
+    }
+}
+
+/// The kind of a widget, as exposed to an [`Operation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// A widget that contains other widgets.
+    Container,
+
+    /// A widget that can be scrolled.
+    Scrollable,
+
+    /// A widget that can be focused.
+    Focusable,
+
+    /// A widget that has text input.
+    TextInput,
+
+    /// A widget that displays some text.
+    Text,
+}
+
+/// A widget found by a [`Selector`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Target {
+    /// The [`Id`] of the widget, if any.
+    pub id: Option<Id>,
+
+    /// The bounds of the widget.
+    ///
+    /// The translation of any ancestor [`Scrollable`] is already applied.
+    pub bounds: Rectangle,
+
+    /// The [`State`] of the widget.
+    pub state: State,
+}
+
+impl Target {
+    /// Returns the [`Kind`] of the [`Target`].
+    pub fn kind(&self) -> Kind {
+        self.state.kind()
+    }
+}
+
+/// The state of a widget found by a [`Selector`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum State {
+    /// A widget that contains other widgets.
+    Container,
+
+    /// A widget that can be scrolled.
+    Scrollable {
+        /// The current scroll translation of the widget.
+        translation: Vector,
+    },
+
+    /// A widget that can be focused.
+    Focusable {
+        /// Whether the widget is currently focused.
+        is_focused: bool,
+    },
+
+    /// A widget that has text input.
+    TextInput,
+
+    /// A widget that displays some text.
+    Text {
+        /// The text displayed by the widget.
+        content: String,
+    },
+}
+
+impl State {
+    /// Returns the [`Kind`] of the widget with this [`State`].
+    pub fn kind(&self) -> Kind {
+        match self {
+            Self::Container => Kind::Container,
+            Self::Scrollable { .. } => Kind::Scrollable,
+            Self::Focusable { .. } => Kind::Focusable,
+            Self::TextInput => Kind::TextInput,
+            Self::Text { .. } => Kind::Text,
+        }
+    }
+}
+
+/// Produces an [`Operation`] that finds the first widget matching the given
+/// [`Selector`].
+pub fn find(selector: impl Into<Selector>) -> impl Operation<Option<Target>> {
+    Query::new(selector.into(), Some(1), |targets| targets.first().cloned())
+}
+
+/// Produces an [`Operation`] that finds all the widgets matching the given
+/// [`Selector`], in tree order.
+pub fn find_all(selector: impl Into<Selector>) -> impl Operation<Vec<Target>> {
+    Query::new(selector.into(), None, <[Target]>::to_vec)
+}
+
+struct Query<T> {
+    selector: Selector,
+    limit: Option<usize>,
+    output: fn(&[Target]) -> T,
+    ancestors: Vec<Target>,
+    translations: Vec<Vector>,
+    pending_translation: Option<Vector>,
+    matches: Vec<Target>,
+}
+
+impl<T> Query<T> {
+    fn new(
+        selector: Selector,
+        limit: Option<usize>,
+        output: fn(&[Target]) -> T,
+    ) -> Self {
+        Self {
+            selector,
+            limit,
+            output,
+            ancestors: Vec::new(),
+            translations: Vec::new(),
+            pending_translation: None,
+            matches: Vec::new(),
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.limit.is_some_and(|limit| self.matches.len() >= limit)
+    }
+
+    fn target(
+        &self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        state: State,
+    ) -> Target {
+        let translation = self
+            .translations
+            .iter()
+            .fold(Vector::ZERO, |total, translation| total + *translation);
+
+        Target {
+            id: id.cloned(),
+            bounds: bounds - translation,
+            state,
+        }
+    }
+
+    fn visit(&mut self, target: &Target) {
+        if self.is_done() || !self.selector.matches(target, &self.ancestors) {
+            return;
+        }
+
+        // Some widgets expose themselves through multiple hooks
+        // (e.g. a text input is both focusable and has text input).
+        let is_duplicate = self.matches.last().is_some_and(|last| {
+            target.id.is_some()
+                && last.id == target.id
+                && last.bounds == target.bounds
+        });
+
+        if !is_duplicate {
+            self.matches.push(target.clone());
+        }
+    }
+}
+
+impl<T> Operation<T> for Query<T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.is_done() {
+            return;
+        }
+
+        let target = self.target(id, bounds, State::Container);
+        self.visit(&target);
+
+        let translation = self.pending_translation.take();
+
+        if let Some(translation) = translation {
+            self.translations.push(translation);
+        }
+
+        self.ancestors.push(target);
+        operate_on_children(self);
+        let _ = self.ancestors.pop();
+
+        if translation.is_some() {
+            let _ = self.translations.pop();
+        }
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn Focusable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        let target = self.target(
+            id,
+            bounds,
+            State::Focusable {
+                is_focused: state.is_focused(),
+            },
+        );
+
+        self.visit(&target);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        let target = self.target(id, bounds, State::Scrollable { translation });
+
+        self.visit(&target);
+        self.pending_translation = Some(translation);
+    }
+
+    fn text_input(
+        &mut self,
+        _state: &mut dyn TextInput,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        let target = self.target(id, bounds, State::TextInput);
+
+        self.visit(&target);
+    }
+
+    fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+        let target = self.target(
+            id,
+            bounds,
+            State::Text {
+                content: text.to_owned(),
+            },
+        );
+
+        self.visit(&target);
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        Outcome::Some((self.output)(&self.matches))
+    }
+}
@@ -5,6 +5,7 @@ use std::path::PathBuf;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A window was opened.
     Opened {
@@ -41,6 +42,7 @@ pub enum Event {
     /// A window redraw was requested.
     ///
     /// The [`Instant`] contains the current time.
+    #[cfg_attr(feature = "serde", serde(skip))]
     RedrawRequested(Instant),
 
     /// The user has requested for the window to close.
@@ -6,6 +6,7 @@ use std::sync::atomic::{self, AtomicU64};
 /// The id of the window.
 ///
 /// Internally Iced reserves `window::Id::MAIN` for the first window spawned.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Id(u64);
 
 static COUNT: AtomicU64 = AtomicU64::new(1);
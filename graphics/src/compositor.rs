@@ -1,5 +1,6 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
+use crate::core::font;
 use crate::core::Color;
 use crate::futures::{MaybeSend, MaybeSync};
 use crate::{Error, Settings, Viewport};
@@ -9,6 +10,8 @@ use thiserror::Error;
 
 use std::borrow::Cow;
 use std::future::Future;
+use std::io;
+use std::path::Path;
 
 /// A graphics compositor that can draw to windows.
 pub trait Compositor: Sized {
@@ -70,6 +73,22 @@ pub trait Compositor: Sized {
             .load_font(font);
     }
 
+    /// Loads a font from the file at the given path.
+    fn load_font_file(&mut self, path: &Path) -> io::Result<()> {
+        crate::text::font_system()
+            .write()
+            .expect("Write to font system")
+            .load_font_file(path)
+    }
+
+    /// Returns the font faces available to the [`Compositor`].
+    fn font_faces(&self) -> Vec<font::Face> {
+        crate::text::font_system()
+            .read()
+            .expect("Read font system")
+            .faces()
+    }
+
     /// Presents the [`Renderer`] primitives to the next frame of the given [`Surface`].
     ///
     /// [`Renderer`]: Self::Renderer
@@ -180,6 +199,14 @@ impl Compositor for () {
 
     fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
 
+    fn load_font_file(&mut self, _path: &Path) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn font_faces(&self) -> Vec<font::Face> {
+        Vec::new()
+    }
+
     fn fetch_information(&self) -> Information {
         Information {
             adapter: String::from("Null Renderer"),
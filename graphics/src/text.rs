@@ -11,11 +11,13 @@ pub use cosmic_text;
 
 use crate::core::alignment;
 use crate::core::font::{self, Font};
-use crate::core::text::Shaping;
+use crate::core::text::{Overflow, Shaping, Wrapping};
 use crate::core::{Color, Pixels, Point, Rectangle, Size, Transformation};
 
 use once_cell::sync::OnceCell;
 use std::borrow::Cow;
+use std::io;
+use std::path::Path;
 use std::sync::{Arc, RwLock, Weak};
 
 /// A text primitive.
@@ -59,6 +61,10 @@ pub enum Text {
         vertical_alignment: alignment::Vertical,
         /// The shaping strategy of the text.
         shaping: Shaping,
+        /// The wrapping strategy of the text.
+        wrapping: Wrapping,
+        /// The overflow strategy of the text.
+        overflow: Overflow,
         /// The clip bounds of the text.
         clip_bounds: Rectangle,
     },
@@ -195,6 +201,46 @@ impl FontSystem {
         self.version = Version(self.version.0 + 1);
     }
 
+    /// Loads a font from the file at the given path.
+    ///
+    /// Fails if the file cannot be read or does not contain any font face.
+    pub fn load_font_file(&mut self, path: &Path) -> io::Result<()> {
+        let bytes = std::fs::read(path)?;
+        let faces = self.raw.db().len();
+
+        self.load_font(Cow::Owned(bytes));
+
+        if self.raw.db().len() == faces {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "no font faces found",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the [`font::Face`]s available in the [`FontSystem`].
+    ///
+    /// Faces with multiple family names are listed under their first one.
+    pub fn faces(&self) -> Vec<font::Face> {
+        self.raw
+            .db()
+            .faces()
+            .filter_map(|face| {
+                let (family, _) = face.families.first()?;
+
+                Some(font::Face {
+                    family: family.clone(),
+                    weight: from_weight(face.weight),
+                    stretch: from_stretch(face.stretch),
+                    style: from_style(face.style),
+                    is_monospace: face.monospaced,
+                })
+            })
+            .collect()
+    }
+
     /// Returns the current [`Version`] of the [`FontSystem`].
     ///
     /// Loading a font will increase the version of a [`FontSystem`].
@@ -297,6 +343,42 @@ fn to_style(style: font::Style) -> cosmic_text::Style {
     }
 }
 
+fn from_weight(weight: cosmic_text::Weight) -> font::Weight {
+    match weight.0 {
+        0..=149 => font::Weight::Thin,
+        150..=249 => font::Weight::ExtraLight,
+        250..=349 => font::Weight::Light,
+        350..=449 => font::Weight::Normal,
+        450..=549 => font::Weight::Medium,
+        550..=649 => font::Weight::Semibold,
+        650..=749 => font::Weight::Bold,
+        750..=849 => font::Weight::ExtraBold,
+        _ => font::Weight::Black,
+    }
+}
+
+fn from_stretch(stretch: cosmic_text::Stretch) -> font::Stretch {
+    match stretch {
+        cosmic_text::Stretch::UltraCondensed => font::Stretch::UltraCondensed,
+        cosmic_text::Stretch::ExtraCondensed => font::Stretch::ExtraCondensed,
+        cosmic_text::Stretch::Condensed => font::Stretch::Condensed,
+        cosmic_text::Stretch::SemiCondensed => font::Stretch::SemiCondensed,
+        cosmic_text::Stretch::Normal => font::Stretch::Normal,
+        cosmic_text::Stretch::SemiExpanded => font::Stretch::SemiExpanded,
+        cosmic_text::Stretch::Expanded => font::Stretch::Expanded,
+        cosmic_text::Stretch::ExtraExpanded => font::Stretch::ExtraExpanded,
+        cosmic_text::Stretch::UltraExpanded => font::Stretch::UltraExpanded,
+    }
+}
+
+fn from_style(style: cosmic_text::Style) -> font::Style {
+    match style {
+        cosmic_text::Style::Normal => font::Style::Normal,
+        cosmic_text::Style::Italic => font::Style::Italic,
+        cosmic_text::Style::Oblique => font::Style::Oblique,
+    }
+}
+
 /// Converts some [`Shaping`] strategy to a [`cosmic_text::Shaping`] strategy.
 pub fn to_shaping(shaping: Shaping) -> cosmic_text::Shaping {
     match shaping {
@@ -305,9 +387,105 @@ pub fn to_shaping(shaping: Shaping) -> cosmic_text::Shaping {
     }
 }
 
+/// Converts some [`Wrapping`] strategy to a [`cosmic_text::Wrap`] strategy.
+///
+/// `cosmic-text` cannot fall back to glyph wrapping only when a word does not
+/// fit; therefore, [`Wrapping::WordOrGlyph`] is converted to word wrapping.
+/// Use [`set_wrapping`] to emulate it for a whole buffer.
+pub fn to_wrap(wrapping: Wrapping) -> cosmic_text::Wrap {
+    match wrapping {
+        Wrapping::None => cosmic_text::Wrap::None,
+        Wrapping::Word | Wrapping::WordOrGlyph => cosmic_text::Wrap::Word,
+        Wrapping::Glyph => cosmic_text::Wrap::Glyph,
+    }
+}
+
+/// Applies the given [`Wrapping`] strategy to a [`cosmic_text::Buffer`].
+///
+/// [`Wrapping::WordOrGlyph`] wraps the whole buffer at the glyph level as
+/// soon as one of its words does not fit in a line by itself.
+pub fn set_wrapping(
+    font_system: &mut cosmic_text::FontSystem,
+    buffer: &mut cosmic_text::Buffer,
+    wrapping: Wrapping,
+) {
+    buffer.set_wrap(font_system, to_wrap(wrapping));
+
+    if wrapping == Wrapping::WordOrGlyph {
+        let (width, _) = buffer.size();
+
+        if buffer.layout_runs().any(|run| run.line_w > width) {
+            buffer.set_wrap(font_system, cosmic_text::Wrap::Glyph);
+        }
+    }
+}
+
 /// Converts some [`Color`] to a [`cosmic_text::Color`].
 pub fn to_color(color: Color) -> cosmic_text::Color {
     let [r, g, b, a] = color.into_rgba8();
 
     cosmic_text::Color::rgba(r, g, b, a)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty() -> FontSystem {
+        FontSystem {
+            raw: cosmic_text::FontSystem::new_with_locale_and_db(
+                String::from("en-US"),
+                cosmic_text::fontdb::Database::new(),
+            ),
+            version: Version::default(),
+        }
+    }
+
+    fn font(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fonts")
+            .join(name)
+    }
+
+    #[test]
+    fn it_loads_font_files() {
+        let mut font_system = empty();
+
+        assert!(font_system.faces().is_empty());
+
+        font_system
+            .load_font_file(&font("FiraSans-Regular.ttf"))
+            .expect("Load font file");
+
+        assert_eq!(font_system.version(), Version(1));
+        assert_eq!(
+            font_system.faces(),
+            [font::Face {
+                family: String::from("Fira Sans"),
+                weight: font::Weight::Normal,
+                stretch: font::Stretch::Normal,
+                style: font::Style::Normal,
+                is_monospace: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn it_fails_to_load_invalid_font_files() {
+        let mut font_system = empty();
+
+        let error = font_system
+            .load_font_file(&font("Missing.ttf"))
+            .expect_err("Missing file");
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+
+        let error = font_system
+            .load_font_file(
+                &Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"),
+            )
+            .expect_err("Not a font");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        assert!(font_system.faces().is_empty());
+    }
+}
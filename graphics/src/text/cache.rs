@@ -1,6 +1,6 @@
 //! Cache text.
 use crate::core::{Font, Size};
-use crate::text;
+use crate::text::{self, paragraph};
 
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 use std::collections::hash_map;
@@ -51,11 +51,14 @@ impl Cache {
                 key.bounds.width,
                 key.bounds.height.max(key.line_height),
             );
-            buffer.set_text(
+            paragraph::layout(
                 font_system,
+                &mut buffer,
                 key.content,
-                text::to_attributes(key.font),
-                text::to_shaping(key.shaping),
+                &[(0..key.content.len(), text::to_attributes(key.font))],
+                key.shaping,
+                key.wrapping,
+                key.overflow,
             );
 
             let bounds = text::measure(&buffer);
@@ -114,6 +117,10 @@ pub struct Key<'a> {
     pub bounds: Size,
     /// The shaping strategy of the text.
     pub shaping: text::Shaping,
+    /// The wrapping strategy of the text.
+    pub wrapping: text::Wrapping,
+    /// The overflow strategy of the text.
+    pub overflow: text::Overflow,
 }
 
 impl Key<'_> {
@@ -125,6 +132,8 @@ impl Key<'_> {
         self.bounds.width.to_bits().hash(&mut hasher);
         self.bounds.height.to_bits().hash(&mut hasher);
         self.shaping.hash(&mut hasher);
+        self.wrapping.hash(&mut hasher);
+        self.overflow.hash(&mut hasher);
 
         hasher.finish()
     }
@@ -1,12 +1,16 @@
 //! Draw and edit text.
+mod history;
+
 use crate::core::text::editor::{
-    self, Action, Cursor, Direction, Edit, Motion,
+    self, Action, Cursor, Direction, Edit, Indentation, Motion,
 };
 use crate::core::text::highlighter::{self, Highlighter};
-use crate::core::text::LineHeight;
+use crate::core::text::{LineHeight, Wrapping};
 use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
+use history::History;
+
 use cosmic_text::Edit as _;
 
 use std::fmt;
@@ -19,8 +23,12 @@ pub struct Editor(Option<Arc<Internal>>);
 struct Internal {
     editor: cosmic_text::Editor,
     font: Font,
+    wrapping: Wrapping,
     bounds: Size,
+    carets: Vec<Caret>,
+    preferred_x: Option<f32>,
     topmost_line_changed: Option<usize>,
+    history: History,
     version: text::Version,
 }
 
@@ -93,141 +101,90 @@ impl editor::Editor for Editor {
         self.buffer().lines.len()
     }
 
-    fn selection(&self) -> Option<String> {
-        self.internal().editor.copy_selection()
+    fn visible_lines(&self) -> Vec<editor::LineLayout> {
+        let buffer = self.buffer();
+
+        let line_height = buffer.metrics().line_height;
+        let scroll = buffer.scroll();
+        let end = scroll + buffer.visible_lines();
+
+        let mut visual_line = 0;
+        let mut lines = Vec::new();
+
+        for (index, line) in buffer.lines.iter().enumerate() {
+            // Lines after the visible ones may not be laid out yet
+            let Some(layout) = line.layout_opt() else {
+                break;
+            };
+
+            if visual_line >= end {
+                break;
+            }
+
+            let visual_lines = layout.len() as i32;
+
+            if visual_line + visual_lines > scroll {
+                lines.push(editor::LineLayout {
+                    index,
+                    y: (visual_line - scroll) as f32 * line_height,
+                    height: visual_lines as f32 * line_height,
+                });
+            }
+
+            visual_line += visual_lines;
+        }
+
+        lines
     }
 
-    fn cursor(&self) -> editor::Cursor {
+    fn selection(&self) -> Option<String> {
         let internal = self.internal();
 
-        let cursor = internal.editor.cursor();
-        let buffer = internal.editor.buffer();
-
-        match internal.editor.select_opt() {
-            Some(selection) => {
-                let (start, end) = if cursor < selection {
-                    (cursor, selection)
-                } else {
-                    (selection, cursor)
-                };
+        if internal.carets.is_empty() {
+            return internal.editor.copy_selection();
+        }
 
-                let line_height = buffer.metrics().line_height;
-                let selected_lines = end.line - start.line + 1;
+        let buffer = internal.editor.buffer();
 
-                let visual_lines_offset =
-                    visual_lines_offset(start.line, buffer);
+        let mut carets: Vec<Caret> =
+            std::iter::once(Caret::of(&internal.editor))
+                .chain(internal.carets.iter().copied())
+                .collect();
 
-                let regions = buffer
-                    .lines
-                    .iter()
-                    .skip(start.line)
-                    .take(selected_lines)
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        highlight_line(
-                            line,
-                            if i == 0 { start.index } else { 0 },
-                            if i == selected_lines - 1 {
-                                end.index
-                            } else {
-                                line.text().len()
-                            },
-                        )
-                    })
-                    .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(Rectangle {
-                                x,
-                                width,
-                                y: (visual_line as i32 + visual_lines_offset)
-                                    as f32
-                                    * line_height,
-                                height: line_height,
-                            })
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+        carets.sort_by_key(Caret::start);
 
-                Cursor::Selection(regions)
-            }
-            _ => {
-                let line_height = buffer.metrics().line_height;
+        let selections: Vec<String> = carets
+            .iter()
+            .filter_map(|caret| caret.selected_text(buffer))
+            .collect();
 
-                let visual_lines_offset =
-                    visual_lines_offset(cursor.line, buffer);
+        (!selections.is_empty()).then(|| selections.join("\n"))
+    }
 
-                let line = buffer
-                    .lines
-                    .get(cursor.line)
-                    .expect("Cursor line should be present");
+    fn selection_range(&self) -> Option<((usize, usize), (usize, usize))> {
+        let caret = Caret::of(&self.internal().editor);
+        let _ = caret.selection?;
 
-                let layout = line
-                    .layout_opt()
-                    .as_ref()
-                    .expect("Line layout should be cached");
+        let (start, end) = (caret.start(), caret.end());
 
-                let mut lines = layout.iter().enumerate();
+        Some(((start.line, start.index), (end.line, end.index)))
+    }
 
-                let (visual_line, offset) = lines
-                    .find_map(|(i, line)| {
-                        let start = line
-                            .glyphs
-                            .first()
-                            .map(|glyph| glyph.start)
-                            .unwrap_or(0);
-                        let end = line
-                            .glyphs
-                            .last()
-                            .map(|glyph| glyph.end)
-                            .unwrap_or(0);
+    fn cursor(&self) -> editor::Cursor {
+        let internal = self.internal();
 
-                        let is_cursor_before_start = start > cursor.index;
+        visual_cursor(internal.editor.buffer(), Caret::of(&internal.editor))
+            .expect("Cursor line should be laid out")
+    }
 
-                        let is_cursor_before_end = match cursor.affinity {
-                            cosmic_text::Affinity::Before => {
-                                cursor.index <= end
-                            }
-                            cosmic_text::Affinity::After => cursor.index < end,
-                        };
-
-                        if is_cursor_before_start {
-                            // Sometimes, the glyph we are looking for is right
-                            // between lines. This can happen when a line wraps
-                            // on a space.
-                            // In that case, we can assume the cursor is at the
-                            // end of the previous line.
-                            // i is guaranteed to be > 0 because `start` is always
-                            // 0 for the first line, so there is no way for the
-                            // cursor to be before it.
-                            Some((i - 1, layout[i - 1].w))
-                        } else if is_cursor_before_end {
-                            let offset = line
-                                .glyphs
-                                .iter()
-                                .take_while(|glyph| cursor.index > glyph.start)
-                                .map(|glyph| glyph.w)
-                                .sum();
-
-                            Some((i, offset))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((
-                        layout.len().saturating_sub(1),
-                        layout.last().map(|line| line.w).unwrap_or(0.0),
-                    ));
+    fn cursors(&self) -> Vec<editor::Cursor> {
+        let internal = self.internal();
+        let buffer = internal.editor.buffer();
 
-                Cursor::Caret(Point::new(
-                    offset,
-                    (visual_lines_offset + visual_line as i32) as f32
-                        * line_height,
-                ))
-            }
-        }
+        std::iter::once(Caret::of(&internal.editor))
+            .chain(internal.carets.iter().copied())
+            .filter_map(|caret| visual_cursor(buffer, caret))
+            .collect()
     }
 
     fn cursor_position(&self) -> (usize, usize) {
@@ -249,63 +206,88 @@ impl editor::Editor for Editor {
 
         let editor = &mut internal.editor;
 
+        if !action.is_edit() && !matches!(action, Action::Scroll { .. }) {
+            internal.history.break_group();
+        }
+
+        // Only motions and edits are applied at every caret. Other actions
+        // bring the editor back to a single caret.
+        if !matches!(
+            action,
+            Action::Move(_)
+                | Action::Select(_)
+                | Action::Edit(_)
+                | Action::AddCursor(_)
+                | Action::Scroll { .. }
+        ) {
+            internal.carets.clear();
+            internal.preferred_x = None;
+        }
+
+        // Replacing a range is the same as selecting it and pasting
+        let action = match action {
+            Action::Edit(Edit::ReplaceRange { start, end, text }) => {
+                internal.carets.clear();
+
+                let start = to_cursor(editor.buffer(), start);
+                let end = to_cursor(editor.buffer(), end);
+
+                editor.set_cursor(end);
+                editor.set_select_opt((start != end).then_some(start));
+
+                Action::Edit(Edit::Paste(text))
+            }
+            action => action,
+        };
+
         match action {
             // Motion events
             Action::Move(motion) => {
-                if let Some(selection) = editor.select_opt() {
-                    let cursor = editor.cursor();
+                for_each_caret(
+                    editor,
+                    &mut internal.preferred_x,
+                    &mut internal.carets,
+                    false,
+                    |editor, preferred_x| {
+                        move_caret(
+                            editor,
+                            font_system.raw(),
+                            motion,
+                            preferred_x,
+                        );
+                    },
+                );
+            }
 
-                    let (left, right) = if cursor < selection {
-                        (cursor, selection)
-                    } else {
-                        (selection, cursor)
-                    };
+            Action::MoveTo(position) => {
+                let cursor = to_cursor(editor.buffer(), position);
 
-                    editor.set_select_opt(None);
-
-                    match motion {
-                        // These motions are performed as-is even when a selection
-                        // is present
-                        Motion::Home
-                        | Motion::End
-                        | Motion::DocumentStart
-                        | Motion::DocumentEnd => {
-                            editor.action(
-                                font_system.raw(),
-                                motion_to_action(motion),
-                            );
-                        }
-                        // Other motions simply move the cursor to one end of the selection
-                        _ => editor.set_cursor(match motion.direction() {
-                            Direction::Left => left,
-                            Direction::Right => right,
-                        }),
-                    }
-                } else {
-                    editor.action(font_system.raw(), motion_to_action(motion));
-                }
+                editor.set_cursor(cursor);
+                editor.set_select_opt(None);
+
+                // Scroll to the new cursor, since it may be
+                // outside of the viewport
+                editor
+                    .buffer_mut()
+                    .shape_until_cursor(font_system.raw(), cursor);
             }
 
             // Selection events
             Action::Select(motion) => {
-                let cursor = editor.cursor();
-
-                if editor.select_opt().is_none() {
-                    editor.set_select_opt(Some(cursor));
-                }
-
-                editor.action(font_system.raw(), motion_to_action(motion));
-
-                // Deselect if selection matches cursor position
-                if let Some(selection) = editor.select_opt() {
-                    let cursor = editor.cursor();
-
-                    if cursor.line == selection.line
-                        && cursor.index == selection.index
-                    {
-                        editor.set_select_opt(None);
-                    }
-                }
+                for_each_caret(
+                    editor,
+                    &mut internal.preferred_x,
+                    &mut internal.carets,
+                    false,
+                    |editor, preferred_x| {
+                        select_caret(
+                            editor,
+                            font_system.raw(),
+                            motion,
+                            preferred_x,
+                        );
+                    },
+                );
             }
             Action::SelectWord => {
                 use unicode_segmentation::UnicodeSegmentation;
@@ -386,43 +368,156 @@ impl editor::Editor for Editor {
                 }
             }
 
-            // Editing events
-            Action::Edit(edit) => {
-                match edit {
-                    Edit::Insert(c) => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Insert(c),
-                        );
-                    }
-                    Edit::Paste(text) => {
-                        editor.insert_string(&text, None);
-                    }
-                    Edit::Enter => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Enter,
-                        );
-                    }
-                    Edit::Backspace => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Backspace,
-                        );
+            Action::SelectRange { start, end } => {
+                let start = to_cursor(editor.buffer(), start);
+                let end = to_cursor(editor.buffer(), end);
+
+                editor.set_cursor(end);
+                editor.set_select_opt((start != end).then_some(start));
+
+                // Scroll to the new cursor, since it may be
+                // outside of the viewport
+                editor
+                    .buffer_mut()
+                    .shape_until_cursor(font_system.raw(), end);
+            }
+
+            Action::AddCursor(position) => {
+                if let Some(cursor) =
+                    editor.buffer().hit(position.x, position.y)
+                {
+                    let main = Caret::of(editor);
+
+                    if !std::iter::once(&main)
+                        .chain(&internal.carets)
+                        .any(|caret| caret.is_at(cursor))
+                    {
+                        internal.carets.push(Caret {
+                            cursor,
+                            selection: None,
+                            preferred_x: None,
+                        });
                     }
-                    Edit::Delete => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Delete,
-                        );
+                }
+            }
+            Action::SelectColumn { from, to } => {
+                let buffer = editor.buffer();
+                let line_height = buffer.metrics().line_height;
+
+                let row = |y: f32| (y / line_height).max(0.0) as usize;
+                let top = row(from.y.min(to.y));
+                let bottom = row(from.y.max(to.y));
+
+                let mut carets: Vec<Caret> = Vec::new();
+
+                for row in top..=bottom {
+                    let y = (row as f32 + 0.5) * line_height;
+
+                    let (Some(anchor), Some(cursor)) =
+                        (buffer.hit(from.x, y), buffer.hit(to.x, y))
+                    else {
+                        continue;
+                    };
+
+                    if anchor.line != cursor.line
+                        || carets.iter().any(|caret| caret.is_at(cursor))
+                    {
+                        continue;
                     }
+
+                    carets.push(Caret {
+                        cursor,
+                        selection: (anchor.index != cursor.index)
+                            .then_some(anchor),
+                        preferred_x: None,
+                    });
                 }
 
-                let cursor = editor.cursor();
-                let selection = editor.select_opt().unwrap_or(cursor);
+                // The caret under the mouse becomes the main one
+                let main = if to.y < from.y {
+                    (!carets.is_empty()).then(|| carets.remove(0))
+                } else {
+                    carets.pop()
+                };
+
+                if let Some(main) = main {
+                    main.apply(editor);
+                    internal.carets = carets;
+                }
+            }
+
+            // Editing events
+            Action::Edit(edit) => {
+                let kind = history::Kind::of(&edit);
+
+                let before = Caret::of(editor);
+                let mut changes = Vec::new();
+
+                for_each_caret(
+                    editor,
+                    &mut internal.preferred_x,
+                    &mut internal.carets,
+                    true,
+                    |editor, _preferred_x| {
+                        let caret = Caret::of(editor);
+                        let old_line_count = editor.buffer().lines.len();
+
+                        // An edit can join the surrounding lines (i.e.
+                        // backspace at the start of a line), so we track
+                        // one extra line at each end of the caret.
+                        let start = caret.start().line.saturating_sub(1);
+                        let end =
+                            (caret.end().line + 1).min(old_line_count - 1);
+
+                        let old_lines = lines(editor, start, end);
+
+                        edit_caret(editor, font_system.raw(), &edit);
+
+                        let new_line_count = editor.buffer().lines.len();
+                        let new_lines = lines(
+                            editor,
+                            start,
+                            end + new_line_count - old_line_count,
+                        );
+
+                        changes.push(history::Change {
+                            line: start,
+                            old_lines,
+                            new_lines,
+                        });
+                    },
+                );
+
+                if let Some(topmost_line) =
+                    changes.iter().map(|change| change.line).min()
+                {
+                    internal.topmost_line_changed = Some(topmost_line);
+                }
 
-                internal.topmost_line_changed =
-                    Some(cursor.min(selection).line);
+                internal.history.record(
+                    kind,
+                    changes,
+                    before,
+                    Caret::of(editor),
+                );
+            }
+            Action::Undo => {
+                if let Some(line) = internal.history.undo(
+                    editor,
+                    font_system.raw(),
+                    text::to_attributes(internal.font),
+                ) {
+                    internal.topmost_line_changed = Some(line);
+                }
+            }
+            Action::Redo => {
+                if let Some(line) = internal.history.redo(
+                    editor,
+                    font_system.raw(),
+                    text::to_attributes(internal.font),
+                ) {
+                    internal.topmost_line_changed = Some(line);
+                }
             }
 
             // Mouse events
@@ -470,6 +565,14 @@ impl editor::Editor for Editor {
         self.0 = Some(Arc::new(internal));
     }
 
+    fn can_undo(&self) -> bool {
+        self.internal().history.can_undo()
+    }
+
+    fn can_redo(&self) -> bool {
+        self.internal().history.can_redo()
+    }
+
     fn bounds(&self) -> Size {
         self.internal().bounds
     }
@@ -486,6 +589,7 @@ impl editor::Editor for Editor {
         new_font: Font,
         new_size: Pixels,
         new_line_height: LineHeight,
+        new_wrapping: Wrapping,
         new_highlighter: &mut impl Highlighter,
     ) {
         let editor =
@@ -535,6 +639,17 @@ impl editor::Editor for Editor {
             );
         }
 
+        if new_wrapping != internal.wrapping {
+            log::trace!("Updating wrapping of `Editor`...");
+
+            internal
+                .editor
+                .buffer_mut()
+                .set_wrap(font_system.raw(), text::to_wrap(new_wrapping));
+
+            internal.wrapping = new_wrapping;
+        }
+
         if new_bounds != internal.bounds {
             log::trace!("Updating size of `Editor`...");
 
@@ -558,6 +673,22 @@ impl editor::Editor for Editor {
 
         internal.editor.shape_as_needed(font_system.raw());
 
+        if internal.wrapping == Wrapping::WordOrGlyph {
+            let buffer = internal.editor.buffer_mut();
+
+            let wrap = if overflows(buffer) {
+                cosmic_text::Wrap::Glyph
+            } else {
+                cosmic_text::Wrap::Word
+            };
+
+            if buffer.wrap() != wrap {
+                log::trace!("Falling back to {wrap:?} wrapping in `Editor`...");
+
+                buffer.set_wrap(font_system.raw(), wrap);
+            }
+        }
+
         self.0 = Some(Arc::new(internal));
     }
 
@@ -609,6 +740,12 @@ impl editor::Editor for Editor {
 
         let attributes = text::to_attributes(font);
 
+        highlighter.sync(
+            internal.editor.buffer().lines[current_line..]
+                .iter()
+                .map(cosmic_text::BufferLine::text),
+        );
+
         for line in &mut internal.editor.buffer_mut().lines
             [current_line..=last_visible_line]
         {
@@ -665,8 +802,12 @@ impl Default for Internal {
                 },
             )),
             font: Font::default(),
+            wrapping: Wrapping::default(),
             bounds: Size::ZERO,
+            carets: Vec::new(),
+            preferred_x: None,
             topmost_line_changed: None,
+            history: History::default(),
             version: text::Version::default(),
         }
     }
@@ -705,6 +846,126 @@ impl PartialEq for Weak {
     }
 }
 
+fn visual_cursor(buffer: &cosmic_text::Buffer, caret: Caret) -> Option<Cursor> {
+    let cursor = caret.cursor;
+
+    match caret.selection {
+        Some(selection) => {
+            let (start, end) = if cursor < selection {
+                (cursor, selection)
+            } else {
+                (selection, cursor)
+            };
+
+            // Lines after the visible ones may not be laid out yet
+            let _ = buffer.lines.get(start.line)?.layout_opt().as_ref()?;
+
+            let line_height = buffer.metrics().line_height;
+            let selected_lines = end.line - start.line + 1;
+
+            let visual_lines_offset = visual_lines_offset(start.line, buffer);
+
+            let regions = buffer
+                .lines
+                .iter()
+                .skip(start.line)
+                .take(selected_lines)
+                .take_while(|line| line.layout_opt().is_some())
+                .enumerate()
+                .flat_map(|(i, line)| {
+                    highlight_line(
+                        line,
+                        if i == 0 { start.index } else { 0 },
+                        if i == selected_lines - 1 {
+                            end.index
+                        } else {
+                            line.text().len()
+                        },
+                    )
+                })
+                .enumerate()
+                .filter_map(|(visual_line, (x, width))| {
+                    if width > 0.0 {
+                        Some(Rectangle {
+                            x,
+                            width,
+                            y: (visual_line as i32 + visual_lines_offset)
+                                as f32
+                                * line_height,
+                            height: line_height,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            Some(Cursor::Selection(regions))
+        }
+        _ => {
+            // Lines after the visible ones may not be laid out yet
+            let layout =
+                buffer.lines.get(cursor.line)?.layout_opt().as_ref()?;
+
+            let line_height = buffer.metrics().line_height;
+
+            let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
+
+            let mut lines = layout.iter().enumerate();
+
+            let (visual_line, offset) = lines
+                .find_map(|(i, line)| {
+                    let start = line
+                        .glyphs
+                        .first()
+                        .map(|glyph| glyph.start)
+                        .unwrap_or(0);
+                    let end =
+                        line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
+
+                    let is_cursor_before_start = start > cursor.index;
+
+                    let is_cursor_before_end = match cursor.affinity {
+                        cosmic_text::Affinity::Before => cursor.index <= end,
+                        cosmic_text::Affinity::After => cursor.index < end,
+                    };
+
+                    if is_cursor_before_start {
+                        // Sometimes, the glyph we are looking for is right
+                        // between lines. This can happen when a line wraps
+                        // on a space.
+                        // In that case, we can assume the cursor is at the
+                        // end of the previous line.
+                        // i is guaranteed to be > 0 because `start` is always
+                        // 0 for the first line, so there is no way for the
+                        // cursor to be before it.
+                        Some((i - 1, layout[i - 1].w))
+                    } else if is_cursor_before_end {
+                        let offset = line
+                            .glyphs
+                            .iter()
+                            .take_while(|glyph| cursor.index > glyph.start)
+                            .map(|glyph| glyph.w)
+                            .sum();
+
+                        Some((i, offset))
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or((
+                    layout.len().saturating_sub(1),
+                    layout.last().map(|line| line.w).unwrap_or(0.0),
+                ));
+
+            Some(Cursor::Caret(Point::new(
+                offset,
+                (visual_lines_offset + visual_line as i32) as f32 * line_height,
+            )))
+        }
+    }
+}
+
 fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
@@ -771,19 +1032,1039 @@ fn visual_lines_offset(line: usize, buffer: &cosmic_text::Buffer) -> i32 {
     visual_lines_before_start as i32 - buffer.scroll()
 }
 
-fn motion_to_action(motion: Motion) -> cosmic_text::Action {
-    match motion {
-        Motion::Left => cosmic_text::Action::Left,
-        Motion::Right => cosmic_text::Action::Right,
-        Motion::Up => cosmic_text::Action::Up,
-        Motion::Down => cosmic_text::Action::Down,
-        Motion::WordLeft => cosmic_text::Action::LeftWord,
-        Motion::WordRight => cosmic_text::Action::RightWord,
-        Motion::Home => cosmic_text::Action::Home,
-        Motion::End => cosmic_text::Action::End,
-        Motion::PageUp => cosmic_text::Action::PageUp,
-        Motion::PageDown => cosmic_text::Action::PageDown,
-        Motion::DocumentStart => cosmic_text::Action::BufferStart,
-        Motion::DocumentEnd => cosmic_text::Action::BufferEnd,
+fn overflows(buffer: &cosmic_text::Buffer) -> bool {
+    let (width, _) = buffer.size();
+    let font_size = buffer.metrics().font_size;
+
+    buffer
+        .lines
+        .iter()
+        .filter_map(|line| line.shape_opt().as_ref())
+        .flat_map(|shape| &shape.spans)
+        .flat_map(|span| &span.words)
+        .any(|word| !word.blank && word.x_advance * font_size > width)
+}
+
+fn to_cursor(
+    buffer: &cosmic_text::Buffer,
+    (line, column): (usize, usize),
+) -> cosmic_text::Cursor {
+    let line = line.min(buffer.lines.len().saturating_sub(1));
+
+    let text = buffer
+        .lines
+        .get(line)
+        .map(cosmic_text::BufferLine::text)
+        .unwrap_or_default();
+
+    let mut index = column.min(text.len());
+
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+
+    cosmic_text::Cursor::new(line, index)
+}
+
+fn move_caret(
+    editor: &mut cosmic_text::Editor,
+    font_system: &mut cosmic_text::FontSystem,
+    motion: Motion,
+    preferred_x: &mut Option<f32>,
+) {
+    if let Some(selection) = editor.select_opt() {
+        let cursor = editor.cursor();
+
+        let (left, right) = if cursor < selection {
+            (cursor, selection)
+        } else {
+            (selection, cursor)
+        };
+
+        editor.set_select_opt(None);
+
+        match motion {
+            // These motions are performed as-is even when a selection
+            // is present
+            Motion::Home
+            | Motion::End
+            | Motion::DocumentStart
+            | Motion::DocumentEnd => {
+                apply_motion(editor, font_system, motion, preferred_x);
+            }
+            // Other motions simply move the cursor to one end of the selection
+            _ => {
+                editor.set_cursor(match motion.direction() {
+                    Direction::Left => left,
+                    Direction::Right => right,
+                });
+
+                *preferred_x = None;
+            }
+        }
+    } else {
+        apply_motion(editor, font_system, motion, preferred_x);
+    }
+}
+
+fn select_caret(
+    editor: &mut cosmic_text::Editor,
+    font_system: &mut cosmic_text::FontSystem,
+    motion: Motion,
+    preferred_x: &mut Option<f32>,
+) {
+    let cursor = editor.cursor();
+
+    if editor.select_opt().is_none() {
+        editor.set_select_opt(Some(cursor));
+    }
+
+    apply_motion(editor, font_system, motion, preferred_x);
+
+    // Deselect if selection matches cursor position
+    if let Some(selection) = editor.select_opt() {
+        let cursor = editor.cursor();
+
+        if cursor.line == selection.line && cursor.index == selection.index {
+            editor.set_select_opt(None);
+        }
+    }
+}
+
+fn edit_caret(
+    editor: &mut cosmic_text::Editor,
+    font_system: &mut cosmic_text::FontSystem,
+    edit: &Edit,
+) {
+    match edit {
+        Edit::Insert(c) => {
+            editor.action(font_system, cosmic_text::Action::Insert(*c));
+        }
+        Edit::Paste(text) | Edit::ReplaceRange { text, .. } => {
+            editor.insert_string(text, None);
+        }
+        Edit::Enter => {
+            editor.action(font_system, cosmic_text::Action::Enter);
+
+            // Keep the indentation of the line that was broken
+            let cursor = editor.cursor();
+
+            let indentation: String = editor.buffer().lines[cursor.line - 1]
+                .text()
+                .chars()
+                .take_while(|c| *c == ' ' || *c == '\t')
+                .collect();
+
+            insert_inline(editor, &indentation);
+        }
+        Edit::LineBreak => {
+            editor.action(font_system, cosmic_text::Action::Enter);
+        }
+        Edit::Backspace => {
+            editor.action(font_system, cosmic_text::Action::Backspace);
+        }
+        Edit::Delete => {
+            editor.action(font_system, cosmic_text::Action::Delete);
+        }
+        Edit::Indent(indentation) => {
+            indent(editor, *indentation);
+        }
+        Edit::Unindent(indentation) => {
+            unindent(editor, *indentation);
+        }
+    }
+}
+
+fn indent(editor: &mut cosmic_text::Editor, indentation: Indentation) {
+    let caret = Caret::of(editor);
+
+    if caret.start().line == caret.end().line {
+        let _ = editor.delete_selection();
+
+        let unit = match indentation {
+            Indentation::Tabs => String::from("\t"),
+            Indentation::Spaces(amount) => {
+                let cursor = editor.cursor();
+                let column = editor.buffer().lines[cursor.line].text()
+                    [..cursor.index]
+                    .chars()
+                    .count();
+
+                // Align the cursor to the next indentation stop
+                " ".repeat(amount - column % amount.max(1))
+            }
+        };
+
+        insert_inline(editor, &unit);
+
+        return;
+    }
+
+    let unit = match indentation {
+        Indentation::Tabs => String::from("\t"),
+        Indentation::Spaces(amount) => " ".repeat(amount),
+    };
+
+    let lines = selected_lines(caret);
+
+    for line in lines.clone() {
+        editor.set_select_opt(None);
+        editor.set_cursor(cosmic_text::Cursor::new(line, 0));
+
+        insert_inline(editor, &unit);
+    }
+
+    let shift = |cursor: cosmic_text::Cursor| {
+        if lines.contains(&cursor.line) && cursor.index > 0 {
+            cosmic_text::Cursor {
+                index: cursor.index + unit.len(),
+                ..cursor
+            }
+        } else {
+            cursor
+        }
+    };
+
+    Caret {
+        cursor: shift(caret.cursor),
+        selection: caret.selection.map(shift),
+        preferred_x: None,
+    }
+    .apply(editor);
+}
+
+fn unindent(editor: &mut cosmic_text::Editor, indentation: Indentation) {
+    let caret = Caret::of(editor);
+    let lines = selected_lines(caret);
+
+    let mut removed = Vec::with_capacity(lines.clone().count());
+
+    for line in lines.clone() {
+        let text = editor.buffer().lines[line].text();
+
+        let length = if text.starts_with('\t') {
+            1
+        } else {
+            match indentation {
+                Indentation::Tabs => 0,
+                Indentation::Spaces(amount) => text
+                    .bytes()
+                    .take(amount)
+                    .take_while(|byte| *byte == b' ')
+                    .count(),
+            }
+        };
+
+        if length > 0 {
+            editor.set_cursor(cosmic_text::Cursor::new(line, length));
+            editor.set_select_opt(Some(cosmic_text::Cursor::new(line, 0)));
+
+            let _ = editor.delete_selection();
+        }
+
+        removed.push(length);
+    }
+
+    let shift = |cursor: cosmic_text::Cursor| {
+        if lines.contains(&cursor.line) {
+            cosmic_text::Cursor {
+                index: cursor
+                    .index
+                    .saturating_sub(removed[cursor.line - lines.start()]),
+                ..cursor
+            }
+        } else {
+            cursor
+        }
+    };
+
+    Caret {
+        cursor: shift(caret.cursor),
+        selection: caret.selection.map(shift),
+        preferred_x: None,
+    }
+    .apply(editor);
+}
+
+/// Inserts some text without line breaks at the cursor.
+///
+/// Unlike `insert_string`, this keeps trailing control characters,
+/// like tabs.
+fn insert_inline(editor: &mut cosmic_text::Editor, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+
+    let _ = editor.delete_selection();
+
+    let cursor = editor.cursor();
+    let line = &mut editor.buffer_mut().lines[cursor.line];
+
+    let after = line.split_off(cursor.index);
+    let attributes = cosmic_text::AttrsList::new(after.attrs_list().defaults());
+
+    line.append(cosmic_text::BufferLine::new(
+        text,
+        attributes,
+        cosmic_text::Shaping::Advanced,
+    ));
+    line.append(after);
+
+    editor.set_cursor(cosmic_text::Cursor::new(
+        cursor.line,
+        cursor.index + text.len(),
+    ));
+    editor.buffer_mut().set_redraw(true);
+}
+
+/// Returns the lines spanned by the given [`Caret`].
+///
+/// A selection ending at the start of a line does not include it.
+fn selected_lines(caret: Caret) -> std::ops::RangeInclusive<usize> {
+    let (start, end) = (caret.start(), caret.end());
+
+    if end.line > start.line && end.index == 0 {
+        start.line..=end.line - 1
+    } else {
+        start.line..=end.line
+    }
+}
+
+fn apply_motion(
+    editor: &mut cosmic_text::Editor,
+    font_system: &mut cosmic_text::FontSystem,
+    motion: Motion,
+    preferred_x: &mut Option<f32>,
+) {
+    let page = || {
+        let buffer = editor.buffer();
+
+        (buffer.size().1 / buffer.metrics().line_height) as i32
+    };
+
+    let rows = match motion {
+        Motion::Up => -1,
+        Motion::Down => 1,
+        Motion::PageUp => -page(),
+        Motion::PageDown => page(),
+        _ => 0,
+    };
+
+    if rows != 0 {
+        move_vertically(editor, font_system, preferred_x, rows);
+        return;
+    }
+
+    *preferred_x = None;
+
+    let cursor = editor.cursor();
+
+    // Wrapped rows of a line simply move to their start
+    if motion != Motion::Home
+        || editor.buffer().layout_cursor(&cursor).layout > 0
+    {
+        editor.action(font_system, motion_to_action(motion));
+        return;
+    }
+
+    let text = editor.buffer().lines[cursor.line].text();
+    let first_non_blank = text.len() - text.trim_start().len();
+
+    let index = if cursor.index == first_non_blank {
+        0
+    } else {
+        first_non_blank
+    };
+
+    editor.set_cursor(cosmic_text::Cursor::new(cursor.line, index));
+}
+
+/// Moves the cursor of the editor the given amount of rows up (if negative)
+/// or down, keeping the given preferred horizontal position.
+///
+/// `cosmic_text` keeps a single preferred glyph index in its editor, which
+/// would be shared by every caret and drifts with proportional fonts.
+fn move_vertically(
+    editor: &mut cosmic_text::Editor,
+    font_system: &mut cosmic_text::FontSystem,
+    preferred_x: &mut Option<f32>,
+    rows: i32,
+) {
+    let cursor = editor.cursor();
+    let buffer = editor.buffer_mut();
+
+    let mut position = buffer.layout_cursor(&cursor);
+
+    let x = match *preferred_x {
+        Some(x) => x,
+        None => {
+            let x = buffer
+                .line_layout(font_system, position.line)
+                .and_then(|layout| layout.get(position.layout))
+                .map_or(0.0, |row| x_of(row, position.glyph));
+
+            *preferred_x.insert(x)
+        }
+    };
+
+    let mut row_count = |buffer: &mut cosmic_text::Buffer, line| {
+        buffer
+            .line_layout(font_system, line)
+            .map_or(1, |layout| layout.len().max(1))
+    };
+
+    for _ in 0..rows.unsigned_abs() {
+        if rows < 0 {
+            if position.layout > 0 {
+                position.layout -= 1;
+            } else if position.line > 0 {
+                position.line -= 1;
+                position.layout = row_count(buffer, position.line) - 1;
+            } else {
+                break;
+            }
+        } else if position.layout + 1 < row_count(buffer, position.line) {
+            position.layout += 1;
+        } else if position.line + 1 < buffer.lines.len() {
+            position.line += 1;
+            position.layout = 0;
+        } else {
+            break;
+        }
+    }
+
+    let Some(row) = buffer
+        .line_layout(font_system, position.line)
+        .and_then(|layout| layout.get(position.layout).or(layout.last()))
+    else {
+        return;
+    };
+
+    let (index, affinity) = hit_x(row, x);
+
+    buffer.set_redraw(true);
+
+    editor.set_cursor(cosmic_text::Cursor::new_with_affinity(
+        position.line,
+        index,
+        affinity,
+    ));
+}
+
+/// Returns the horizontal position of a cursor placed before the given glyph
+/// of a row; or after the whole row, if the glyph is out of bounds.
+fn x_of(row: &cosmic_text::LayoutLine, glyph: usize) -> f32 {
+    match row.glyphs.get(glyph) {
+        Some(glyph) if glyph.level.is_rtl() => glyph.x + glyph.w,
+        Some(glyph) => glyph.x,
+        None => row.glyphs.last().map_or(0.0, |glyph| {
+            if glyph.level.is_rtl() {
+                glyph.x
+            } else {
+                glyph.x + glyph.w
+            }
+        }),
+    }
+}
+
+/// Returns the cursor index and affinity closest to the given horizontal
+/// position in a row.
+fn hit_x(
+    row: &cosmic_text::LayoutLine,
+    x: f32,
+) -> (usize, cosmic_text::Affinity) {
+    // Glyphs are laid out in visual order
+    let glyph = row
+        .glyphs
+        .iter()
+        .find(|glyph| x < glyph.x + glyph.w)
+        .or(row.glyphs.last());
+
+    let Some(glyph) = glyph else {
+        return (0, cosmic_text::Affinity::After);
+    };
+
+    // The start of a right-to-left glyph is at its right edge
+    let is_left_half = x < glyph.x + glyph.w / 2.0;
+
+    if is_left_half != glyph.level.is_rtl() {
+        (glyph.start, cosmic_text::Affinity::After)
+    } else {
+        (glyph.end, cosmic_text::Affinity::Before)
+    }
+}
+
+/// Returns the text of the lines from `start` to `end` (inclusive).
+fn lines(
+    editor: &cosmic_text::Editor,
+    start: usize,
+    end: usize,
+) -> Vec<String> {
+    editor.buffer().lines[start..=end]
+        .iter()
+        .map(|line| line.text().to_owned())
+        .collect()
+}
+
+fn motion_to_action(motion: Motion) -> cosmic_text::Action {
+    match motion {
+        Motion::Left => cosmic_text::Action::Left,
+        Motion::Right => cosmic_text::Action::Right,
+        Motion::Up => cosmic_text::Action::Up,
+        Motion::Down => cosmic_text::Action::Down,
+        Motion::WordLeft => cosmic_text::Action::LeftWord,
+        Motion::WordRight => cosmic_text::Action::RightWord,
+        Motion::Home => cosmic_text::Action::Home,
+        Motion::End => cosmic_text::Action::End,
+        Motion::PageUp => cosmic_text::Action::PageUp,
+        Motion::PageDown => cosmic_text::Action::PageDown,
+        Motion::DocumentStart => cosmic_text::Action::BufferStart,
+        Motion::DocumentEnd => cosmic_text::Action::BufferEnd,
+    }
+}
+
+/// The cursor and selection of a caret in an [`Editor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Caret {
+    cursor: cosmic_text::Cursor,
+    selection: Option<cosmic_text::Cursor>,
+    /// The horizontal position the caret tries to keep when moving
+    /// vertically.
+    preferred_x: Option<f32>,
+}
+
+impl Caret {
+    fn of(editor: &cosmic_text::Editor) -> Self {
+        Self {
+            cursor: editor.cursor(),
+            selection: editor.select_opt(),
+            preferred_x: None,
+        }
+    }
+
+    fn apply(self, editor: &mut cosmic_text::Editor) {
+        editor.set_cursor(self.cursor);
+        editor.set_select_opt(self.selection);
+    }
+
+    fn start(&self) -> cosmic_text::Cursor {
+        self.selection
+            .map_or(self.cursor, |selection| selection.min(self.cursor))
+    }
+
+    fn end(&self) -> cosmic_text::Cursor {
+        self.selection
+            .map_or(self.cursor, |selection| selection.max(self.cursor))
+    }
+
+    fn is_at(&self, cursor: cosmic_text::Cursor) -> bool {
+        self.cursor.line == cursor.line && self.cursor.index == cursor.index
+    }
+
+    fn selected_text(&self, buffer: &cosmic_text::Buffer) -> Option<String> {
+        let _ = self.selection?;
+        let (start, end) = (self.start(), self.end());
+
+        let mut text = String::new();
+
+        for (i, line) in buffer.lines[start.line..=end.line].iter().enumerate()
+        {
+            let line_index = start.line + i;
+            let line = line.text();
+
+            let from = if line_index == start.line {
+                start.index
+            } else {
+                0
+            };
+            let to = if line_index == end.line {
+                end.index
+            } else {
+                line.len()
+            };
+
+            if i > 0 {
+                text.push('\n');
+            }
+
+            text.push_str(&line[from..to]);
+        }
+
+        Some(text)
+    }
+
+    /// Returns the [`Caret`] with its positions counted backwards from the
+    /// end of the buffer.
+    ///
+    /// These positions do not change when editing the text before them.
+    fn relative_to_end(self, buffer: &cosmic_text::Buffer) -> Self {
+        let flip = |cursor: cosmic_text::Cursor| {
+            let line_length = buffer
+                .lines
+                .get(cursor.line)
+                .map_or(0, |line| line.text().len());
+
+            cosmic_text::Cursor {
+                line: buffer.lines.len().saturating_sub(cursor.line + 1),
+                index: line_length.saturating_sub(cursor.index),
+                ..cursor
+            }
+        };
+
+        Self {
+            cursor: flip(self.cursor),
+            selection: self.selection.map(flip),
+            ..self
+        }
+    }
+
+    /// Turns the positions of a [`Caret`] obtained with
+    /// [`Caret::relative_to_end`] back into regular positions.
+    fn absolute_from_end(self, buffer: &cosmic_text::Buffer) -> Self {
+        let flip = |cursor: cosmic_text::Cursor| {
+            let line = buffer.lines.len().saturating_sub(cursor.line + 1);
+            let line_length =
+                buffer.lines.get(line).map_or(0, |line| line.text().len());
+
+            cosmic_text::Cursor {
+                line,
+                index: line_length.saturating_sub(cursor.index),
+                ..cursor
+            }
+        };
+
+        Self {
+            cursor: flip(self.cursor),
+            selection: self.selection.map(flip),
+            ..self
+        }
+    }
+}
+
+/// Runs the given function at every caret of the editor.
+///
+/// Carets are visited in order. If the function edits the text, the carets
+/// after the current one are moved along with it and their preferred
+/// horizontal positions are forgotten.
+///
+/// The preferred horizontal position of the main caret is the given
+/// `preferred_x`.
+fn for_each_caret(
+    editor: &mut cosmic_text::Editor,
+    preferred_x: &mut Option<f32>,
+    carets: &mut Vec<Caret>,
+    is_edit: bool,
+    mut f: impl FnMut(&mut cosmic_text::Editor, &mut Option<f32>),
+) {
+    if carets.is_empty() {
+        f(editor, preferred_x);
+
+        if is_edit {
+            *preferred_x = None;
+        }
+
+        return;
+    }
+
+    let main = Caret {
+        preferred_x: *preferred_x,
+        ..Caret::of(editor)
+    };
+
+    let mut all: Vec<(bool, Caret)> = std::iter::once((true, main))
+        .chain(carets.drain(..).map(|caret| (false, caret)))
+        .collect();
+
+    all.sort_by_key(|(_, caret)| caret.start());
+
+    for i in 0..all.len() {
+        if is_edit {
+            for (_, caret) in &mut all[i + 1..] {
+                *caret = caret.relative_to_end(editor.buffer());
+            }
+        }
+
+        let mut caret_x = all[i].1.preferred_x;
+
+        all[i].1.apply(editor);
+        f(editor, &mut caret_x);
+
+        all[i].1 = Caret {
+            preferred_x: caret_x.filter(|_| !is_edit),
+            ..Caret::of(editor)
+        };
+
+        if is_edit {
+            for (_, caret) in &mut all[i + 1..] {
+                *caret = caret.absolute_from_end(editor.buffer());
+            }
+        }
+    }
+
+    // Carets may end up in the same position (i.e. after deleting the text
+    // in between), in which case they are merged
+    all.dedup_by(|(is_main, caret), (is_previous_main, previous)| {
+        let is_duplicate = previous.is_at(caret.cursor);
+
+        if is_duplicate {
+            *is_previous_main |= *is_main;
+        }
+
+        is_duplicate
+    });
+
+    for (is_main, caret) in all {
+        if is_main {
+            caret.apply(editor);
+            *preferred_x = caret.preferred_x;
+        } else {
+            carets.push(caret);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor(text: &str) -> cosmic_text::Editor {
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        let mut buffer = cosmic_text::Buffer::new(
+            font_system.raw(),
+            cosmic_text::Metrics::new(16.0, 20.0),
+        );
+
+        buffer.set_size(font_system.raw(), 1000.0, 1000.0);
+        buffer.set_text(
+            font_system.raw(),
+            text,
+            cosmic_text::Attrs::new(),
+            cosmic_text::Shaping::Advanced,
+        );
+
+        cosmic_text::Editor::new(buffer)
+    }
+
+    fn caret(line: usize, index: usize) -> Caret {
+        Caret {
+            cursor: cosmic_text::Cursor::new(line, index),
+            selection: None,
+            preferred_x: None,
+        }
+    }
+
+    fn lines_of(editor: &Editor) -> Vec<&str> {
+        editor
+            .buffer()
+            .lines
+            .iter()
+            .map(cosmic_text::BufferLine::text)
+            .collect()
+    }
+
+    #[test]
+    fn it_undoes_edits_at_every_caret() {
+        use editor::Editor as _;
+
+        let mut editor = Editor::with_text("a\nb\nc\nd\ne");
+
+        Arc::get_mut(editor.0.as_mut().expect("Initialized editor"))
+            .expect("Unique editor")
+            .carets
+            .push(caret(4, 1));
+
+        editor.perform(Action::Edit(Edit::Enter));
+        editor.perform(Action::Edit(Edit::Insert('x')));
+
+        assert_eq!(lines_of(&editor), ["", "xa", "b", "c", "d", "e", "x"]);
+
+        editor.perform(Action::Undo);
+
+        assert_eq!(lines_of(&editor), ["", "a", "b", "c", "d", "e", ""]);
+
+        editor.perform(Action::Undo);
+
+        assert_eq!(lines_of(&editor), ["a", "b", "c", "d", "e"]);
+
+        editor.perform(Action::Redo);
+        editor.perform(Action::Redo);
+
+        assert_eq!(lines_of(&editor), ["", "xa", "b", "c", "d", "e", "x"]);
+    }
+
+    #[test]
+    fn it_wraps_long_words_by_glyph() {
+        use editor::Editor as _;
+
+        fn wrap_of(text: &str) -> (cosmic_text::Wrap, usize) {
+            let mut editor = Editor::with_text(text);
+
+            editor.update(
+                Size::new(30.0, 1000.0),
+                Font::MONOSPACE,
+                Pixels(16.0),
+                LineHeight::default(),
+                Wrapping::WordOrGlyph,
+                &mut highlighter::PlainText,
+            );
+
+            let buffer = editor.internal().editor.buffer();
+
+            (buffer.wrap(), buffer.layout_runs().count())
+        }
+
+        assert_eq!(wrap_of("ab cd\nef gh"), (cosmic_text::Wrap::Word, 4));
+        assert_eq!(
+            wrap_of("ab cd\nabcdefghijkl"),
+            (cosmic_text::Wrap::Glyph, 6)
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_column_of_every_caret() {
+        let mut editor = editor("abcdef\nabcdef\na\nabcdef\nabcdef");
+        let mut preferred_x = None;
+        let mut carets = vec![caret(1, 2)];
+
+        caret(0, 5).apply(&mut editor);
+
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        for _ in 0..3 {
+            for_each_caret(
+                &mut editor,
+                &mut preferred_x,
+                &mut carets,
+                false,
+                |editor, preferred_x| {
+                    move_caret(
+                        editor,
+                        font_system.raw(),
+                        Motion::Down,
+                        preferred_x,
+                    );
+                },
+            );
+        }
+
+        let position = |caret: Caret| (caret.cursor.line, caret.cursor.index);
+
+        assert_eq!(position(Caret::of(&editor)), (3, 5));
+        assert_eq!(
+            carets.into_iter().map(position).collect::<Vec<_>>(),
+            [(4, 2)]
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_horizontal_position_with_proportional_fonts() {
+        let mut editor = editor("iiiiiiii\nWWWWWWWW");
+        let mut preferred_x = None;
+
+        caret(0, 4).apply(&mut editor);
+
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        move_caret(
+            &mut editor,
+            font_system.raw(),
+            Motion::Down,
+            &mut preferred_x,
+        );
+
+        let cursor = editor.cursor();
+
+        // A wide glyph below covers the same horizontal position as many
+        // narrow glyphs above
+        assert_eq!(cursor.line, 1);
+        assert!(cursor.index < 4, "{cursor:?} did not keep its position");
+
+        move_caret(
+            &mut editor,
+            font_system.raw(),
+            Motion::Up,
+            &mut preferred_x,
+        );
+
+        assert_eq!(editor.cursor().line, 0);
+        assert_eq!(editor.cursor().index, 4);
+    }
+
+    fn select(line: usize, index: usize, anchor: (usize, usize)) -> Caret {
+        Caret {
+            selection: Some(cosmic_text::Cursor::new(anchor.0, anchor.1)),
+            ..caret(line, index)
+        }
+    }
+
+    /// Applies the [`Edit`] to the given text with the given [`Caret`],
+    /// returning the resulting lines and the positions of the cursor and
+    /// its selection.
+    fn edit(
+        text: &str,
+        caret: Caret,
+        edit: Edit,
+    ) -> (Vec<String>, (usize, usize), Option<(usize, usize)>) {
+        let mut editor = editor(text);
+
+        caret.apply(&mut editor);
+
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        edit_caret(&mut editor, font_system.raw(), &edit);
+
+        let caret = Caret::of(&editor);
+
+        (
+            editor
+                .buffer()
+                .lines
+                .iter()
+                .map(|line| line.text().to_owned())
+                .collect(),
+            (caret.cursor.line, caret.cursor.index),
+            caret
+                .selection
+                .map(|selection| (selection.line, selection.index)),
+        )
+    }
+
+    #[test]
+    fn it_indents_selected_lines() {
+        assert_eq!(
+            edit(
+                "a\n  b\nc",
+                select(1, 3, (0, 1)),
+                Edit::Indent(Indentation::Spaces(4))
+            ),
+            (
+                vec!["    a".into(), "      b".into(), "c".into()],
+                (1, 7),
+                Some((0, 5))
+            )
+        );
+
+        assert_eq!(
+            edit(
+                "a\n  b\nc",
+                select(1, 3, (0, 1)),
+                Edit::Indent(Indentation::Tabs)
+            ),
+            (
+                vec!["\ta".into(), "\t  b".into(), "c".into()],
+                (1, 4),
+                Some((0, 2))
+            )
+        );
+    }
+
+    #[test]
+    fn it_skips_lines_where_a_selection_ends_at_their_start() {
+        assert_eq!(
+            edit(
+                "a\nb\nc",
+                select(2, 0, (0, 0)),
+                Edit::Indent(Indentation::Spaces(2))
+            ),
+            (
+                vec!["  a".into(), "  b".into(), "c".into()],
+                (2, 0),
+                Some((0, 0))
+            )
+        );
+
+        assert_eq!(selected_lines(select(2, 0, (0, 1))), 0..=1);
+        assert_eq!(selected_lines(select(0, 1, (2, 1))), 0..=2);
+        assert_eq!(selected_lines(caret(1, 0)), 1..=1);
+    }
+
+    #[test]
+    fn it_indents_to_the_next_stop_without_a_multiline_selection() {
+        assert_eq!(
+            edit("ab", caret(0, 1), Edit::Indent(Indentation::Spaces(4))),
+            (vec!["a   b".into()], (0, 4), None)
+        );
+
+        assert_eq!(
+            edit("abc", select(0, 2, (0, 1)), Edit::Indent(Indentation::Tabs)),
+            (vec!["a\tc".into()], (0, 2), None)
+        );
+    }
+
+    #[test]
+    fn it_unindents_lines_with_less_indentation_than_a_unit() {
+        assert_eq!(
+            edit(
+                "  a\n      b\n\tc\nd",
+                select(3, 1, (0, 3)),
+                Edit::Unindent(Indentation::Spaces(4))
+            ),
+            (
+                vec!["a".into(), "  b".into(), "c".into(), "d".into()],
+                (3, 1),
+                Some((0, 1))
+            )
+        );
+
+        assert_eq!(
+            edit("    a", caret(0, 1), Edit::Unindent(Indentation::Spaces(4))),
+            (vec!["a".into()], (0, 0), None)
+        );
+
+        assert_eq!(
+            edit(
+                "\ta\n  b",
+                select(1, 3, (0, 0)),
+                Edit::Unindent(Indentation::Tabs)
+            ),
+            (vec!["a".into(), "  b".into()], (1, 3), Some((0, 0)))
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_indentation_when_breaking_lines() {
+        assert_eq!(
+            edit("    ab", caret(0, 5), Edit::Enter),
+            (vec!["    a".into(), "    b".into()], (1, 4), None)
+        );
+
+        assert_eq!(
+            edit("\t ab", caret(0, 3), Edit::Enter),
+            (vec!["\t a".into(), "\t b".into()], (1, 2), None)
+        );
+
+        assert_eq!(
+            edit("    ab", caret(0, 5), Edit::LineBreak),
+            (vec!["    a".into(), "b".into()], (1, 0), None)
+        );
+    }
+
+    #[test]
+    fn it_moves_home_to_the_first_non_blank_character() {
+        let mut editor = editor("    ab");
+        let mut preferred_x = None;
+
+        caret(0, 6).apply(&mut editor);
+
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        let mut home = || {
+            move_caret(
+                &mut editor,
+                font_system.raw(),
+                Motion::Home,
+                &mut preferred_x,
+            );
+
+            editor.cursor().index
+        };
+
+        assert_eq!([home(), home(), home()], [4, 0, 4]);
     }
 }
@@ -0,0 +1,461 @@
+//! Keep track of the edits of an [`Editor`] to undo and redo them.
+//!
+//! [`Editor`]: super::Editor
+use crate::core::text::editor::Edit;
+
+use super::Caret;
+
+use cosmic_text::Edit as _;
+
+use std::collections::VecDeque;
+
+/// The maximum amount of transactions that can be undone.
+///
+/// Once reached, recording a new transaction forgets the oldest one.
+pub const LIMIT: usize = 1_000;
+
+/// The undo and redo stacks of an [`Editor`](super::Editor).
+///
+/// Edits are grouped into transactions. Consecutive typing, backspacing
+/// or deleting is undone all at once, while any other action starts a
+/// new transaction. Only the last [`LIMIT`] transactions are kept.
+#[derive(Debug, Default)]
+pub struct History {
+    undo: VecDeque<Transaction>,
+    redo: Vec<Transaction>,
+    last: Option<Kind>,
+}
+
+impl History {
+    /// Returns whether there are transactions to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns whether there are transactions to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Ensures the next recorded [`Change`] starts a new transaction.
+    pub fn break_group(&mut self) {
+        self.last = None;
+    }
+
+    /// Records the changes produced by an edit of the given [`Kind`],
+    /// alongside the main caret before and after it took place.
+    ///
+    /// An edit produces a [`Change`] at every caret, in order.
+    pub fn record(
+        &mut self,
+        kind: Kind,
+        changes: Vec<Change>,
+        before: Caret,
+        after: Caret,
+    ) {
+        let changes: Vec<_> = changes
+            .into_iter()
+            .filter(|change| change.old_lines != change.new_lines)
+            .collect();
+
+        if changes.is_empty() {
+            return;
+        }
+
+        self.redo.clear();
+
+        let joins = self.last.is_some_and(|last| last.joins(kind));
+
+        match self.undo.back_mut() {
+            Some(transaction) if joins => {
+                transaction.changes.extend(changes);
+                transaction.after = after;
+            }
+            _ => {
+                self.push_undo(Transaction {
+                    changes,
+                    before,
+                    after,
+                });
+            }
+        }
+
+        self.last = Some(kind);
+    }
+
+    /// Reverts the last transaction in the given editor.
+    ///
+    /// Returns the topmost line that changed, if any.
+    pub fn undo(
+        &mut self,
+        editor: &mut cosmic_text::Editor,
+        font_system: &mut cosmic_text::FontSystem,
+        attributes: cosmic_text::Attrs<'_>,
+    ) -> Option<usize> {
+        let transaction = self.undo.pop_back()?;
+        self.last = None;
+
+        for change in transaction.changes.iter().rev() {
+            replace_lines(
+                editor,
+                change.line,
+                change.new_lines.len(),
+                &change.old_lines,
+                attributes,
+            );
+        }
+
+        let topmost_line = transaction.topmost_line();
+
+        restore(transaction.before, editor, font_system);
+
+        self.redo.push(transaction);
+
+        Some(topmost_line)
+    }
+
+    /// Reapplies the last reverted transaction in the given editor.
+    ///
+    /// Returns the topmost line that changed, if any.
+    pub fn redo(
+        &mut self,
+        editor: &mut cosmic_text::Editor,
+        font_system: &mut cosmic_text::FontSystem,
+        attributes: cosmic_text::Attrs<'_>,
+    ) -> Option<usize> {
+        let transaction = self.redo.pop()?;
+        self.last = None;
+
+        for change in &transaction.changes {
+            replace_lines(
+                editor,
+                change.line,
+                change.old_lines.len(),
+                &change.new_lines,
+                attributes,
+            );
+        }
+
+        let topmost_line = transaction.topmost_line();
+
+        restore(transaction.after, editor, font_system);
+
+        self.push_undo(transaction);
+
+        Some(topmost_line)
+    }
+
+    fn push_undo(&mut self, transaction: Transaction) {
+        if self.undo.len() == LIMIT {
+            let _ = self.undo.pop_front();
+        }
+
+        self.undo.push_back(transaction);
+    }
+}
+
+/// The kind of an edit, used to group edits into transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Insert { is_whitespace: bool },
+    Backspace,
+    Delete,
+    Other,
+}
+
+impl Kind {
+    /// Returns the [`Kind`] of the given [`Edit`].
+    pub fn of(edit: &Edit) -> Self {
+        match edit {
+            Edit::Insert(c) => Kind::Insert {
+                is_whitespace: c.is_whitespace(),
+            },
+            Edit::Backspace => Kind::Backspace,
+            Edit::Delete => Kind::Delete,
+            Edit::Paste(_)
+            | Edit::Enter
+            | Edit::LineBreak
+            | Edit::Indent(_)
+            | Edit::Unindent(_)
+            | Edit::ReplaceRange { .. } => Kind::Other,
+        }
+    }
+
+    fn joins(self, next: Self) -> bool {
+        match (self, next) {
+            // Typing a word right after some whitespace joins them,
+            // but whitespace after a word starts a new transaction.
+            (
+                Kind::Insert { is_whitespace },
+                Kind::Insert {
+                    is_whitespace: next_is_whitespace,
+                },
+            ) => is_whitespace || !next_is_whitespace,
+            (Kind::Backspace, Kind::Backspace)
+            | (Kind::Delete, Kind::Delete) => true,
+            _ => false,
+        }
+    }
+}
+
+/// A replacement of a range of lines.
+#[derive(Debug)]
+pub struct Change {
+    pub line: usize,
+    pub old_lines: Vec<String>,
+    pub new_lines: Vec<String>,
+}
+
+/// A group of changes, alongside the main caret before and after they
+/// took place.
+#[derive(Debug)]
+struct Transaction {
+    changes: Vec<Change>,
+    before: Caret,
+    after: Caret,
+}
+
+impl Transaction {
+    fn topmost_line(&self) -> usize {
+        self.changes
+            .iter()
+            .map(|change| change.line)
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+fn restore(
+    caret: Caret,
+    editor: &mut cosmic_text::Editor,
+    font_system: &mut cosmic_text::FontSystem,
+) {
+    caret.apply(editor);
+
+    // Lay out the buffer until the cursor and scroll to it,
+    // since it may have been restored outside of the viewport
+    editor
+        .buffer_mut()
+        .shape_until_cursor(font_system, caret.cursor);
+}
+
+fn replace_lines(
+    editor: &mut cosmic_text::Editor,
+    start: usize,
+    count: usize,
+    lines: &[String],
+    attributes: cosmic_text::Attrs<'_>,
+) {
+    let buffer = editor.buffer_mut();
+    let wrap = buffer.wrap();
+
+    let _ = buffer.lines.splice(
+        start..start + count,
+        lines.iter().map(|line| {
+            let mut line = cosmic_text::BufferLine::new(
+                line,
+                cosmic_text::AttrsList::new(attributes),
+                cosmic_text::Shaping::Advanced,
+            );
+
+            let _ = line.set_wrap(wrap);
+
+            line
+        }),
+    );
+
+    buffer.set_redraw(true);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor(text: &str) -> cosmic_text::Editor {
+        let mut font_system = crate::text::font_system()
+            .write()
+            .expect("Write font system");
+
+        let mut buffer = cosmic_text::Buffer::new(
+            font_system.raw(),
+            cosmic_text::Metrics::new(16.0, 20.0),
+        );
+
+        buffer.set_text(
+            font_system.raw(),
+            text,
+            cosmic_text::Attrs::new(),
+            cosmic_text::Shaping::Advanced,
+        );
+
+        cosmic_text::Editor::new(buffer)
+    }
+
+    fn text(editor: &cosmic_text::Editor) -> Vec<&str> {
+        editor
+            .buffer()
+            .lines
+            .iter()
+            .map(cosmic_text::BufferLine::text)
+            .collect()
+    }
+
+    fn caret(line: usize, index: usize) -> Caret {
+        Caret {
+            cursor: cosmic_text::Cursor::new(line, index),
+            selection: None,
+            preferred_x: None,
+        }
+    }
+
+    fn record(
+        history: &mut History,
+        kind: Kind,
+        line: usize,
+        old: &str,
+        new: &str,
+    ) {
+        history.record(
+            kind,
+            vec![Change {
+                line,
+                old_lines: vec![old.to_owned()],
+                new_lines: vec![new.to_owned()],
+            }],
+            caret(line, old.len()),
+            caret(line, new.len()),
+        );
+    }
+
+    fn insert(c: char) -> Kind {
+        Kind::of(&Edit::Insert(c))
+    }
+
+    #[test]
+    fn it_groups_words_into_transactions() {
+        let mut history = History::default();
+
+        record(&mut history, insert('a'), 0, "", "a");
+        record(&mut history, insert('b'), 0, "a", "ab");
+        record(&mut history, insert(' '), 0, "ab", "ab ");
+        record(&mut history, insert('c'), 0, "ab ", "ab c");
+
+        assert_eq!(history.undo.len(), 2);
+
+        record(&mut history, Kind::Backspace, 0, "ab c", "ab ");
+        record(&mut history, Kind::Backspace, 0, "ab ", "ab");
+
+        assert_eq!(history.undo.len(), 3);
+
+        history.break_group();
+        record(&mut history, Kind::Backspace, 0, "ab", "a");
+
+        assert_eq!(history.undo.len(), 4);
+
+        record(&mut history, Kind::Other, 0, "a", "a\n");
+        record(&mut history, Kind::Other, 0, "a\n", "a\n\n");
+
+        assert_eq!(history.undo.len(), 6);
+    }
+
+    #[test]
+    fn it_ignores_changes_without_effect() {
+        let mut history = History::default();
+
+        record(&mut history, Kind::Delete, 0, "same", "same");
+
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn it_undoes_and_redoes_transactions() {
+        let mut editor = editor("one\ntwo");
+        let mut history = History::default();
+        let attributes = cosmic_text::Attrs::new();
+
+        replace_lines(&mut editor, 1, 1, &["twos".to_owned()], attributes);
+        record(&mut history, insert('s'), 1, "two", "twos");
+
+        replace_lines(
+            &mut editor,
+            1,
+            1,
+            &["twos".to_owned(), "three".to_owned()],
+            attributes,
+        );
+        history.record(
+            Kind::Other,
+            vec![Change {
+                line: 1,
+                old_lines: vec!["twos".to_owned()],
+                new_lines: vec!["twos".to_owned(), "three".to_owned()],
+            }],
+            caret(1, 4),
+            caret(2, 5),
+        );
+
+        assert_eq!(text(&editor), ["one", "twos", "three"]);
+
+        let mut font_system = crate::text::font_system()
+            .write()
+            .expect("Write font system");
+        let raw = font_system.raw();
+
+        assert_eq!(history.undo(&mut editor, raw, attributes), Some(1));
+        assert_eq!(text(&editor), ["one", "twos"]);
+        assert_eq!(editor.cursor(), cosmic_text::Cursor::new(1, 4));
+
+        assert_eq!(history.undo(&mut editor, raw, attributes), Some(1));
+        assert_eq!(text(&editor), ["one", "two"]);
+        assert_eq!(history.undo(&mut editor, raw, attributes), None);
+
+        assert_eq!(history.redo(&mut editor, raw, attributes), Some(1));
+        assert_eq!(history.redo(&mut editor, raw, attributes), Some(1));
+        assert_eq!(text(&editor), ["one", "twos", "three"]);
+        assert_eq!(editor.cursor(), cosmic_text::Cursor::new(2, 5));
+        assert!(!history.can_redo());
+
+        assert_eq!(history.undo(&mut editor, raw, attributes), Some(1));
+        record(&mut history, insert('!'), 1, "twos", "twos!");
+
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn it_forgets_the_oldest_transactions() {
+        let mut history = History::default();
+
+        for i in 0..LIMIT + 10 {
+            history.break_group();
+            record(&mut history, Kind::Other, 0, "", &i.to_string());
+        }
+
+        assert_eq!(history.undo.len(), LIMIT);
+        assert_eq!(
+            history.undo.front().map(|transaction| transaction.changes[0]
+                .new_lines[0]
+                .as_str()),
+            Some("10")
+        );
+    }
+
+    #[test]
+    fn it_replaces_lines() {
+        let mut editor = editor("a\nb\nc\nd");
+        let attributes = cosmic_text::Attrs::new();
+
+        replace_lines(
+            &mut editor,
+            1,
+            2,
+            &["x".to_owned(), "y".to_owned(), "z".to_owned()],
+            attributes,
+        );
+
+        assert_eq!(text(&editor), ["a", "x", "y", "z", "d"]);
+
+        replace_lines(&mut editor, 0, 4, &[], attributes);
+
+        assert_eq!(text(&editor), ["d"]);
+    }
+}
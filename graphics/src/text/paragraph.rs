@@ -1,11 +1,14 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Hit, LineHeight, Shaping, Text};
-use crate::core::{Font, Pixels, Point, Size};
+use crate::core::text::{
+    Hit, LineHeight, Overflow, Shaping, Span, Text, Wrapping,
+};
+use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A bunch of text.
@@ -15,8 +18,11 @@ pub struct Paragraph(Option<Arc<Internal>>);
 struct Internal {
     buffer: cosmic_text::Buffer,
     content: String, // TODO: Reuse from `buffer` (?)
+    spans: Vec<(Range<usize>, cosmic_text::Attrs<'static>)>,
     font: Font,
     shaping: Shaping,
+    wrapping: Wrapping,
+    overflow: Overflow,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     bounds: Size,
@@ -56,14 +62,12 @@ impl Paragraph {
             .as_ref()
             .expect("paragraph should always be initialized")
     }
-}
-
-impl core::text::Paragraph for Paragraph {
-    type Font = Font;
-
-    fn with_text(text: Text<&str>) -> Self {
-        log::trace!("Allocating paragraph: {}", text.content);
 
+    fn shape<Content>(
+        text: Text<Content>,
+        content: String,
+        spans: Vec<(Range<usize>, cosmic_text::Attrs<'static>)>,
+    ) -> Self {
         let mut font_system =
             text::font_system().write().expect("Write font system");
 
@@ -81,27 +85,77 @@ impl core::text::Paragraph for Paragraph {
             text.bounds.height,
         );
 
-        buffer.set_text(
+        layout(
             font_system.raw(),
-            text.content,
-            text::to_attributes(text.font),
-            text::to_shaping(text.shaping),
+            &mut buffer,
+            &content,
+            &spans,
+            text.shaping,
+            text.wrapping,
+            text.overflow,
         );
 
         let min_bounds = text::measure(&buffer);
 
         Self(Some(Arc::new(Internal {
             buffer,
-            content: text.content.to_owned(),
+            content,
+            spans,
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            wrapping: text.wrapping,
+            overflow: text.overflow,
             bounds: text.bounds,
             min_bounds,
             version: font_system.version(),
         })))
     }
+}
+
+impl core::text::Paragraph for Paragraph {
+    type Font = Font;
+
+    fn with_text(text: Text<&str>) -> Self {
+        log::trace!("Allocating paragraph: {}", text.content);
+
+        let spans =
+            vec![(0..text.content.len(), text::to_attributes(text.font))];
+
+        Self::shape(text, text.content.to_owned(), spans)
+    }
+
+    fn with_spans<Link>(text: Text<&[Span<'_, Link>]>) -> Self {
+        log::trace!("Allocating rich paragraph: {} spans", text.content.len());
+
+        let mut content = String::new();
+
+        // TODO: Honor the size and line height of each span once
+        // `cosmic-text` supports per-span metrics
+        let spans = text
+            .content
+            .iter()
+            .enumerate()
+            .map(|(i, span)| {
+                let start = content.len();
+                content.push_str(&span.text);
+
+                let attributes =
+                    text::to_attributes(span.font.unwrap_or(text.font))
+                        .metadata(i);
+
+                let attributes = match span.color {
+                    Some(color) => attributes.color(text::to_color(color)),
+                    None => attributes,
+                };
+
+                (start..content.len(), attributes)
+            })
+            .collect();
+
+        Self::shape(text, content, spans)
+    }
 
     fn resize(&mut self, new_bounds: Size) {
         let paragraph = self
@@ -120,6 +174,26 @@ impl core::text::Paragraph for Paragraph {
                     new_bounds.height,
                 );
 
+                if internal.overflow == Overflow::Clip {
+                    text::set_wrapping(
+                        font_system.raw(),
+                        &mut internal.buffer,
+                        internal.wrapping,
+                    );
+                } else {
+                    // The buffer may contain truncated text, so we lay out
+                    // the whole content again
+                    layout(
+                        font_system.raw(),
+                        &mut internal.buffer,
+                        &internal.content,
+                        &internal.spans,
+                        internal.shaping,
+                        internal.wrapping,
+                        internal.overflow,
+                    );
+                }
+
                 internal.bounds = new_bounds;
                 internal.min_bounds = text::measure(&internal.buffer);
 
@@ -130,18 +204,24 @@ impl core::text::Paragraph for Paragraph {
 
                 // If there is a strong reference somewhere, we recompute the
                 // buffer from scratch
-                *self = Self::with_text(Text {
-                    content: &internal.content,
-                    bounds: internal.bounds,
-                    size: Pixels(metrics.font_size),
-                    line_height: LineHeight::Absolute(Pixels(
-                        metrics.line_height,
-                    )),
-                    font: internal.font,
-                    horizontal_alignment: internal.horizontal_alignment,
-                    vertical_alignment: internal.vertical_alignment,
-                    shaping: internal.shaping,
-                });
+                *self = Self::shape(
+                    Text {
+                        content: (),
+                        bounds: new_bounds,
+                        size: Pixels(metrics.font_size),
+                        line_height: LineHeight::Absolute(Pixels(
+                            metrics.line_height,
+                        )),
+                        font: internal.font,
+                        horizontal_alignment: internal.horizontal_alignment,
+                        vertical_alignment: internal.vertical_alignment,
+                        shaping: internal.shaping,
+                        wrapping: internal.wrapping,
+                        overflow: internal.overflow,
+                    },
+                    internal.content.clone(),
+                    internal.spans.clone(),
+                );
             }
         }
     }
@@ -157,6 +237,8 @@ impl core::text::Paragraph for Paragraph {
             || metrics.line_height != text.line_height.to_absolute(text.size).0
             || paragraph.font != text.font
             || paragraph.shaping != text.shaping
+            || paragraph.wrapping != text.wrapping
+            || paragraph.overflow != text.overflow
             || paragraph.horizontal_alignment != text.horizontal_alignment
             || paragraph.vertical_alignment != text.vertical_alignment
         {
@@ -182,8 +264,9 @@ impl core::text::Paragraph for Paragraph {
 
     fn hit_test(&self, point: Point) -> Option<Hit> {
         let cursor = self.internal().buffer.hit(point.x, point.y)?;
+        let offset = self.line_offsets().get(cursor.line).copied();
 
-        Some(Hit::CharOffset(cursor.index))
+        Some(Hit::CharOffset(offset.unwrap_or(0) + cursor.index))
     }
 
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
@@ -227,6 +310,85 @@ impl core::text::Paragraph for Paragraph {
             glyph.y - glyph.y_offset * glyph.font_size,
         ))
     }
+
+    fn hit_span(&self, point: Point) -> Option<usize> {
+        let buffer = &self.internal().buffer;
+        let line_height = buffer.metrics().line_height;
+
+        let run = buffer.layout_runs().find(|run| {
+            point.y >= run.line_top && point.y < run.line_top + line_height
+        })?;
+
+        let glyph = run
+            .glyphs
+            .iter()
+            .find(|glyph| point.x >= glyph.x && point.x < glyph.x + glyph.w)?;
+
+        Some(glyph.metadata)
+    }
+
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle> {
+        self.glyph_bounds(|_line, glyph| glyph.metadata == index)
+    }
+
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let offsets = self.line_offsets();
+
+        self.glyph_bounds(|line, glyph| {
+            let offset = offsets.get(line).copied().unwrap_or(0);
+
+            offset + glyph.start < range.end && offset + glyph.end > range.start
+        })
+    }
+}
+
+impl Paragraph {
+    /// Returns the byte offset of every line of the buffer in the content of
+    /// the [`Paragraph`].
+    fn line_offsets(&self) -> Vec<usize> {
+        let content = &self.internal().content;
+        let start = content.as_ptr() as usize;
+
+        cosmic_text::BidiParagraphs::new(content)
+            .map(|line| line.as_ptr() as usize - start)
+            .collect()
+    }
+
+    /// Returns the bounds of the glyphs that satisfy the given predicate,
+    /// merged by line.
+    fn glyph_bounds(
+        &self,
+        predicate: impl Fn(usize, &cosmic_text::LayoutGlyph) -> bool,
+    ) -> Vec<Rectangle> {
+        let buffer = &self.internal().buffer;
+        let line_height = buffer.metrics().line_height;
+
+        buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let mut glyphs = run
+                    .glyphs
+                    .iter()
+                    .filter(|glyph| predicate(run.line_i, glyph));
+
+                let first = glyphs.next()?;
+
+                let (start, end) = glyphs.fold(
+                    (first.x, first.x + first.w),
+                    |(start, end), glyph| {
+                        (start.min(glyph.x), end.max(glyph.x + glyph.w))
+                    },
+                );
+
+                Some(Rectangle {
+                    x: start,
+                    y: run.line_top,
+                    width: end - start,
+                    height: line_height,
+                })
+            })
+            .collect()
+    }
 }
 
 impl Default for Paragraph {
@@ -243,6 +405,8 @@ impl fmt::Debug for Paragraph {
             .field("content", &paragraph.content)
             .field("font", &paragraph.font)
             .field("shaping", &paragraph.shaping)
+            .field("wrapping", &paragraph.wrapping)
+            .field("overflow", &paragraph.overflow)
             .field("horizontal_alignment", &paragraph.horizontal_alignment)
             .field("vertical_alignment", &paragraph.vertical_alignment)
             .field("bounds", &paragraph.bounds)
@@ -254,8 +418,11 @@ impl fmt::Debug for Paragraph {
 impl PartialEq for Internal {
     fn eq(&self, other: &Self) -> bool {
         self.content == other.content
+            && self.spans == other.spans
             && self.font == other.font
             && self.shaping == other.shaping
+            && self.wrapping == other.wrapping
+            && self.overflow == other.overflow
             && self.horizontal_alignment == other.horizontal_alignment
             && self.vertical_alignment == other.vertical_alignment
             && self.bounds == other.bounds
@@ -272,8 +439,11 @@ impl Default for Internal {
                 line_height: 1.0,
             }),
             content: String::new(),
+            spans: Vec::new(),
             font: Font::default(),
             shaping: Shaping::default(),
+            wrapping: Wrapping::default(),
+            overflow: Overflow::default(),
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             bounds: Size::ZERO,
@@ -283,6 +453,139 @@ impl Default for Internal {
     }
 }
 
+/// Lays out the given spans of content in a [`cosmic_text::Buffer`], truncating
+/// them with an ellipsis if they do not fit its bounds and the [`Overflow`]
+/// strategy asks for it.
+pub(super) fn layout(
+    font_system: &mut cosmic_text::FontSystem,
+    buffer: &mut cosmic_text::Buffer,
+    content: &str,
+    spans: &[(Range<usize>, cosmic_text::Attrs<'static>)],
+    shaping: Shaping,
+    wrapping: Wrapping,
+    overflow: Overflow,
+) {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut set_text = |buffer: &mut cosmic_text::Buffer,
+                        cut: Option<Range<usize>>| {
+        let ellipsis = cut.as_ref().map(|cut| {
+            let attributes = spans
+                .iter()
+                .find(|(range, _)| range.contains(&cut.start.saturating_sub(1)))
+                .or(spans.first())
+                .map(|(_, attributes)| *attributes)
+                .unwrap_or_else(cosmic_text::Attrs::new);
+
+            ("…", attributes)
+        });
+
+        let cut = cut.unwrap_or(content.len()..content.len());
+
+        let before = spans.iter().filter_map(|(range, attributes)| {
+            let range = range.start..range.end.min(cut.start);
+
+            (!range.is_empty()).then(|| (&content[range], *attributes))
+        });
+
+        let after = spans.iter().filter_map(|(range, attributes)| {
+            let range = range.start.max(cut.end)..range.end;
+
+            (!range.is_empty()).then(|| (&content[range], *attributes))
+        });
+
+        buffer.set_rich_text(
+            font_system,
+            before.chain(ellipsis).chain(after),
+            text::to_shaping(shaping),
+        );
+
+        text::set_wrapping(font_system, buffer, wrapping);
+    };
+
+    set_text(buffer, None);
+
+    if overflow == Overflow::Clip || fits(buffer) {
+        return;
+    }
+
+    let boundaries: Vec<usize> = content
+        .grapheme_indices(true)
+        .map(|(i, _)| i)
+        .chain(std::iter::once(content.len()))
+        .collect();
+
+    let total = boundaries.len() - 1;
+
+    // Returns the range of content that is replaced by the ellipsis when
+    // only `kept` graphemes are kept
+    let cut = |kept: usize| {
+        let (head, tail) = match overflow {
+            Overflow::MiddleEllipsis => {
+                (boundaries[kept.div_ceil(2)], boundaries[total - kept / 2])
+            }
+            Overflow::Clip | Overflow::Ellipsis => {
+                (boundaries[kept], content.len())
+            }
+        };
+
+        content[..head].trim_end().len()
+            ..content.len() - content[tail..].trim_start().len()
+    };
+
+    // Find the largest amount of graphemes that fits with a binary search
+    let mut low = 0;
+    let mut high = total.saturating_sub(1);
+
+    while low < high {
+        let middle = (low + high).div_ceil(2);
+
+        set_text(buffer, Some(cut(middle)));
+
+        if fits(buffer) {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    set_text(buffer, Some(cut(low)));
+}
+
+/// Returns true if all the lines of the [`cosmic_text::Buffer`] fit its
+/// bounds.
+fn fits(buffer: &cosmic_text::Buffer) -> bool {
+    let (width, _) = buffer.size();
+
+    let mut last_line = None;
+    let mut layout_lines = 0;
+
+    for run in buffer.layout_runs() {
+        if run.line_w > width {
+            return false;
+        }
+
+        if last_line != Some(run.line_i) {
+            last_line = Some(run.line_i);
+            layout_lines = 0;
+        }
+
+        layout_lines += 1;
+    }
+
+    // Lines that do not fit vertically are not part of the layout runs
+    match last_line {
+        Some(line) => {
+            line + 1 == buffer.lines.len()
+                && buffer.lines[line]
+                    .layout_opt()
+                    .as_ref()
+                    .is_some_and(|layout| layout.len() == layout_lines)
+        }
+        None => true,
+    }
+}
+
 /// A weak reference to a [`Paragraph`].
 #[derive(Debug, Clone)]
 pub struct Weak {
@@ -310,3 +613,152 @@ impl PartialEq for Weak {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+    /// Lays out the given content in a monospace buffer as wide as the given
+    /// amount of characters and returns its resulting lines.
+    fn truncate(
+        content: &str,
+        characters: f32,
+        lines: usize,
+        wrapping: Wrapping,
+        overflow: Overflow,
+    ) -> Vec<String> {
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        let attributes = text::to_attributes(Font::MONOSPACE);
+        let metrics = cosmic_text::Metrics::new(16.0, 20.0);
+
+        let character_width = {
+            let mut buffer =
+                cosmic_text::Buffer::new(font_system.raw(), metrics);
+
+            buffer.set_size(font_system.raw(), f32::MAX, f32::MAX);
+            buffer.set_text(
+                font_system.raw(),
+                "a",
+                attributes,
+                cosmic_text::Shaping::Advanced,
+            );
+
+            buffer.layout_runs().map(|run| run.line_w).sum::<f32>()
+        };
+
+        let mut buffer = cosmic_text::Buffer::new(font_system.raw(), metrics);
+
+        buffer.set_size(
+            font_system.raw(),
+            character_width * characters,
+            metrics.line_height * lines as f32,
+        );
+
+        layout(
+            font_system.raw(),
+            &mut buffer,
+            content,
+            &[(0..content.len(), attributes)],
+            Shaping::Advanced,
+            wrapping,
+            overflow,
+        );
+
+        assert!(fits(&buffer), "{content:?} should fit after truncation");
+
+        buffer
+            .layout_runs()
+            .map(|run| {
+                let start = run.glyphs.first().map_or(0, |glyph| glyph.start);
+                let end = run.glyphs.last().map_or(0, |glyph| glyph.end);
+
+                run.text[start..end].to_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_keeps_content_that_fits() {
+        for overflow in [Overflow::Ellipsis, Overflow::MiddleEllipsis] {
+            assert_eq!(
+                truncate("abc", 10.5, 1, Wrapping::None, overflow),
+                ["abc"]
+            );
+        }
+    }
+
+    #[test]
+    fn it_truncates_at_the_end() {
+        assert_eq!(
+            truncate(ALPHABET, 10.5, 1, Wrapping::None, Overflow::Ellipsis),
+            ["abcdefghi…"]
+        );
+    }
+
+    #[test]
+    fn it_truncates_in_the_middle() {
+        assert_eq!(
+            truncate(
+                ALPHABET,
+                10.5,
+                1,
+                Wrapping::None,
+                Overflow::MiddleEllipsis
+            ),
+            ["abcde…wxyz"]
+        );
+    }
+
+    #[test]
+    fn it_trims_whitespace_around_the_ellipsis() {
+        assert_eq!(
+            truncate(
+                "abcd efgh ijkl mnop",
+                10.5,
+                1,
+                Wrapping::None,
+                Overflow::Ellipsis
+            ),
+            ["abcd efgh…"]
+        );
+
+        assert_eq!(
+            truncate(
+                "abcd efgh ijkl mnop",
+                10.5,
+                1,
+                Wrapping::None,
+                Overflow::MiddleEllipsis
+            ),
+            ["abcd…mnop"]
+        );
+    }
+
+    #[test]
+    fn it_truncates_wrapped_lines() {
+        assert_eq!(
+            truncate(
+                "abcd efgh ijkl mnop qrst",
+                10.5,
+                2,
+                Wrapping::Word,
+                Overflow::Ellipsis
+            ),
+            ["abcd efgh", "ijkl mnop…"]
+        );
+    }
+
+    #[test]
+    fn it_truncates_by_graphemes() {
+        let content = "ééééééééééééééé";
+
+        let lines =
+            truncate(content, 10.5, 1, Wrapping::None, Overflow::Ellipsis);
+
+        assert_eq!(lines, ["ééééééééé…"]);
+    }
+}
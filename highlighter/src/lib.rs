@@ -1,27 +1,178 @@
 //! A syntax highlighter for iced.
 use iced_core as core;
 
+#[cfg(feature = "tree-sitter")]
+pub mod tree_sitter;
+
+pub use crate::core::text::highlighter::Kind;
+
 use crate::core::text::highlighter::{self, Format};
 use crate::core::{Color, Font};
 
 use once_cell::sync::Lazy;
+use std::collections::BTreeMap;
+use std::io;
 use std::ops::Range;
+use std::path::Path;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use syntect::highlighting;
 use syntect::parsing;
 
-static SYNTAXES: Lazy<parsing::SyntaxSet> =
-    Lazy::new(parsing::SyntaxSet::load_defaults_nonewlines);
-
-static THEMES: Lazy<highlighting::ThemeSet> =
-    Lazy::new(highlighting::ThemeSet::load_defaults);
+static REGISTRY: Lazy<RwLock<Registry>> = Lazy::new(|| {
+    RwLock::new(Registry {
+        syntaxes: Arc::new(parsing::SyntaxSet::load_defaults_nonewlines()),
+        themes: Vec::new(),
+    })
+});
+
+static THEMES: Lazy<BTreeMap<String, Arc<highlighting::Theme>>> =
+    Lazy::new(|| {
+        highlighting::ThemeSet::load_defaults()
+            .themes
+            .into_iter()
+            .map(|(name, theme)| (name, Arc::new(theme)))
+            .collect()
+    });
+
+static KINDS: Lazy<Vec<(parsing::Scope, Option<Kind>)>> = Lazy::new(|| {
+    [
+        ("invalid", Some(Kind::Error)),
+        ("comment", Some(Kind::Comment)),
+        ("string", Some(Kind::String)),
+        ("constant.character.escape", Some(Kind::Keyword)),
+        ("constant", Some(Kind::Constant)),
+        ("keyword.operator", None),
+        ("keyword", Some(Kind::Keyword)),
+        ("storage.type", Some(Kind::Keyword)),
+        ("storage.modifier", Some(Kind::Keyword)),
+        ("entity.name.type", Some(Kind::Type)),
+        ("entity.name.class", Some(Kind::Type)),
+        ("entity.name.struct", Some(Kind::Type)),
+        ("entity.name.enum", Some(Kind::Type)),
+        ("entity.name.trait", Some(Kind::Type)),
+        ("entity.other.inherited-class", Some(Kind::Type)),
+        ("support.type", Some(Kind::Type)),
+        ("support.class", Some(Kind::Type)),
+    ]
+    .into_iter()
+    .map(|(scope, kind)| {
+        (parsing::Scope::new(scope).expect("Valid scope"), kind)
+    })
+    .collect()
+});
 
 const LINES_PER_SNAPSHOT: usize = 50;
 
+/// The syntaxes and custom themes loaded at runtime.
+struct Registry {
+    syntaxes: Arc<parsing::SyntaxSet>,
+    themes: Vec<Loaded>,
+}
+
+/// A [`Theme`] loaded at runtime.
+struct Loaded {
+    name: String,
+    theme: Arc<highlighting::Theme>,
+    generation: usize,
+}
+
+fn registry() -> RwLockReadGuard<'static, Registry> {
+    REGISTRY.read().expect("Read highlighter registry")
+}
+
+fn registry_mut() -> RwLockWriteGuard<'static, Registry> {
+    REGISTRY.write().expect("Write highlighter registry")
+}
+
+/// Loads a `.sublime-syntax` definition, making it available to any new
+/// [`Highlighter`].
+///
+/// The syntax is selected by matching the [`Settings::extension`] against
+/// the file extensions or the name of the definition.
+pub fn load_syntax(definition: impl AsRef<[u8]>) -> Result<(), Error> {
+    let definition = std::str::from_utf8(definition.as_ref())
+        .map_err(|error| Error::InvalidSyntax(error.to_string()))?;
+
+    let syntax =
+        parsing::SyntaxDefinition::load_from_str(definition, false, None)
+            .map_err(|error| Error::InvalidSyntax(error.to_string()))?;
+
+    add_syntaxes(|builder| {
+        builder.add(syntax);
+        Ok(())
+    })
+}
+
+/// Loads the `.sublime-syntax` definitions at the given path, making them
+/// available to any new [`Highlighter`].
+///
+/// The path can be a single definition or a folder containing them.
+pub fn load_syntax_from_path(path: impl AsRef<Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+
+    if path.is_dir() {
+        return add_syntaxes(|builder| {
+            builder
+                .add_from_folder(path, false)
+                .map_err(|error| Error::InvalidSyntax(error.to_string()))
+        });
+    }
+
+    let definition = std::fs::read_to_string(path)
+        .map_err(|error| Error::Io(error.into()))?;
+
+    let syntax = parsing::SyntaxDefinition::load_from_str(
+        &definition,
+        false,
+        path.file_stem().and_then(|name| name.to_str()),
+    )
+    .map_err(|error| Error::InvalidSyntax(error.to_string()))?;
+
+    add_syntaxes(|builder| {
+        builder.add(syntax);
+        Ok(())
+    })
+}
+
+fn add_syntaxes(
+    f: impl FnOnce(&mut parsing::SyntaxSetBuilder) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let mut registry = registry_mut();
+
+    let mut builder =
+        parsing::SyntaxSet::clone(&registry.syntaxes).into_builder();
+    f(&mut builder)?;
+
+    registry.syntaxes = Arc::new(builder.build());
+
+    Ok(())
+}
+
+/// An error produced when loading a syntax or a [`Theme`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The syntax definition is invalid.
+    #[error("invalid syntax definition: {0}")]
+    InvalidSyntax(String),
+    /// The theme is invalid.
+    #[error("invalid theme: {0}")]
+    InvalidTheme(String),
+    /// The tree-sitter query is invalid.
+    ///
+    /// It is only produced when the `tree-sitter` feature is enabled.
+    #[error("invalid query: {0}")]
+    InvalidQuery(String),
+    /// The file could not be read.
+    #[error("the file could not be read: {0}")]
+    Io(Arc<io::Error>),
+}
+
 /// A syntax highlighter.
 #[derive(Debug)]
 pub struct Highlighter {
-    syntax: &'static parsing::SyntaxReference,
-    highlighter: highlighting::Highlighter<'static>,
+    syntaxes: Arc<parsing::SyntaxSet>,
+    syntax: parsing::SyntaxReference,
+    theme: Option<Arc<highlighting::Theme>>,
     caches: Vec<(parsing::ParseState, parsing::ScopeStack)>,
     current_line: usize,
 }
@@ -34,33 +185,28 @@ impl highlighter::Highlighter for Highlighter {
         Box<dyn Iterator<Item = (Range<usize>, Self::Highlight)> + 'a>;
 
     fn new(settings: &Self::Settings) -> Self {
-        let syntax = SYNTAXES
-            .find_syntax_by_token(&settings.extension)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+        let syntaxes = registry().syntaxes.clone();
+        let syntax = find_syntax(&syntaxes, &settings.extension);
 
-        let highlighter = highlighting::Highlighter::new(
-            &THEMES.themes[settings.theme.key()],
-        );
+        let theme = settings.theme.get();
 
-        let parser = parsing::ParseState::new(syntax);
+        let parser = parsing::ParseState::new(&syntax);
         let stack = parsing::ScopeStack::new();
 
         Highlighter {
+            syntaxes,
             syntax,
-            highlighter,
+            theme,
             caches: vec![(parser, stack)],
             current_line: 0,
         }
     }
 
     fn update(&mut self, new_settings: &Self::Settings) {
-        self.syntax = SYNTAXES
-            .find_syntax_by_token(&new_settings.extension)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+        self.syntaxes = registry().syntaxes.clone();
+        self.syntax = find_syntax(&self.syntaxes, &new_settings.extension);
 
-        self.highlighter = highlighting::Highlighter::new(
-            &THEMES.themes[new_settings.theme.key()],
-        );
+        self.theme = new_settings.theme.get();
 
         // Restart the highlighter
         self.change_line(0);
@@ -80,7 +226,7 @@ impl highlighter::Highlighter for Highlighter {
         let (parser, stack) =
             self.caches.last().cloned().unwrap_or_else(|| {
                 (
-                    parsing::ParseState::new(self.syntax),
+                    parsing::ParseState::new(&self.syntax),
                     parsing::ScopeStack::new(),
                 )
             });
@@ -101,9 +247,10 @@ impl highlighter::Highlighter for Highlighter {
         let (parser, stack) =
             self.caches.last_mut().expect("Caches must not be empty");
 
-        let ops = parser.parse_line(line, &SYNTAXES).unwrap_or_default();
+        let ops = parser.parse_line(line, &self.syntaxes).unwrap_or_default();
 
-        let highlighter = &self.highlighter;
+        let highlighter =
+            self.theme.as_deref().map(highlighting::Highlighter::new);
 
         Box::new(
             ScopeRangeIterator {
@@ -120,9 +267,7 @@ impl highlighter::Highlighter for Highlighter {
                 } else {
                     Some((
                         range,
-                        Highlight(
-                            highlighter.style_mod_for_stack(&stack.scopes),
-                        ),
+                        Highlight::new(highlighter.as_ref(), &stack.scopes),
                     ))
                 }
             }),
@@ -134,6 +279,16 @@ impl highlighter::Highlighter for Highlighter {
     }
 }
 
+fn find_syntax(
+    syntaxes: &parsing::SyntaxSet,
+    extension: &str,
+) -> parsing::SyntaxReference {
+    syntaxes
+        .find_syntax_by_token(extension)
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text())
+        .clone()
+}
+
 /// The settings of a [`Highlighter`].
 #[derive(Debug, Clone, PartialEq)]
 pub struct Settings {
@@ -144,24 +299,55 @@ pub struct Settings {
     /// The extension of the file to highlight.
     ///
     /// The [`Highlighter`] will use the extension to automatically determine
-    /// the grammar to use for highlighting.
+    /// the grammar to use for highlighting, including any syntax loaded with
+    /// [`load_syntax`].
     pub extension: String,
 }
 
 /// A highlight produced by a [`Highlighter`].
 #[derive(Debug)]
-pub struct Highlight(highlighting::StyleModifier);
+pub struct Highlight {
+    style: highlighting::StyleModifier,
+    kind: Option<Kind>,
+}
 
 impl Highlight {
+    /// Creates the [`Highlight`] of the given stack of scopes.
+    ///
+    /// With a theme [`highlighting::Highlighter`], only its style is known.
+    /// Otherwise, only its [`Kind`] is.
+    fn new(
+        highlighter: Option<&highlighting::Highlighter<'_>>,
+        scopes: &[parsing::Scope],
+    ) -> Self {
+        match highlighter {
+            Some(highlighter) => Highlight {
+                style: highlighter.style_mod_for_stack(scopes),
+                kind: None,
+            },
+            None => Highlight {
+                style: highlighting::StyleModifier::default(),
+                kind: kind_of(scopes),
+            },
+        }
+    }
+
     /// Returns the color of this [`Highlight`].
     ///
     /// If `None`, the original text color should be unchanged.
     pub fn color(&self) -> Option<Color> {
-        self.0.foreground.map(|color| {
+        self.style.foreground.map(|color| {
             Color::from_rgba8(color.r, color.g, color.b, color.a as f32 / 255.0)
         })
     }
 
+    /// Returns the [`Kind`] of the highlighted text, if it is known.
+    ///
+    /// It is only known when highlighting with [`Theme::Palette`].
+    pub fn kind(&self) -> Option<Kind> {
+        self.kind
+    }
+
     /// Returns the font of this [`Highlight`].
     ///
     /// If `None`, the original font should be unchanged.
@@ -181,6 +367,30 @@ impl Highlight {
             font: self.font(),
         }
     }
+
+    /// Returns the [`Format`] of the [`Highlight`] using the colors of the
+    /// palette of the given application theme, instead of the colors of the
+    /// highlighting [`Theme`].
+    ///
+    /// It can be used with [`Theme::Palette`] as the formatting function of
+    /// a text editor, so that highlighted code follows the theme of the
+    /// application, including any custom palette.
+    pub fn to_palette_format(&self, theme: &core::Theme) -> Format<Font> {
+        Format {
+            color: self.kind.map(|kind| kind.color(theme.extended_palette())),
+            font: self.font(),
+        }
+    }
+}
+
+/// Finds the [`Kind`] of the innermost scope of the stack that has one.
+fn kind_of(scopes: &[parsing::Scope]) -> Option<Kind> {
+    scopes.iter().rev().find_map(|scope| {
+        KINDS
+            .iter()
+            .find(|(prefix, _)| prefix.is_prefix_of(*scope))
+            .map(|(_, kind)| *kind)
+    })?
 }
 
 /// A highlighting theme.
@@ -192,10 +402,27 @@ pub enum Theme {
     Base16Ocean,
     Base16Eighties,
     InspiredGitHub,
+    /// A theme loaded at runtime with [`Theme::load`].
+    Custom(Custom),
+    /// Follows the palette of the application theme.
+    ///
+    /// A [`Highlight`] only has a [`Kind`] in this mode, so it must be
+    /// formatted with [`Highlight::to_palette_format`].
+    Palette,
+}
+
+/// A handle to a [`Theme`] loaded at runtime.
+///
+/// Replacing a loaded theme produces a new handle, so that highlighters
+/// notice the change in their [`Settings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Custom {
+    index: usize,
+    generation: usize,
 }
 
 impl Theme {
-    /// A static slice containing all the available themes.
+    /// A static slice containing all the built-in themes.
     pub const ALL: &'static [Self] = &[
         Self::SolarizedDark,
         Self::Base16Mocha,
@@ -204,6 +431,58 @@ impl Theme {
         Self::InspiredGitHub,
     ];
 
+    /// Loads a `.tmTheme` file from its contents.
+    ///
+    /// The loaded [`Theme`] stays available for the rest of the program.
+    /// Loading a theme with the same name as a previously loaded one
+    /// replaces it, returning a new handle.
+    pub fn load(theme: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let theme = highlighting::ThemeSet::load_from_reader(
+            &mut io::Cursor::new(theme.as_ref()),
+        )
+        .map_err(|error| Error::InvalidTheme(error.to_string()))?;
+
+        Ok(Self::register(theme, None))
+    }
+
+    /// Loads the `.tmTheme` file at the given path.
+    ///
+    /// The loaded [`Theme`] stays available for the rest of the program.
+    /// Loading a theme with the same name as a previously loaded one
+    /// replaces it, returning a new handle.
+    pub fn load_from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        let theme = highlighting::ThemeSet::get_theme(path).map_err(
+            |error| match error {
+                syntect::LoadingError::Io(error) => Error::Io(error.into()),
+                error => Error::InvalidTheme(error.to_string()),
+            },
+        )?;
+
+        Ok(Self::register(
+            theme,
+            path.file_stem().and_then(|name| name.to_str()),
+        ))
+    }
+
+    /// Returns all the available themes, including the ones loaded at
+    /// runtime.
+    pub fn available() -> Vec<Self> {
+        let registry = registry();
+
+        Self::ALL
+            .iter()
+            .copied()
+            .chain(registry.themes.iter().enumerate().map(|(index, loaded)| {
+                Self::Custom(Custom {
+                    index,
+                    generation: loaded.generation,
+                })
+            }))
+            .collect()
+    }
+
     /// Returns `true` if the [`Theme`] is dark, and false otherwise.
     pub fn is_dark(self) -> bool {
         match self {
@@ -211,18 +490,74 @@ impl Theme {
             | Self::Base16Mocha
             | Self::Base16Ocean
             | Self::Base16Eighties => true,
-            Self::InspiredGitHub => false,
+            Self::InspiredGitHub | Self::Palette => false,
+            Self::Custom(_) => self.get().is_some_and(|theme| {
+                theme.settings.background.is_some_and(|background| {
+                    let luminance = 0.299 * f32::from(background.r)
+                        + 0.587 * f32::from(background.g)
+                        + 0.114 * f32::from(background.b);
+
+                    luminance < 128.0
+                })
+            }),
         }
     }
 
-    fn key(self) -> &'static str {
-        match self {
+    fn register(
+        theme: highlighting::Theme,
+        fallback_name: Option<&str>,
+    ) -> Self {
+        let name = theme
+            .name
+            .clone()
+            .or_else(|| fallback_name.map(str::to_owned))
+            .unwrap_or_else(|| String::from("Custom"));
+
+        let theme = Arc::new(theme);
+        let mut registry = registry_mut();
+
+        if let Some(index) = registry
+            .themes
+            .iter()
+            .position(|loaded| loaded.name == name)
+        {
+            let loaded = &mut registry.themes[index];
+
+            loaded.theme = theme;
+            loaded.generation += 1;
+
+            return Self::Custom(Custom {
+                index,
+                generation: loaded.generation,
+            });
+        }
+
+        registry.themes.push(Loaded {
+            name,
+            theme,
+            generation: 0,
+        });
+
+        Self::Custom(Custom {
+            index: registry.themes.len() - 1,
+            generation: 0,
+        })
+    }
+
+    fn get(self) -> Option<Arc<highlighting::Theme>> {
+        let key = match self {
             Theme::SolarizedDark => "Solarized (dark)",
             Theme::Base16Mocha => "base16-mocha.dark",
             Theme::Base16Ocean => "base16-ocean.dark",
             Theme::Base16Eighties => "base16-eighties.dark",
             Theme::InspiredGitHub => "InspiredGitHub",
-        }
+            Theme::Custom(Custom { index, .. }) => {
+                return Some(registry().themes[index].theme.clone());
+            }
+            Theme::Palette => return None,
+        };
+
+        Some(THEMES[key].clone())
     }
 }
 
@@ -234,6 +569,10 @@ impl std::fmt::Display for Theme {
             Theme::Base16Ocean => write!(f, "Ocean"),
             Theme::Base16Eighties => write!(f, "Eighties"),
             Theme::InspiredGitHub => write!(f, "Inspired GitHub"),
+            Theme::Custom(Custom { index, .. }) => {
+                write!(f, "{}", registry().themes[*index].name)
+            }
+            Theme::Palette => write!(f, "Palette"),
         }
     }
 }
@@ -272,3 +611,133 @@ impl Iterator for ScopeRangeIterator {
         Some((range, op))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::highlighter::Highlighter as _;
+
+    /// Returns a `.tmTheme` with the given name that colors comments with
+    /// the given color.
+    fn tm_theme(name: &str, comment: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0">
+<dict>
+    <key>name</key>
+    <string>{name}</string>
+    <key>settings</key>
+    <array>
+        <dict>
+            <key>settings</key>
+            <dict>
+                <key>background</key>
+                <string>#101010</string>
+                <key>foreground</key>
+                <string>#EEEEEE</string>
+            </dict>
+        </dict>
+        <dict>
+            <key>scope</key>
+            <string>comment</string>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>{comment}</string>
+            </dict>
+        </dict>
+    </array>
+</dict>
+</plist>"#
+        )
+    }
+
+    /// Highlights a Rust comment and returns the highlight of its text.
+    fn highlight_comment(highlighter: &mut Highlighter) -> Highlight {
+        highlighter.change_line(0);
+
+        highlighter
+            .highlight_line("// Truth")
+            .last()
+            .map(|(_, highlight)| highlight)
+            .expect("Highlight comment")
+    }
+
+    fn settings(theme: Theme) -> Settings {
+        Settings {
+            theme,
+            extension: String::from("rs"),
+        }
+    }
+
+    #[test]
+    fn it_loads_custom_themes() {
+        let theme =
+            Theme::load(tm_theme("Loaded", "#FF0000")).expect("Load theme");
+
+        assert_eq!(theme.to_string(), "Loaded");
+        assert!(theme.is_dark());
+        assert!(Theme::available().contains(&theme));
+
+        let mut highlighter = Highlighter::new(&settings(theme));
+
+        assert_eq!(
+            highlight_comment(&mut highlighter).color(),
+            Some(Color::from_rgb8(0xFF, 0x00, 0x00))
+        );
+
+        assert!(matches!(
+            Theme::load("not a theme"),
+            Err(Error::InvalidTheme(_))
+        ));
+    }
+
+    #[test]
+    fn it_replaces_themes_with_the_same_name() {
+        let old =
+            Theme::load(tm_theme("Replaced", "#FF0000")).expect("Load theme");
+
+        let mut highlighter = Highlighter::new(&settings(old));
+
+        let new =
+            Theme::load(tm_theme("Replaced", "#0000FF")).expect("Load theme");
+
+        assert_ne!(settings(old), settings(new));
+        assert_eq!(new.to_string(), "Replaced");
+
+        let available = Theme::available();
+
+        assert!(available.contains(&new));
+        assert!(!available.contains(&old));
+
+        highlighter.update(&settings(new));
+
+        assert_eq!(
+            highlight_comment(&mut highlighter).color(),
+            Some(Color::from_rgb8(0x00, 0x00, 0xFF))
+        );
+    }
+
+    #[test]
+    fn it_formats_highlights_with_the_palette() {
+        let theme = core::Theme::Dark;
+        let palette = theme.extended_palette();
+
+        let mut highlighter = Highlighter::new(&settings(Theme::Palette));
+        let highlight = highlight_comment(&mut highlighter);
+
+        assert_eq!(highlight.kind(), Some(Kind::Comment));
+        assert_eq!(highlight.color(), None);
+        assert_eq!(
+            highlight.to_palette_format(&theme).color,
+            Some(Kind::Comment.color(palette))
+        );
+
+        let mut highlighter = Highlighter::new(&settings(Theme::SolarizedDark));
+        let highlight = highlight_comment(&mut highlighter);
+
+        assert_eq!(highlight.kind(), None);
+        assert!(highlight.color().is_some());
+        assert_eq!(highlight.to_palette_format(&theme).color, None);
+    }
+}
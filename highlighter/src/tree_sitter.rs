@@ -0,0 +1,538 @@
+//! A syntax highlighter backed by [tree-sitter].
+//!
+//! [tree-sitter]: https://tree-sitter.github.io
+mod source;
+
+use source::{Position, Source};
+
+use crate::core::text::highlighter;
+use crate::{Error, Highlight, Settings};
+
+use once_cell::sync::Lazy;
+use std::fmt;
+use std::ops::Range;
+use std::sync::{Arc, RwLock};
+use syntect::highlighting;
+use syntect::parsing;
+use tree_sitter::{
+    InputEdit, Language, Parser, Point, Query, QueryCursor, Tree,
+};
+
+static GRAMMARS: Lazy<RwLock<Vec<Arc<Grammar>>>> =
+    Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Registers a [`Grammar`], making it available to any new [`Highlighter`].
+///
+/// A previous [`Grammar`] with the same name is replaced.
+pub fn register(grammar: Grammar) {
+    let mut grammars = GRAMMARS.write().expect("Write grammar registry");
+
+    grammars.retain(|current| current.name != grammar.name);
+    grammars.push(Arc::new(grammar));
+}
+
+/// Finds a registered [`Grammar`] by its name or one of its extensions.
+fn find_grammar(token: &str) -> Option<Arc<Grammar>> {
+    let grammars = GRAMMARS.read().expect("Read grammar registry");
+
+    grammars
+        .iter()
+        .find(|grammar| {
+            grammar.name.eq_ignore_ascii_case(token)
+                || grammar
+                    .extensions
+                    .iter()
+                    .any(|extension| extension.eq_ignore_ascii_case(token))
+        })
+        .cloned()
+}
+
+/// The grammar of a language, together with the queries used to highlight
+/// it.
+pub struct Grammar {
+    name: String,
+    extensions: Vec<String>,
+    language: Language,
+    highlights: Query,
+    scopes: Vec<parsing::Scope>,
+    injections: Option<Query>,
+}
+
+impl Grammar {
+    /// Creates a new [`Grammar`] with the given name, tree-sitter
+    /// [`Language`], and highlights query.
+    ///
+    /// The captures of the query (like `@keyword` or `@string.special`)
+    /// are mapped to the scopes of the highlighting [`Theme`].
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn new(
+        name: impl Into<String>,
+        language: Language,
+        highlights: &str,
+    ) -> Result<Self, Error> {
+        let highlights = Query::new(&language, highlights)
+            .map_err(|error| Error::InvalidQuery(error.to_string()))?;
+
+        let scopes = highlights
+            .capture_names()
+            .iter()
+            .map(|name| scope(name))
+            .collect();
+
+        Ok(Self {
+            name: name.into(),
+            extensions: Vec::new(),
+            language,
+            highlights,
+            scopes,
+            injections: None,
+        })
+    }
+
+    /// Sets the file extensions of the [`Grammar`].
+    pub fn extensions(
+        mut self,
+        extensions: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.extensions = extensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the injections query of the [`Grammar`].
+    ///
+    /// Injected languages are found by name among the registered grammars,
+    /// either with an `@injection.language` capture or an
+    /// `injection.language` property.
+    pub fn injections(mut self, injections: &str) -> Result<Self, Error> {
+        self.injections = Some(
+            Query::new(&self.language, injections)
+                .map_err(|error| Error::InvalidQuery(error.to_string()))?,
+        );
+
+        Ok(self)
+    }
+}
+
+impl fmt::Debug for Grammar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Grammar")
+            .field("name", &self.name)
+            .field("extensions", &self.extensions)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the scope of the highlighting theme that best matches the given
+/// capture name.
+fn scope(capture: &str) -> parsing::Scope {
+    const SCOPES: &[(&str, &str)] = &[
+        ("attribute", "entity.other.attribute-name"),
+        ("boolean", "constant.language"),
+        ("comment", "comment"),
+        ("constant", "constant"),
+        ("constant.builtin", "constant.language"),
+        ("constructor", "entity.name.type"),
+        ("escape", "constant.character.escape"),
+        ("function", "entity.name.function"),
+        ("function.builtin", "support.function"),
+        ("function.macro", "entity.name.function.macro"),
+        ("keyword", "keyword"),
+        ("label", "entity.name.label"),
+        ("module", "entity.name.namespace"),
+        ("number", "constant.numeric"),
+        ("operator", "keyword.operator"),
+        ("property", "variable.other.member"),
+        ("punctuation", "punctuation"),
+        ("string", "string"),
+        ("string.special", "string.regexp"),
+        ("tag", "entity.name.tag"),
+        ("type", "entity.name.type"),
+        ("type.builtin", "storage.type"),
+        ("variable", "variable"),
+        ("variable.builtin", "variable.language"),
+        ("variable.parameter", "variable.parameter"),
+    ];
+
+    let mut name = capture;
+
+    loop {
+        if let Some((_, scope)) = SCOPES.iter().find(|(key, _)| *key == name) {
+            return parsing::Scope::new(scope).unwrap_or_default();
+        }
+
+        match name.rsplit_once('.') {
+            Some((parent, _)) => name = parent,
+            None => return parsing::Scope::new(capture).unwrap_or_default(),
+        }
+    }
+}
+
+/// A syntax highlighter backed by tree-sitter.
+///
+/// The lines fed to the [`Highlighter`] are kept in a single source, which
+/// is reparsed incrementally once per highlighting pass.
+pub struct Highlighter {
+    grammar: Option<Arc<Grammar>>,
+    theme: Option<Arc<highlighting::Theme>>,
+    parser: Parser,
+    injector: Parser,
+    cursor: QueryCursor,
+    tree: Option<Tree>,
+    injections: Vec<Layer>,
+    is_dirty: bool,
+    source: Source,
+    current_line: usize,
+}
+
+struct Layer {
+    grammar: Arc<Grammar>,
+    tree: Tree,
+    /// The start of the first range of the injection, used to reuse the
+    /// [`Tree`] of the [`Layer`] when reparsing.
+    start: Option<usize>,
+}
+
+impl Highlighter {
+    /// Replaces the source from the start of the line at the given index
+    /// with the given text, editing the current trees.
+    fn replace_from(&mut self, index: usize, text: &str) {
+        let Some(change) = self.source.replace_from(index, text) else {
+            return;
+        };
+
+        let edit = InputEdit {
+            start_byte: change.start_byte,
+            old_end_byte: change.old_end_byte,
+            new_end_byte: change.new_end_byte,
+            start_position: to_point(change.start_position),
+            old_end_position: to_point(change.old_end_position),
+            new_end_position: to_point(change.new_end_position),
+        };
+
+        if let Some(tree) = &mut self.tree {
+            tree.edit(&edit);
+        }
+
+        for layer in &mut self.injections {
+            layer.tree.edit(&edit);
+            layer.start = layer.start.and_then(|start| {
+                if start >= edit.old_end_byte {
+                    Some(start - edit.old_end_byte + edit.new_end_byte)
+                } else {
+                    (start < edit.start_byte).then_some(start)
+                }
+            });
+        }
+
+        self.is_dirty = true;
+    }
+
+    fn parse(&mut self, grammar: &Grammar) {
+        self.tree = self.parser.parse(self.source.as_str(), self.tree.as_ref());
+
+        let layers = std::mem::take(&mut self.injections);
+
+        self.injections = self
+            .tree
+            .as_ref()
+            .map(|tree| {
+                injections(
+                    &mut self.injector,
+                    grammar,
+                    tree,
+                    self.source.as_str(),
+                    layers,
+                )
+            })
+            .unwrap_or_default();
+
+        self.is_dirty = false;
+    }
+}
+
+impl highlighter::Highlighter for Highlighter {
+    type Settings = Settings;
+    type Highlight = Highlight;
+
+    type Iterator<'a> =
+        Box<dyn Iterator<Item = (Range<usize>, Self::Highlight)> + 'a>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        let grammar = find_grammar(&settings.extension);
+        let mut parser = Parser::new();
+
+        if let Some(grammar) = &grammar {
+            // The language was already validated by its queries
+            let _ = parser.set_language(&grammar.language);
+        }
+
+        Highlighter {
+            grammar,
+            theme: settings.theme.get(),
+            parser,
+            injector: Parser::new(),
+            cursor: QueryCursor::new(),
+            tree: None,
+            injections: Vec::new(),
+            is_dirty: true,
+            source: Source::default(),
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        *self = Self::new(new_settings);
+    }
+
+    fn change_line(&mut self, line: usize) {
+        // Lines after the changed one are kept, so their old contents can
+        // be reused if they did not change
+        self.current_line =
+            self.current_line.min(line).min(self.source.line_count());
+    }
+
+    fn sync<'a>(&mut self, lines: impl Iterator<Item = &'a str>) {
+        let mut text = String::new();
+
+        for (i, line) in lines.enumerate() {
+            if i > 0 {
+                text.push('\n');
+            }
+
+            text.push_str(line);
+        }
+
+        self.replace_from(self.current_line, &text);
+
+        if let Some(grammar) = self.grammar.clone() {
+            if self.is_dirty {
+                self.parse(&grammar);
+            }
+        }
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        let index = self.current_line;
+        self.current_line += 1;
+
+        // Lines are normally synchronized beforehand. Otherwise, the rest of
+        // the source is replaced with the given line.
+        if self
+            .source
+            .line_range(index)
+            .map(|range| &self.source.as_str()[range])
+            != Some(line)
+        {
+            self.replace_from(index, line);
+        }
+
+        let Some(grammar) = self.grammar.clone() else {
+            return Box::new(std::iter::empty());
+        };
+
+        if self.is_dirty {
+            self.parse(&grammar);
+        }
+
+        let (Some(tree), Some(range)) =
+            (&self.tree, self.source.line_range(index))
+        else {
+            return Box::new(std::iter::empty());
+        };
+
+        let mut painted = vec![None; range.len()];
+
+        paint(
+            &mut self.cursor,
+            &grammar,
+            tree,
+            self.source.as_str(),
+            range.clone(),
+            0,
+            &mut painted,
+        );
+
+        for layer in &self.injections {
+            paint(
+                &mut self.cursor,
+                &layer.grammar,
+                &layer.tree,
+                self.source.as_str(),
+                range.clone(),
+                1,
+                &mut painted,
+            );
+        }
+
+        let highlighter =
+            self.theme.as_deref().map(highlighting::Highlighter::new);
+        let mut highlights = Vec::new();
+        let mut start = 0;
+
+        for end in 1..=painted.len() {
+            if end < painted.len()
+                && painted[end].map(|paint| paint.scope)
+                    == painted[start].map(|paint| paint.scope)
+            {
+                continue;
+            }
+
+            if let Some(paint) = painted[start] {
+                highlights.push((
+                    start..end,
+                    Highlight::new(highlighter.as_ref(), &[paint.scope]),
+                ));
+            }
+
+            start = end;
+        }
+
+        Box::new(highlights.into_iter())
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+impl fmt::Debug for Highlighter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Highlighter")
+            .field("grammar", &self.grammar)
+            .field("current_line", &self.current_line)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The capture painted over a byte of a line.
+#[derive(Debug, Clone, Copy)]
+struct Paint {
+    scope: parsing::Scope,
+    length: usize,
+    depth: usize,
+}
+
+/// Paints the captures of the highlights query of a [`Grammar`] over the
+/// bytes of a line.
+///
+/// Captures of deeper layers and smaller nodes take precedence.
+fn paint(
+    cursor: &mut QueryCursor,
+    grammar: &Grammar,
+    tree: &Tree,
+    source: &str,
+    line: Range<usize>,
+    depth: usize,
+    painted: &mut [Option<Paint>],
+) {
+    let _ = cursor.set_byte_range(line.clone());
+
+    for (query_match, index) in cursor.captures(
+        &grammar.highlights,
+        tree.root_node(),
+        source.as_bytes(),
+    ) {
+        let capture = query_match.captures[index];
+        let node = capture.node.byte_range();
+
+        let start = node.start.clamp(line.start, line.end) - line.start;
+        let end = node.end.clamp(line.start, line.end) - line.start;
+
+        let paint = Paint {
+            scope: grammar.scopes[capture.index as usize],
+            length: node.len(),
+            depth,
+        };
+
+        for byte in &mut painted[start..end] {
+            let is_covered = byte.is_some_and(|current| {
+                current.depth > depth
+                    || current.depth == depth && current.length <= paint.length
+            });
+
+            if !is_covered {
+                *byte = Some(paint);
+            }
+        }
+    }
+}
+
+/// Parses the languages injected in the given [`Tree`].
+///
+/// The trees of the previous layers are reused when their injection starts
+/// at the same place.
+fn injections(
+    parser: &mut Parser,
+    grammar: &Grammar,
+    tree: &Tree,
+    source: &str,
+    mut previous: Vec<Layer>,
+) -> Vec<Layer> {
+    let Some(query) = &grammar.injections else {
+        return Vec::new();
+    };
+
+    let content = query.capture_index_for_name("injection.content");
+    let language = query.capture_index_for_name("injection.language");
+
+    let mut cursor = QueryCursor::new();
+    let mut layers = Vec::new();
+
+    for query_match in
+        cursor.matches(query, tree.root_node(), source.as_bytes())
+    {
+        let mut name = query
+            .property_settings(query_match.pattern_index)
+            .iter()
+            .find(|property| &*property.key == "injection.language")
+            .and_then(|property| property.value.as_deref());
+
+        let mut ranges = Vec::new();
+
+        for capture in query_match.captures {
+            if Some(capture.index) == language {
+                name = capture.node.utf8_text(source.as_bytes()).ok();
+            } else if Some(capture.index) == content {
+                ranges.push(capture.node.range());
+            }
+        }
+
+        let Some(grammar) = name.and_then(find_grammar) else {
+            continue;
+        };
+
+        if ranges.is_empty()
+            || parser.set_language(&grammar.language).is_err()
+            || parser.set_included_ranges(&ranges).is_err()
+        {
+            continue;
+        }
+
+        let start = ranges.first().map(|range| range.start_byte);
+
+        let old_tree = previous
+            .iter()
+            .position(|layer| {
+                Arc::ptr_eq(&layer.grammar, &grammar) && layer.start == start
+            })
+            .map(|index| previous.swap_remove(index).tree);
+
+        if let Some(tree) = parser.parse(source, old_tree.as_ref()) {
+            layers.push(Layer {
+                grammar,
+                tree,
+                start,
+            });
+        }
+    }
+
+    layers
+}
+
+fn to_point(position: Position) -> Point {
+    Point {
+        row: position.row,
+        column: position.column,
+    }
+}
@@ -0,0 +1,376 @@
+use std::ops::Range;
+
+/// The lines of a document, kept in a single string that can be parsed.
+#[derive(Debug, Default)]
+pub struct Source {
+    text: String,
+    lines: Vec<usize>,
+}
+
+/// An edit of a [`Source`], as expected by tree-sitter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub start_byte: usize,
+    pub old_end_byte: usize,
+    pub new_end_byte: usize,
+    pub start_position: Position,
+    pub old_end_position: Position,
+    pub new_end_position: Position,
+}
+
+/// A position in a [`Source`]; its column is measured in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Source {
+    /// Returns the whole text of the [`Source`].
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the amount of lines of the [`Source`].
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the byte range of the line at the given index, without its
+    /// line break.
+    pub fn line_range(&self, index: usize) -> Option<Range<usize>> {
+        let start = *self.lines.get(index)?;
+
+        let end = self
+            .lines
+            .get(index + 1)
+            .map_or(self.text.len(), |next| next - 1);
+
+        Some(start..end)
+    }
+
+    /// Replaces the text from the start of the line at the given index
+    /// with the given text.
+    ///
+    /// Only the bytes that differ from the current text are changed, so
+    /// inserting or removing lines does not invalidate the lines after them.
+    pub fn replace_from(&mut self, index: usize, text: &str) -> Option<Change> {
+        let index = index.min(self.lines.len());
+
+        let (start, separator) = match self.lines.get(index) {
+            Some(start) => (*start, ""),
+            None if index == 0 => (0, ""),
+            None => (self.text.len(), "\n"),
+        };
+
+        let new = format!("{separator}{text}");
+        let old = &self.text[start..];
+
+        if old == new {
+            return None;
+        }
+
+        let prefix = common_prefix(old, &new);
+        let suffix = common_suffix(&old[prefix..], &new[prefix..]);
+
+        let start_byte = start + prefix;
+        let old_end_byte = self.text.len() - suffix;
+        let new_end_byte = start + new.len() - suffix;
+
+        let start_position = position(&self.lines, start_byte);
+        let old_end_position = position(&self.lines, old_end_byte);
+
+        self.text.replace_range(
+            start_byte..old_end_byte,
+            &new[prefix..new.len() - suffix],
+        );
+
+        self.lines.truncate(index);
+        self.lines.push(start + separator.len());
+
+        let rest = start + separator.len();
+
+        self.lines.extend(
+            self.text[rest..]
+                .match_indices('\n')
+                .map(|(i, _)| rest + i + 1),
+        );
+
+        Some(Change {
+            start_byte,
+            old_end_byte,
+            new_end_byte,
+            start_position,
+            old_end_position,
+            new_end_position: position(&self.lines, new_end_byte),
+        })
+    }
+}
+
+/// Returns the length in bytes of the common prefix of two strings.
+fn common_prefix(a: &str, b: &str) -> usize {
+    let mut length =
+        a.bytes().zip(b.bytes()).take_while(|(a, b)| a == b).count();
+
+    while !a.is_char_boundary(length) {
+        length -= 1;
+    }
+
+    length
+}
+
+/// Returns the length in bytes of the common suffix of two strings.
+fn common_suffix(a: &str, b: &str) -> usize {
+    let mut length = a
+        .bytes()
+        .rev()
+        .zip(b.bytes().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    while !a.is_char_boundary(a.len() - length)
+        || !b.is_char_boundary(b.len() - length)
+    {
+        length -= 1;
+    }
+
+    length
+}
+
+/// Returns the [`Position`] of the given byte, given the start of every
+/// line.
+fn position(lines: &[usize], byte: usize) -> Position {
+    let row = lines
+        .partition_point(|start| *start <= byte)
+        .saturating_sub(1);
+
+    Position {
+        row,
+        column: byte - lines.get(row).copied().unwrap_or(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(text: &str) -> Source {
+        let mut source = Source::default();
+        let _ = source.replace_from(0, text);
+
+        source
+    }
+
+    fn at(row: usize, column: usize) -> Position {
+        Position { row, column }
+    }
+
+    /// Replaces the text of the [`Source`] from the given line, checking
+    /// that the [`Change`] describes the difference between both texts.
+    fn replace(source: &mut Source, index: usize, text: &str) -> Change {
+        let old = source.as_str().to_owned();
+
+        let change = source
+            .replace_from(index, text)
+            .expect("Text should change");
+
+        let new = source.as_str();
+
+        assert_eq!(old[..change.start_byte], new[..change.start_byte]);
+        assert_eq!(old[change.old_end_byte..], new[change.new_end_byte..]);
+
+        change
+    }
+
+    #[test]
+    fn it_finds_common_prefixes_and_suffixes() {
+        assert_eq!(common_prefix("hello", "help"), 3);
+        assert_eq!(common_prefix("abc", "xyz"), 0);
+        assert_eq!(common_suffix("hello", "jello"), 4);
+        assert_eq!(common_suffix("abc", "abc"), 3);
+
+        // "é" and "è" share their first byte, "ù" and "ú" their last one
+        assert_eq!(common_prefix("aé", "aè"), 1);
+        assert_eq!(common_suffix("ùa", "úa"), 1);
+        assert_eq!(common_suffix("aé", "é"), 2);
+    }
+
+    #[test]
+    fn it_finds_positions_of_bytes() {
+        let lines = [0, 4, 5, 9];
+
+        assert_eq!(position(&lines, 0), at(0, 0));
+        assert_eq!(position(&lines, 3), at(0, 3));
+        assert_eq!(position(&lines, 4), at(1, 0));
+        assert_eq!(position(&lines, 8), at(2, 3));
+        assert_eq!(position(&lines, 12), at(3, 3));
+        assert_eq!(position(&[], 0), at(0, 0));
+    }
+
+    #[test]
+    fn it_keeps_the_start_of_every_line() {
+        let source = source("abc\n\nde\nf");
+
+        assert_eq!(source.line_count(), 4);
+        assert_eq!(source.line_range(0), Some(0..3));
+        assert_eq!(source.line_range(1), Some(4..4));
+        assert_eq!(source.line_range(2), Some(5..7));
+        assert_eq!(source.line_range(3), Some(8..9));
+        assert_eq!(source.line_range(4), None);
+    }
+
+    #[test]
+    fn it_inserts_and_deletes_text() {
+        let mut source = source("abc\ndef");
+
+        // At the start
+        assert_eq!(
+            replace(&mut source, 0, "xabc\ndef"),
+            Change {
+                start_byte: 0,
+                old_end_byte: 0,
+                new_end_byte: 1,
+                start_position: at(0, 0),
+                old_end_position: at(0, 0),
+                new_end_position: at(0, 1),
+            }
+        );
+
+        // In the middle
+        assert_eq!(
+            replace(&mut source, 1, "dYef"),
+            Change {
+                start_byte: 6,
+                old_end_byte: 6,
+                new_end_byte: 7,
+                start_position: at(1, 1),
+                old_end_position: at(1, 1),
+                new_end_position: at(1, 2),
+            }
+        );
+
+        // At the end
+        assert_eq!(
+            replace(&mut source, 1, "dY"),
+            Change {
+                start_byte: 7,
+                old_end_byte: 9,
+                new_end_byte: 7,
+                start_position: at(1, 2),
+                old_end_position: at(1, 4),
+                new_end_position: at(1, 2),
+            }
+        );
+
+        // Deleting at the start
+        assert_eq!(
+            replace(&mut source, 0, "abc\ndY"),
+            Change {
+                start_byte: 0,
+                old_end_byte: 1,
+                new_end_byte: 0,
+                start_position: at(0, 0),
+                old_end_position: at(0, 1),
+                new_end_position: at(0, 0),
+            }
+        );
+
+        assert_eq!(source.as_str(), "abc\ndY");
+        assert_eq!(source.replace_from(0, "abc\ndY"), None);
+    }
+
+    #[test]
+    fn it_edits_multi_byte_characters() {
+        let mut source = source("añb");
+
+        // "ñ" and "ò" share their first byte, which must not be kept alone
+        assert_eq!(
+            replace(&mut source, 0, "aòb"),
+            Change {
+                start_byte: 1,
+                old_end_byte: 3,
+                new_end_byte: 3,
+                start_position: at(0, 1),
+                old_end_position: at(0, 3),
+                new_end_position: at(0, 3),
+            }
+        );
+
+        assert_eq!(
+            replace(&mut source, 0, "aòüb"),
+            Change {
+                start_byte: 3,
+                old_end_byte: 3,
+                new_end_byte: 5,
+                start_position: at(0, 3),
+                old_end_position: at(0, 3),
+                new_end_position: at(0, 5),
+            }
+        );
+
+        assert_eq!(source.as_str(), "aòüb");
+    }
+
+    #[test]
+    fn it_edits_across_lines() {
+        let mut source = source("one\ntwo\nthree");
+
+        // Joining two lines
+        assert_eq!(
+            replace(&mut source, 0, "one two\nthree"),
+            Change {
+                start_byte: 3,
+                old_end_byte: 4,
+                new_end_byte: 4,
+                start_position: at(0, 3),
+                old_end_position: at(1, 0),
+                new_end_position: at(0, 4),
+            }
+        );
+
+        assert_eq!(source.line_count(), 2);
+        assert_eq!(source.line_range(1), Some(8..13));
+
+        // Splitting a line in two
+        assert_eq!(
+            replace(&mut source, 0, "one\ntwo\nthree"),
+            Change {
+                start_byte: 3,
+                old_end_byte: 4,
+                new_end_byte: 4,
+                start_position: at(0, 3),
+                old_end_position: at(0, 4),
+                new_end_position: at(1, 0),
+            }
+        );
+
+        // Appending lines after the last one
+        assert_eq!(
+            replace(&mut source, 3, "four"),
+            Change {
+                start_byte: 13,
+                old_end_byte: 13,
+                new_end_byte: 18,
+                start_position: at(2, 5),
+                old_end_position: at(2, 5),
+                new_end_position: at(3, 4),
+            }
+        );
+
+        // Removing every line after the first one
+        assert_eq!(
+            replace(&mut source, 1, ""),
+            Change {
+                start_byte: 4,
+                old_end_byte: 18,
+                new_end_byte: 4,
+                start_position: at(1, 0),
+                old_end_position: at(3, 4),
+                new_end_position: at(1, 0),
+            }
+        );
+
+        assert_eq!(source.as_str(), "one\n");
+        assert_eq!(source.line_count(), 2);
+    }
+}
@@ -1,4 +1,5 @@
 //! Compose existing renderers and create type-safe fallback strategies.
+use crate::core::font;
 use crate::core::image;
 use crate::core::renderer;
 use crate::core::svg;
@@ -10,6 +11,8 @@ use crate::graphics::compositor;
 use crate::graphics::mesh;
 
 use std::borrow::Cow;
+use std::io;
+use std::path::Path;
 
 /// A renderer `A` with a fallback strategy `B`.
 ///
@@ -339,6 +342,14 @@ where
         delegate!(self, compositor, compositor.load_font(font));
     }
 
+    fn load_font_file(&mut self, path: &Path) -> io::Result<()> {
+        delegate!(self, compositor, compositor.load_font_file(path))
+    }
+
+    fn font_faces(&self) -> Vec<font::Face> {
+        delegate!(self, compositor, compositor.font_faces())
+    }
+
     fn fetch_information(&self) -> compositor::Information {
         delegate!(self, compositor, compositor.fetch_information())
     }
@@ -635,3 +646,28 @@ where
 {
     type Compositor = Compositor<A::Compositor, B::Compositor>;
 }
+
+impl<A, B> renderer::Headless for Renderer<A, B>
+where
+    B: renderer::Headless,
+{
+    fn new(default_font: core::Font, default_text_size: core::Pixels) -> Self {
+        Self::Secondary(B::new(default_font, default_text_size))
+    }
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8> {
+        match self {
+            Self::Primary(_) => {
+                unreachable!("Headless fallback renderers are always secondary")
+            }
+            Self::Secondary(renderer) => {
+                renderer.screenshot(size, scale_factor, background_color)
+            }
+        }
+    }
+}
@@ -8,6 +8,7 @@ use crate::window;
 use std::any::Any;
 use std::borrow::Cow;
 use std::fmt;
+use std::path::PathBuf;
 
 /// An action that a [`Command`] can perform.
 ///
@@ -44,6 +45,18 @@ pub enum Action<T> {
         tagger: Box<dyn Fn(Result<(), font::Error>) -> T>,
     },
 
+    /// Load a font from a file.
+    LoadFontFile {
+        /// The path of the font file to load.
+        path: PathBuf,
+
+        /// The message to produce when the font has been loaded.
+        tagger: Box<dyn Fn(Result<(), font::Error>) -> T>,
+    },
+
+    /// List the font faces available to the renderer.
+    ListFonts(Box<dyn Fn(Vec<font::Face>) -> T>),
+
     /// A custom action supported by a specific runtime.
     Custom(Box<dyn Any>),
 }
@@ -75,6 +88,13 @@ impl<T> Action<T> {
                 bytes,
                 tagger: Box::new(move |result| f(tagger(result))),
             },
+            Self::LoadFontFile { path, tagger } => Action::LoadFontFile {
+                path,
+                tagger: Box::new(move |result| f(tagger(result))),
+            },
+            Self::ListFonts(tagger) => {
+                Action::ListFonts(Box::new(move |faces| f(tagger(faces))))
+            }
             Self::Custom(custom) => Action::Custom(custom),
         }
     }
@@ -94,6 +114,10 @@ impl<T> fmt::Debug for Action<T> {
             Self::System(action) => write!(f, "Action::System({action:?})"),
             Self::Widget(_action) => write!(f, "Action::Widget"),
             Self::LoadFont { .. } => write!(f, "Action::LoadFont"),
+            Self::LoadFontFile { path, .. } => {
+                write!(f, "Action::LoadFontFile({path:?})")
+            }
+            Self::ListFonts(_) => write!(f, "Action::ListFonts"),
             Self::Custom(_) => write!(f, "Action::Custom"),
         }
     }
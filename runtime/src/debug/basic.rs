@@ -1,12 +1,26 @@
 #![allow(missing_docs)]
 use crate::core::time;
 
+use std::any::Any;
 use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// The maximum amount of messages kept in the history of a [`struct@Debug`].
+///
+/// Once full, logging a message discards the oldest one, whose snapshot
+/// becomes the earliest state the debugger can travel back to.
+const HISTORY_LIMIT: usize = 1_000;
 
 /// A bunch of time measurements for debugging purposes.
+///
+/// It also keeps a history of the last 1000 messages, which can be exported
+/// and travelled through.
 #[derive(Debug)]
 pub struct Debug {
     is_enabled: bool,
+    is_inspecting: bool,
 
     startup_start: time::Instant,
     startup_duration: time::Duration,
@@ -30,7 +44,22 @@ pub struct Debug {
     render_durations: TimeBuffer,
 
     message_count: usize,
-    last_messages: VecDeque<String>,
+    history: VecDeque<Entry>,
+    initial_snapshot: Option<Box<dyn Any>>,
+    position: usize,
+    is_paused: bool,
+    steps: usize,
+    pending_travel: bool,
+}
+
+/// A message logged in the history of a [`struct@Debug`].
+#[derive(Debug)]
+struct Entry {
+    at: time::Duration,
+    message: String,
+    update: time::Duration,
+    view: Option<time::Duration>,
+    snapshot: Option<Box<dyn Any>>,
 }
 
 impl Debug {
@@ -40,6 +69,7 @@ impl Debug {
 
         Self {
             is_enabled: false,
+            is_inspecting: false,
             startup_start: now,
             startup_duration: time::Duration::from_secs(0),
 
@@ -62,12 +92,34 @@ impl Debug {
             render_durations: TimeBuffer::new(50),
 
             message_count: 0,
-            last_messages: VecDeque::new(),
+            history: VecDeque::new(),
+            initial_snapshot: None,
+            position: 0,
+            is_paused: false,
+            steps: 0,
+            pending_travel: false,
         }
     }
 
     pub fn toggle(&mut self) {
         self.is_enabled = !self.is_enabled;
+
+        // Hiding the debugger resumes any paused program
+        if !self.is_enabled {
+            self.is_paused = false;
+        }
+    }
+
+    /// Toggles the layout inspector.
+    ///
+    /// See [`UserInterface::inspect`](crate::UserInterface::inspect).
+    pub fn toggle_inspector(&mut self) {
+        self.is_inspecting = !self.is_inspecting;
+    }
+
+    /// Returns true if the layout inspector is enabled.
+    pub fn is_inspecting(&self) -> bool {
+        self.is_inspecting
     }
 
     pub fn startup_started(&mut self) {
@@ -83,7 +135,13 @@ impl Debug {
     }
 
     pub fn update_finished(&mut self) {
-        self.update_durations.push(self.update_start.elapsed());
+        let duration = self.update_start.elapsed();
+
+        self.update_durations.push(duration);
+
+        if let Some(entry) = self.history.back_mut() {
+            entry.update = duration;
+        }
     }
 
     pub fn view_started(&mut self) {
@@ -91,7 +149,13 @@ impl Debug {
     }
 
     pub fn view_finished(&mut self) {
-        self.view_durations.push(self.view_start.elapsed());
+        let duration = self.view_start.elapsed();
+
+        self.view_durations.push(duration);
+
+        if let Some(entry) = self.history.back_mut() {
+            let _ = entry.view.get_or_insert(duration);
+        }
     }
 
     pub fn layout_started(&mut self) {
@@ -127,15 +191,131 @@ impl Debug {
     }
 
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
-        self.last_messages.push_back(format!("{message:?}"));
+        // Logging a message after travelling back in time discards the
+        // messages that came after it
+        self.history.truncate(self.position);
 
-        if self.last_messages.len() > 10 {
-            let _ = self.last_messages.pop_front();
+        if self.history.len() == HISTORY_LIMIT {
+            if let Some(oldest) = self.history.pop_front() {
+                self.initial_snapshot = oldest.snapshot;
+            }
         }
 
+        self.history.push_back(Entry {
+            at: self.startup_start.elapsed(),
+            message: format!("{message:?}"),
+            update: time::Duration::ZERO,
+            view: None,
+            snapshot: None,
+        });
+
+        self.position = self.history.len();
         self.message_count += 1;
     }
 
+    /// Stores a snapshot of the state of the program after the last logged
+    /// message, or its initial state if no messages have been logged yet.
+    ///
+    /// Snapshots let the debugger travel back in time.
+    pub fn log_snapshot(
+        &mut self,
+        snapshot: impl FnOnce() -> Option<Box<dyn Any>>,
+    ) {
+        match self.history.back_mut() {
+            Some(entry) => entry.snapshot = snapshot(),
+            None => self.initial_snapshot = snapshot(),
+        }
+    }
+
+    /// Pauses or resumes the processing of messages.
+    ///
+    /// While paused, messages are queued until stepped through with
+    /// [`step`](Self::step).
+    pub fn toggle_pause(&mut self) {
+        if self.is_enabled {
+            self.is_paused = !self.is_paused;
+            self.steps = 0;
+        }
+    }
+
+    /// Allows a single queued message to be processed while paused.
+    pub fn step(&mut self) {
+        if self.is_enabled && self.is_paused {
+            self.steps += 1;
+        }
+    }
+
+    /// Returns how many of the given amount of pending messages can be
+    /// processed right now.
+    pub fn allowed_messages(&mut self, pending: usize) -> usize {
+        if !self.is_paused {
+            return pending;
+        }
+
+        let allowed = self.steps.min(pending);
+        self.steps -= allowed;
+
+        allowed
+    }
+
+    /// Travels back to the state before the current message, if a snapshot
+    /// of it is available.
+    pub fn travel_back(&mut self) {
+        if self.is_enabled && self.position > 0 {
+            self.travel_to(self.position - 1);
+        }
+    }
+
+    /// Travels forward to the state after the next message, if a snapshot of
+    /// it is available.
+    pub fn travel_forward(&mut self) {
+        if self.is_enabled && self.position < self.history.len() {
+            self.travel_to(self.position + 1);
+        }
+    }
+
+    fn travel_to(&mut self, position: usize) {
+        if self.snapshot(position).is_some() {
+            self.position = position;
+            self.pending_travel = true;
+        }
+    }
+
+    fn snapshot(&self, position: usize) -> Option<&dyn Any> {
+        match position {
+            0 => self.initial_snapshot.as_deref(),
+            _ => self.history[position - 1].snapshot.as_deref(),
+        }
+    }
+
+    /// Returns the snapshot the program must be restored to, if the debugger
+    /// has travelled in time since the last call.
+    pub fn time_travel(&mut self) -> Option<&dyn Any> {
+        if !std::mem::take(&mut self.pending_travel) {
+            return None;
+        }
+
+        self.snapshot(self.position)
+    }
+
+    /// Writes the message history to the file at the given path.
+    pub fn export(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+
+        for entry in &self.history {
+            writeln!(
+                file,
+                "{:?}\tupdate: {:?}\tview: {:?}\t{}",
+                entry.at,
+                entry.update,
+                entry.view.unwrap_or_default(),
+                entry.message
+            )?;
+        }
+
+        file.flush()
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         if !self.is_enabled {
             return Vec::new();
@@ -167,14 +347,45 @@ impl Debug {
         ));
         lines.push(key_value("Render:", self.render_durations.average()));
         lines.push(key_value("Message count:", self.message_count));
+
+        if self.is_paused {
+            lines.push(String::from("Paused (F9 to resume, F10 to step)"));
+        }
+
+        if self.position < self.history.len() {
+            lines.push(format!(
+                "Travelling: {}/{} (F7/F8 to travel)",
+                self.position,
+                self.history.len()
+            ));
+        }
+
         lines.push(String::from("Last messages:"));
-        lines.extend(self.last_messages.iter().map(|msg| {
-            if msg.len() <= 100 {
-                format!("    {msg}")
-            } else {
-                format!("    {msg:.100}...")
-            }
-        }));
+
+        let end = (self.position + 5).clamp(10, self.history.len().max(10));
+        let start = end.saturating_sub(10);
+
+        lines.extend(
+            self.history.iter().enumerate().take(end).skip(start).map(
+                |(i, entry)| {
+                    let marker = if i + 1 == self.position { ">" } else { " " };
+                    let msg = &entry.message;
+
+                    let msg = if msg.len() <= 100 {
+                        msg.clone()
+                    } else {
+                        format!("{msg:.100}...")
+                    };
+
+                    format!(
+                        "  {marker} {:?} ({:?} + {:?}) {msg}",
+                        entry.at,
+                        entry.update,
+                        entry.view.unwrap_or_default(),
+                    )
+                },
+            ),
+        );
 
         lines
     }
@@ -218,3 +429,114 @@ impl TimeBuffer {
         sum / self.size.max(1) as u32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(debug: &mut Debug, message: u32) {
+        debug.log_message(&message);
+        debug.update_started();
+        debug.update_finished();
+        debug.log_snapshot(|| Some(Box::new(message)));
+    }
+
+    fn travelled(debug: &mut Debug) -> Option<u32> {
+        debug
+            .time_travel()
+            .and_then(|snapshot| snapshot.downcast_ref::<u32>())
+            .copied()
+    }
+
+    #[test]
+    fn it_travels_through_snapshots() {
+        let mut debug = Debug::new();
+
+        debug.log_snapshot(|| Some(Box::new(0_u32)));
+
+        for message in 1..=3 {
+            log(&mut debug, message);
+        }
+
+        debug.toggle();
+        debug.travel_back();
+
+        assert_eq!(travelled(&mut debug), Some(2));
+        assert_eq!(travelled(&mut debug), None);
+
+        debug.travel_back();
+        debug.travel_back();
+        debug.travel_back();
+
+        assert_eq!(travelled(&mut debug), Some(0));
+
+        debug.travel_forward();
+
+        assert_eq!(travelled(&mut debug), Some(1));
+
+        log(&mut debug, 9);
+        debug.travel_forward();
+
+        assert_eq!(travelled(&mut debug), None);
+    }
+
+    #[test]
+    fn it_caps_the_message_history() {
+        let mut debug = Debug::new();
+
+        debug.log_snapshot(|| Some(Box::new(0_u32)));
+
+        for message in 1..=HISTORY_LIMIT as u32 + 5 {
+            log(&mut debug, message);
+        }
+
+        assert_eq!(debug.history.len(), HISTORY_LIMIT);
+
+        debug.toggle();
+
+        for _ in 0..HISTORY_LIMIT + 5 {
+            debug.travel_back();
+        }
+
+        assert_eq!(travelled(&mut debug), Some(5));
+    }
+
+    #[test]
+    fn it_steps_while_paused() {
+        let mut debug = Debug::new();
+
+        debug.toggle_pause();
+        assert_eq!(debug.allowed_messages(3), 3);
+
+        debug.toggle();
+        debug.toggle_pause();
+        assert_eq!(debug.allowed_messages(3), 0);
+
+        debug.step();
+        assert_eq!(debug.allowed_messages(3), 1);
+        assert_eq!(debug.allowed_messages(3), 0);
+    }
+
+    #[test]
+    fn it_exports_the_message_history() {
+        let mut debug = Debug::new();
+
+        log(&mut debug, 1);
+        log(&mut debug, 2);
+
+        let path = std::env::temp_dir()
+            .join(format!("iced-debug-{}.log", std::process::id()));
+
+        debug.export(&path).expect("Export history");
+
+        let exported = fs::read_to_string(&path).expect("Read history");
+        fs::remove_file(&path).expect("Remove history");
+
+        let messages: Vec<_> = exported
+            .lines()
+            .filter_map(|line| line.rsplit('\t').next())
+            .collect();
+
+        assert_eq!(messages, ["1", "2"]);
+    }
+}
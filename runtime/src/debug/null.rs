@@ -1,4 +1,8 @@
 #![allow(missing_docs)]
+use std::any::Any;
+use std::io;
+use std::path::Path;
+
 #[derive(Debug, Default)]
 pub struct Debug;
 
@@ -7,6 +11,12 @@ impl Debug {
         Self
     }
 
+    pub fn toggle_inspector(&mut self) {}
+
+    pub fn is_inspecting(&self) -> bool {
+        false
+    }
+
     pub fn startup_started(&mut self) {}
 
     pub fn startup_finished(&mut self) {}
@@ -41,6 +51,32 @@ impl Debug {
     ) {
     }
 
+    pub fn log_snapshot(
+        &mut self,
+        _snapshot: impl FnOnce() -> Option<Box<dyn Any>>,
+    ) {
+    }
+
+    pub fn toggle_pause(&mut self) {}
+
+    pub fn step(&mut self) {}
+
+    pub fn allowed_messages(&mut self, pending: usize) -> usize {
+        pending
+    }
+
+    pub fn travel_back(&mut self) {}
+
+    pub fn travel_forward(&mut self) {}
+
+    pub fn time_travel(&mut self) -> Option<&dyn Any> {
+        None
+    }
+
+    pub fn export(&self, _path: impl AsRef<Path>) -> io::Result<()> {
+        Ok(())
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         Vec::new()
     }
@@ -3,10 +3,22 @@ pub use iced_core::font::*;
 
 use crate::command::{self, Command};
 use std::borrow::Cow;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 /// An error while loading a font.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Error {}
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The font file could not be read or contained no font faces.
+    #[error("the font file {} could not be loaded: {error}", path.display())]
+    Io {
+        /// The path of the font file.
+        path: PathBuf,
+        /// The underlying I/O error.
+        error: Arc<io::Error>,
+    },
+}
 
 /// Load a font from its bytes.
 pub fn load(
@@ -17,3 +29,116 @@ pub fn load(
         tagger: Box::new(std::convert::identity),
     })
 }
+
+/// Load a font from the file at the given path.
+pub fn load_file(path: impl Into<PathBuf>) -> Command<Result<(), Error>> {
+    Command::single(command::Action::LoadFontFile {
+        path: path.into(),
+        tagger: Box::new(std::convert::identity),
+    })
+}
+
+/// Lists all the font [`Face`]s available, including the system fonts and
+/// any loaded ones.
+pub fn faces() -> Command<Vec<Face>> {
+    Command::single(command::Action::ListFonts(Box::new(
+        std::convert::identity,
+    )))
+}
+
+/// Lists the names of all the font families available, sorted
+/// alphabetically.
+pub fn families() -> Command<Vec<String>> {
+    Command::single(command::Action::ListFonts(Box::new(|faces| {
+        let mut families: Vec<String> =
+            faces.into_iter().map(|face| face.family).collect();
+
+        families.sort_unstable();
+        families.dedup();
+
+        families
+    })))
+}
+
+/// Checks whether a font family with the given name is available.
+///
+/// Family names are compared case-insensitively.
+pub fn has_family(name: impl Into<String>) -> Command<bool> {
+    let name = name.into();
+
+    Command::single(command::Action::ListFonts(Box::new(move |faces| {
+        faces
+            .iter()
+            .any(|face| face.family.eq_ignore_ascii_case(&name))
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn face(family: &str) -> Face {
+        Face {
+            family: family.to_owned(),
+            weight: Weight::Normal,
+            stretch: Stretch::Normal,
+            style: Style::Normal,
+            is_monospace: false,
+        }
+    }
+
+    fn available() -> Vec<Face> {
+        vec![face("Fira Sans"), face("Iced-Icons"), face("Fira Sans")]
+    }
+
+    /// Runs the [`Command`] as a shell would, listing the [`available`]
+    /// faces.
+    fn list<T>(command: Command<T>) -> T {
+        match command.actions().pop() {
+            Some(command::Action::ListFonts(tagger)) => tagger(available()),
+            _ => panic!("Command should list fonts"),
+        }
+    }
+
+    #[test]
+    fn it_loads_font_files() {
+        let Some(command::Action::LoadFontFile { path, tagger }) =
+            load_file("fonts/missing.ttf").actions().pop()
+        else {
+            panic!("Command should load a font file");
+        };
+
+        assert_eq!(path, PathBuf::from("fonts/missing.ttf"));
+        assert!(tagger(Ok(())).is_ok());
+
+        let error = io::Error::new(io::ErrorKind::NotFound, "not found");
+        let Err(error) = tagger(Err(Error::Io {
+            path,
+            error: Arc::new(error),
+        })) else {
+            panic!("Loading should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "the font file fonts/missing.ttf could not be loaded: not found"
+        );
+    }
+
+    #[test]
+    fn it_lists_faces() {
+        assert_eq!(list(faces()), available());
+    }
+
+    #[test]
+    fn it_lists_sorted_and_unique_families() {
+        assert_eq!(list(families()), ["Fira Sans", "Iced-Icons"]);
+    }
+
+    #[test]
+    fn it_finds_families_ignoring_case() {
+        assert!(list(has_family("Fira Sans")));
+        assert!(list(has_family("fira sans")));
+        assert!(!list(has_family("Fira")));
+    }
+}
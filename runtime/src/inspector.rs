@@ -0,0 +1,239 @@
+//! Inspect the layout of a user interface.
+use crate::core::alignment;
+use crate::core::layout::Layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Quad};
+use crate::core::text::{self, Text};
+use crate::core::{Border, Color, Padding, Pixels, Point, Rectangle, Size};
+
+const COLORS: [Color; 4] = [
+    Color::from_rgb(1.0, 0.3, 0.3),
+    Color::from_rgb(0.3, 0.8, 0.3),
+    Color::from_rgb(0.3, 0.5, 1.0),
+    Color::from_rgb(1.0, 0.7, 0.2),
+];
+
+const LABEL_SIZE: f32 = 12.0;
+const LABEL_PADDING: f32 = 4.0;
+
+/// Draws the bounds and padding of every node of the given [`Layout`].
+///
+/// The node under the mouse cursor is highlighted, together with its tree
+/// path, its size, and the [`Length`] sizing strategy and [`Padding`] it
+/// was laid out with. The sizing strategy and padding are only recorded
+/// when the `debug` feature is enabled.
+///
+/// [`Length`]: crate::core::Length
+pub fn draw<Renderer>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    renderer.with_layer(*viewport, |renderer| {
+        outline(renderer, layout, 0);
+
+        let Some(position) = cursor.position() else {
+            return;
+        };
+
+        let mut path = Vec::new();
+
+        if let Some(hovered) = hovered(layout, position, &mut path) {
+            highlight(renderer, hovered, &path, viewport);
+        }
+    });
+}
+
+fn outline<Renderer>(renderer: &mut Renderer, layout: Layout<'_>, depth: usize)
+where
+    Renderer: renderer::Renderer,
+{
+    let bounds = layout.bounds();
+    let color = COLORS[depth % COLORS.len()];
+
+    renderer.fill_quad(
+        Quad {
+            bounds,
+            border: Border {
+                color,
+                width: 1.0,
+                radius: 0.0.into(),
+            },
+            ..Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+
+    let padding = layout.padding();
+
+    if padding != Padding::ZERO {
+        for strip in padding_strips(bounds, padding) {
+            renderer.fill_quad(
+                Quad {
+                    bounds: strip,
+                    ..Quad::default()
+                },
+                Color { a: 0.15, ..color },
+            );
+        }
+    }
+
+    for child in layout.children() {
+        outline(renderer, child, depth + 1);
+    }
+}
+
+fn padding_strips(bounds: Rectangle, padding: Padding) -> [Rectangle; 4] {
+    let inner_height = bounds.height - padding.vertical();
+
+    [
+        Rectangle {
+            height: padding.top,
+            ..bounds
+        },
+        Rectangle {
+            y: bounds.y + bounds.height - padding.bottom,
+            height: padding.bottom,
+            ..bounds
+        },
+        Rectangle {
+            y: bounds.y + padding.top,
+            width: padding.left,
+            height: inner_height,
+            ..bounds
+        },
+        Rectangle {
+            x: bounds.x + bounds.width - padding.right,
+            y: bounds.y + padding.top,
+            width: padding.right,
+            height: inner_height,
+        },
+    ]
+}
+
+fn hovered<'a>(
+    layout: Layout<'a>,
+    position: Point,
+    path: &mut Vec<usize>,
+) -> Option<Layout<'a>> {
+    if !layout.bounds().contains(position) {
+        return None;
+    }
+
+    // Later children are drawn on top, so we prefer them
+    let children: Vec<_> = layout.children().enumerate().collect();
+
+    for (i, child) in children.into_iter().rev() {
+        path.push(i);
+
+        if let Some(hovered) = hovered(child, position, path) {
+            return Some(hovered);
+        }
+
+        let _ = path.pop();
+    }
+
+    Some(layout)
+}
+
+fn highlight<Renderer>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    path: &[usize],
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let bounds = layout.bounds();
+
+    renderer.fill_quad(
+        Quad {
+            bounds,
+            ..Quad::default()
+        },
+        Color::from_rgba(0.3, 0.5, 1.0, 0.25),
+    );
+
+    let mut lines = vec![
+        std::iter::once(String::from("root"))
+            .chain(path.iter().map(usize::to_string))
+            .collect::<Vec<_>>()
+            .join(" › "),
+        format!("{:.1} × {:.1}", bounds.width, bounds.height),
+    ];
+
+    if let Some(sizing) = layout.sizing() {
+        lines.push(format!("{:?} × {:?}", sizing.width, sizing.height));
+    }
+
+    let padding = layout.padding();
+
+    if padding != Padding::ZERO {
+        lines.push(format!(
+            "padding: {} {} {} {}",
+            padding.top, padding.right, padding.bottom, padding.left
+        ));
+    }
+
+    let line_height = text::LineHeight::default()
+        .to_absolute(Pixels(LABEL_SIZE))
+        .0;
+
+    // We do not have access to text measurements here, so we estimate the
+    // width of the label generously
+    let longest = lines.iter().map(|line| line.chars().count()).max();
+    let size = Size::new(
+        longest.unwrap_or_default() as f32 * LABEL_SIZE * 0.55
+            + LABEL_PADDING * 2.0,
+        lines.len() as f32 * line_height + LABEL_PADDING * 2.0,
+    );
+
+    let position = Point::new(
+        bounds
+            .x
+            .min(viewport.x + viewport.width - size.width)
+            .max(0.0),
+        if bounds.y >= size.height {
+            bounds.y - size.height
+        } else {
+            (bounds.y + bounds.height)
+                .min(viewport.y + viewport.height - size.height)
+        },
+    );
+
+    let label = Rectangle::new(position, size);
+
+    renderer.fill_quad(
+        Quad {
+            bounds: label,
+            ..Quad::default()
+        },
+        Color::from_rgba(0.0, 0.0, 0.0, 0.8),
+    );
+
+    for (i, line) in lines.into_iter().enumerate() {
+        renderer.fill_text(
+            Text {
+                content: line,
+                bounds: Size::new(f32::INFINITY, line_height),
+                size: Pixels(LABEL_SIZE),
+                line_height: text::LineHeight::default(),
+                font: renderer.default_font(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+                overflow: text::Overflow::default(),
+            },
+            Point::new(
+                position.x + LABEL_PADDING,
+                position.y + LABEL_PADDING + i as f32 * line_height,
+            ),
+            Color::WHITE,
+            label,
+        );
+    }
+}
@@ -12,9 +12,11 @@
 pub mod clipboard;
 pub mod command;
 pub mod font;
+pub mod inspector;
 pub mod keyboard;
 pub mod overlay;
 pub mod program;
+pub mod selector;
 pub mod system;
 pub mod user_interface;
 pub mod window;
@@ -22,6 +24,12 @@ pub mod window;
 #[cfg(feature = "multi-window")]
 pub mod multi_window;
 
+#[cfg(feature = "session")]
+pub mod session;
+
+#[cfg(feature = "trace")]
+pub mod trace;
+
 // We disable debug capabilities on release builds unless the `debug` feature
 // is explicitly enabled.
 #[cfg(feature = "debug")]
@@ -0,0 +1,17 @@
+//! Locate widgets in a user interface.
+pub use crate::core::widget::selector::{Kind, Selector, State, Target};
+
+use crate::command::Command;
+use crate::core::widget::selector;
+
+/// Produces a [`Command`] that finds the first widget matching the given
+/// [`Selector`].
+pub fn find(selector: impl Into<Selector>) -> Command<Option<Target>> {
+    Command::widget(selector::find(selector.into()))
+}
+
+/// Produces a [`Command`] that finds all the widgets matching the given
+/// [`Selector`], in tree order.
+pub fn find_all(selector: impl Into<Selector>) -> Command<Vec<Target>> {
+    Command::widget(selector::find_all(selector.into()))
+}
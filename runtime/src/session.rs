@@ -0,0 +1,204 @@
+//! Record and replay the input events of a user interface.
+use crate::core::time::{Duration, Instant};
+use crate::core::window;
+use crate::core::Event;
+
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A recording of the [`Event`]s received by a user interface, together with
+/// the time they were received.
+///
+/// A [`Session`] can be saved to a file and replayed later, either in a live
+/// application or headlessly.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    entries: Vec<Entry>,
+}
+
+/// An [`Event`] of a [`Session`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// The time elapsed since the start of the [`Session`] when the [`Event`]
+    /// was received.
+    pub at: Duration,
+
+    /// The window that received the [`Event`], if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window: Option<window::Id>,
+
+    /// The recorded [`Event`].
+    pub event: Event,
+}
+
+impl Session {
+    /// Creates a new empty [`Session`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given [`Event`] received by the given window to the
+    /// [`Session`] at the given time.
+    ///
+    /// Events that cannot be recorded, like redraw requests, are ignored.
+    /// See [`is_recordable`].
+    pub fn push(
+        &mut self,
+        at: Duration,
+        window: Option<window::Id>,
+        event: Event,
+    ) {
+        if is_recordable(&event) {
+            self.entries.push(Entry { at, window, event });
+        }
+    }
+
+    /// Returns the entries of the [`Session`], in the order they were
+    /// recorded.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns an iterator over the recorded [`Event`]s, ignoring their
+    /// timestamps.
+    pub fn events(&self) -> impl Iterator<Item = &Event> {
+        self.entries.iter().map(|entry| &entry.event)
+    }
+
+    /// Returns the total duration of the [`Session`].
+    pub fn duration(&self) -> Duration {
+        self.entries
+            .last()
+            .map(|entry| entry.at)
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the [`Session`] has no events.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Loads a [`Session`] from the file at the given path.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = io::BufReader::new(fs::File::open(path)?);
+
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    /// Saves the [`Session`] to a file at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let file = io::BufWriter::new(fs::File::create(path)?);
+
+        Ok(serde_json::to_writer(file, self)?)
+    }
+}
+
+/// Returns true if the given [`Event`] can be recorded in a [`Session`].
+///
+/// Redraw requests are produced by the runtime itself and carry an
+/// [`Instant`] that is meaningless outside of the current process; therefore,
+/// they are never recorded.
+pub fn is_recordable(event: &Event) -> bool {
+    !matches!(event, Event::Window(_, window::Event::RedrawRequested(_)))
+}
+
+/// Records the [`Event`]s received by a user interface into a [`Session`].
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    started_at: Instant,
+    session: Session,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] that starts recording right away.
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            session: Session::new(),
+        }
+    }
+
+    /// Records the given [`Event`] received by the given window at the
+    /// current time.
+    pub fn record(&mut self, window: Option<window::Id>, event: &Event) {
+        self.session
+            .push(self.started_at.elapsed(), window, event.clone());
+    }
+
+    /// Returns the [`Session`] recorded so far.
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// Stops recording and returns the recorded [`Session`].
+    pub fn finish(self) -> Session {
+        self.session
+    }
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Replays the [`Event`]s of a [`Session`] in real time.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    session: Session,
+    started_at: Instant,
+    next: usize,
+}
+
+impl Replay {
+    /// Creates a new [`Replay`] of the given [`Session`] that starts right
+    /// away.
+    pub fn new(session: Session) -> Self {
+        Self {
+            session,
+            started_at: Instant::now(),
+            next: 0,
+        }
+    }
+
+    /// Returns the [`Event`]s that are due at the given [`Instant`] and
+    /// have not been replayed yet, together with the window that received
+    /// them.
+    pub fn due(
+        &mut self,
+        now: Instant,
+    ) -> impl Iterator<Item = (Option<window::Id>, Event)> + '_ {
+        let elapsed = now.saturating_duration_since(self.started_at);
+        let start = self.next;
+
+        let pending = &self.session.entries[start..];
+        let count = pending
+            .iter()
+            .take_while(|entry| entry.at <= elapsed)
+            .count();
+
+        self.next += count;
+
+        pending[..count]
+            .iter()
+            .map(|entry| (entry.window, entry.event.clone()))
+    }
+
+    /// Returns the [`Instant`] when the next [`Event`] of the [`Replay`] is
+    /// due, if any.
+    pub fn next_at(&self) -> Option<Instant> {
+        self.session
+            .entries
+            .get(self.next)
+            .map(|entry| self.started_at + entry.at)
+    }
+
+    /// Returns true if all the [`Event`]s of the [`Replay`] have been
+    /// replayed.
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.session.entries.len()
+    }
+}
@@ -0,0 +1,224 @@
+//! Write the [`tracing`] spans of a user interface to a Chrome trace file.
+use crate::core::time::Instant;
+
+use serde_json::{json, Map, Value};
+use tracing::field::{Field, Visit};
+use tracing::span;
+use tracing::{Event, Metadata, Subscriber};
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Mutex;
+
+/// A [`Subscriber`] that writes every span it records to a file in the
+/// [Chrome trace event format].
+///
+/// The resulting file can be loaded in `chrome://tracing` or [Perfetto].
+///
+/// [Chrome trace event format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+/// [Perfetto]: https://ui.perfetto.dev
+#[derive(Debug)]
+pub struct Chrome {
+    started_at: Instant,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    next_id: u64,
+    spans: HashMap<u64, Span>,
+    writer: io::LineWriter<fs::File>,
+}
+
+#[derive(Debug)]
+struct Span {
+    name: &'static str,
+    target: &'static str,
+    args: Map<String, Value>,
+    references: usize,
+    entered_at: Vec<f64>,
+}
+
+impl Chrome {
+    /// Creates a new [`Chrome`] subscriber that writes to a new file at the
+    /// given path.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut writer = io::LineWriter::new(fs::File::create(path)?);
+
+        // The trace event format allows the closing bracket of the array to
+        // be missing, which lets us write events as they happen
+        writer.write_all(b"[\n")?;
+
+        Ok(Self {
+            started_at: Instant::now(),
+            state: Mutex::new(State {
+                next_id: 1,
+                spans: HashMap::new(),
+                writer,
+            }),
+        })
+    }
+
+    fn timestamp(&self) -> f64 {
+        self.started_at.elapsed().as_secs_f64() * 1_000_000.0
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Subscriber for Chrome {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.is_span()
+    }
+
+    fn new_span(&self, attributes: &span::Attributes<'_>) -> span::Id {
+        let metadata = attributes.metadata();
+
+        let mut args = Map::new();
+        attributes.record(&mut Visitor(&mut args));
+
+        let mut state = self.state();
+
+        let id = state.next_id;
+        state.next_id += 1;
+
+        let _ = state.spans.insert(
+            id,
+            Span {
+                name: metadata.name(),
+                target: metadata.target(),
+                args,
+                references: 1,
+                entered_at: Vec::new(),
+            },
+        );
+
+        span::Id::from_u64(id)
+    }
+
+    fn record(&self, span: &span::Id, values: &span::Record<'_>) {
+        if let Some(span) = self.state().spans.get_mut(&span.into_u64()) {
+            values.record(&mut Visitor(&mut span.args));
+        }
+    }
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, span: &span::Id) {
+        let timestamp = self.timestamp();
+
+        if let Some(span) = self.state().spans.get_mut(&span.into_u64()) {
+            span.entered_at.push(timestamp);
+        }
+    }
+
+    fn exit(&self, span: &span::Id) {
+        let timestamp = self.timestamp();
+        let mut state = self.state();
+
+        let Some(span) = state.spans.get_mut(&span.into_u64()) else {
+            return;
+        };
+
+        let Some(entered_at) = span.entered_at.pop() else {
+            return;
+        };
+
+        let event = json!({
+            "name": span.name,
+            "cat": span.target,
+            "ph": "X",
+            "ts": entered_at,
+            "dur": timestamp - entered_at,
+            "pid": std::process::id(),
+            "tid": thread_id(),
+            "args": span.args,
+        });
+
+        let _ = writeln!(state.writer, "{event},");
+    }
+
+    fn clone_span(&self, span: &span::Id) -> span::Id {
+        if let Some(span) = self.state().spans.get_mut(&span.into_u64()) {
+            span.references += 1;
+        }
+
+        span.clone()
+    }
+
+    fn try_close(&self, span: span::Id) -> bool {
+        let mut state = self.state();
+        let id = span.into_u64();
+
+        let Some(span) = state.spans.get_mut(&id) else {
+            return false;
+        };
+
+        span.references -= 1;
+
+        if span.references > 0 {
+            return false;
+        }
+
+        let _ = state.spans.remove(&id);
+
+        true
+    }
+}
+
+struct Visitor<'a>(&'a mut Map<String, Value>);
+
+impl<'a> Visit for Visitor<'a> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        let _ = self.0.insert(field.name().to_owned(), json!(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        let _ = self.0.insert(field.name().to_owned(), json!(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        let _ = self.0.insert(field.name().to_owned(), json!(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        let _ = self.0.insert(field.name().to_owned(), json!(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        let _ = self.0.insert(field.name().to_owned(), json!(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let _ = self
+            .0
+            .insert(field.name().to_owned(), json!(format!("{value:?}")));
+    }
+}
+
+fn thread_id() -> u64 {
+    static NEXT: AtomicU64 = AtomicU64::new(1);
+
+    thread_local! {
+        static ID: Cell<u64> = const { Cell::new(0) };
+    }
+
+    ID.with(|id| {
+        if id.get() == 0 {
+            id.set(NEXT.fetch_add(1, atomic::Ordering::Relaxed));
+        }
+
+        id.get()
+    })
+}
@@ -3,9 +3,13 @@ use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::text;
 use crate::core::widget;
 use crate::core::window;
-use crate::core::{Clipboard, Element, Layout, Rectangle, Shell, Size, Vector};
+use crate::core::{
+    Clipboard, Element, InputMethod, Layout, Rectangle, Shell, Size, Vector,
+};
+use crate::inspector;
 use crate::overlay;
 
 /// A set of interactive graphical elements with a specific [`Layout`].
@@ -187,6 +191,7 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let mut input_method = InputMethod::Disabled;
 
         let mut manual_overlay = ManuallyDrop::new(
             self.root
@@ -221,6 +226,8 @@ where
 
                 event_statuses.push(event_status);
 
+                input_method.merge(shell.input_method());
+
                 match (redraw_request, shell.redraw_request()) {
                     (None, Some(at)) => {
                         redraw_request = Some(at);
@@ -323,6 +330,8 @@ where
                     self.overlay = None;
                 }
 
+                input_method.merge(shell.input_method());
+
                 match (redraw_request, shell.redraw_request()) {
                     (None, Some(at)) => {
                         redraw_request = Some(at);
@@ -355,7 +364,10 @@ where
             if outdated {
                 State::Outdated
             } else {
-                State::Updated { redraw_request }
+                State::Updated {
+                    redraw_request,
+                    input_method,
+                }
             },
             event_statuses,
         )
@@ -611,6 +623,25 @@ where
     }
 }
 
+impl<'a, Message, Theme, Renderer> UserInterface<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Draws the layout of the [`UserInterface`] on top of it, highlighting
+    /// the node under the given mouse cursor.
+    ///
+    /// This is meant to be called right after [`draw`](Self::draw) to debug
+    /// layout issues. See [`inspector::draw`] for more details.
+    pub fn inspect(&self, renderer: &mut Renderer, cursor: mouse::Cursor) {
+        inspector::draw(
+            renderer,
+            Layout::new(&self.base),
+            cursor,
+            &Rectangle::with_size(self.bounds),
+        );
+    }
+}
+
 /// Reusable data of a specific [`UserInterface`].
 #[derive(Debug)]
 pub struct Cache {
@@ -646,5 +677,112 @@ pub enum State {
     Updated {
         /// The [`window::RedrawRequest`] when a redraw should be performed.
         redraw_request: Option<window::RedrawRequest>,
+
+        /// The [`InputMethod`] requested by the widgets.
+        input_method: InputMethod,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+    use crate::core::time::Instant;
+    use crate::core::widget::Tree;
+    use crate::core::{Length, Point, Theme, Widget};
+
+    /// A widget that requests an input method at the given cursor, if any,
+    /// every time it is redrawn.
+    struct Input(Option<Rectangle>);
+
+    impl Widget<(), Theme, ()> for Input {
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Fill, Length::Fill)
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(limits.max())
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+
+        fn on_event(
+            &mut self,
+            _tree: &mut Tree,
+            event: Event,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _renderer: &(),
+            _clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, ()>,
+            _viewport: &Rectangle,
+        ) -> event::Status {
+            match (event, self.0) {
+                (
+                    Event::Window(_, window::Event::RedrawRequested(_)),
+                    Some(cursor),
+                ) => {
+                    shell
+                        .request_input_method(&InputMethod::Enabled { cursor });
+
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            }
+        }
+    }
+
+    /// Redraws the given [`Input`] and returns the [`InputMethod`] requested
+    /// in the resulting [`State`].
+    fn redraw(input: Input) -> InputMethod {
+        let mut ui = UserInterface::build(
+            Element::new(input),
+            Size::new(100.0, 100.0),
+            Cache::default(),
+            &mut (),
+        );
+
+        let (state, _) = ui.update(
+            &[Event::Window(
+                window::Id::MAIN,
+                window::Event::RedrawRequested(Instant::now()),
+            )],
+            mouse::Cursor::Unavailable,
+            &mut (),
+            &mut clipboard::Null,
+            &mut Vec::new(),
+        );
+
+        match state {
+            State::Updated { input_method, .. } => input_method,
+            State::Outdated => panic!("User interface should be updated"),
+        }
+    }
+
+    #[test]
+    fn it_reports_the_input_method_requested_by_widgets() {
+        let cursor =
+            Rectangle::new(Point::new(10.0, 5.0), Size::new(1.0, 20.0));
+
+        assert_eq!(redraw(Input(None)), InputMethod::Disabled);
+        assert_eq!(
+            redraw(Input(Some(cursor))),
+            InputMethod::Enabled { cursor }
+        );
+    }
+}
@@ -4,6 +4,8 @@ use crate::graphics::compositor;
 use crate::shell::application;
 use crate::{Command, Element, Executor, Settings, Subscription};
 
+use std::any::Any;
+
 pub use application::{Appearance, DefaultStyle};
 
 /// An interactive cross-platform application.
@@ -187,6 +189,21 @@ where
         1.0
     }
 
+    /// Returns a snapshot of the current state of the [`Application`], if
+    /// it supports time travel.
+    ///
+    /// When the `debug` feature is enabled, snapshots are taken after every
+    /// message and can be restored from the debug view.
+    ///
+    /// By default, it returns `None`.
+    fn snapshot(&self) -> Option<Box<dyn Any>> {
+        None
+    }
+
+    /// Restores the [`Application`] to a snapshot previously returned by
+    /// [`snapshot`](Self::snapshot).
+    fn restore(&mut self, _snapshot: &dyn Any) {}
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -290,4 +307,12 @@ where
     fn scale_factor(&self) -> f64 {
         self.0.scale_factor()
     }
+
+    fn snapshot(&self) -> Option<Box<dyn Any>> {
+        self.0.snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &dyn Any) {
+        self.0.restore(snapshot);
+    }
 }
@@ -259,6 +259,11 @@ pub mod command {
     pub use crate::runtime::command::{channel, Command};
 }
 
+pub mod selector {
+    //! Locate widgets in a user interface.
+    pub use crate::runtime::selector::*;
+}
+
 pub mod subscription {
     //! Listen to external events in your application.
     pub use iced_futures::subscription::{
@@ -4,6 +4,8 @@ use crate::{Command, Element, Executor, Settings, Subscription};
 
 pub use crate::application::{Appearance, DefaultStyle};
 
+use std::any::Any;
+
 /// An interactive cross-platform multi-window application.
 ///
 /// This trait is the main entrypoint of Iced. Once implemented, you can run
@@ -160,6 +162,21 @@ where
         1.0
     }
 
+    /// Returns a snapshot of the current state of the [`Application`], if
+    /// it supports time travel.
+    ///
+    /// When the `debug` feature is enabled, snapshots are taken after every
+    /// message and can be restored from the debug view.
+    ///
+    /// By default, it returns `None`.
+    fn snapshot(&self) -> Option<Box<dyn Any>> {
+        None
+    }
+
+    /// Restores the [`Application`] to a snapshot previously returned by
+    /// [`snapshot`](Self::snapshot).
+    fn restore(&mut self, _snapshot: &dyn Any) {}
+
     /// Runs the multi-window [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -251,4 +268,12 @@ where
     fn scale_factor(&self, window: window::Id) -> f64 {
         self.0.scale_factor(window)
     }
+
+    fn snapshot(&self) -> Option<Box<dyn Any>> {
+        self.0.snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &dyn Any) {
+        self.0.restore(snapshot);
+    }
 }
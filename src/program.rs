@@ -39,6 +39,7 @@ use crate::{Command, Element, Font, Result, Settings, Size, Subscription};
 
 pub use crate::application::{Appearance, DefaultStyle};
 
+use std::any::Any;
 use std::borrow::Cow;
 
 /// Creates an iced [`Program`] given its title, update, and view logic.
@@ -240,6 +241,21 @@ impl<P: Definition> Program<P> {
             fn style(&self, theme: &Self::Theme) -> Appearance {
                 self.program.style(&self.state, theme)
             }
+
+            fn snapshot(&self) -> Option<Box<dyn Any>> {
+                self.program
+                    .snapshot(&self.state)
+                    .map(|state| Box::new(state) as Box<dyn Any>)
+            }
+
+            fn restore(&mut self, snapshot: &dyn Any) {
+                if let Some(state) = snapshot
+                    .downcast_ref::<P::State>()
+                    .and_then(|state| self.program.snapshot(state))
+                {
+                    self.state = state;
+                }
+            }
         }
 
         let Self { raw, settings } = self;
@@ -408,6 +424,25 @@ impl<P: Definition> Program<P> {
             settings: self.settings,
         }
     }
+
+    /// Enables time travel in the debug view of the [`Program`].
+    ///
+    /// The state of the [`Program`] will be cloned after every message, so
+    /// that the debug view can travel back to any earlier state. This has no
+    /// effect unless the `debug` feature is enabled.
+    pub fn time_travel(
+        self,
+    ) -> Program<
+        impl Definition<State = P::State, Message = P::Message, Theme = P::Theme>,
+    >
+    where
+        P::State: Clone,
+    {
+        Program {
+            raw: with_time_travel(self.raw),
+            settings: self.settings,
+        }
+    }
 }
 
 /// The internal definition of a [`Program`].
@@ -417,7 +452,7 @@ impl<P: Definition> Program<P> {
 #[allow(missing_docs)]
 pub trait Definition: Sized {
     /// The state of the program.
-    type State;
+    type State: 'static;
 
     /// The message of the program.
     type Message: Send + std::fmt::Debug;
@@ -462,6 +497,10 @@ pub trait Definition: Sized {
     fn style(&self, _state: &Self::State, theme: &Self::Theme) -> Appearance {
         DefaultStyle::default_style(theme)
     }
+
+    fn snapshot(&self, _state: &Self::State) -> Option<Self::State> {
+        None
+    }
 }
 
 fn with_title<P: Definition>(
@@ -525,6 +564,10 @@ fn with_title<P: Definition>(
         ) -> Appearance {
             self.program.style(state, theme)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithTitle { program, title }
@@ -590,6 +633,10 @@ fn with_load<P: Definition>(
         ) -> Appearance {
             self.program.style(state, theme)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithLoad { program, load: f }
@@ -655,6 +702,10 @@ fn with_subscription<P: Definition>(
         ) -> Appearance {
             self.program.style(state, theme)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithSubscription {
@@ -723,6 +774,10 @@ fn with_theme<P: Definition>(
         ) -> Appearance {
             self.program.style(state, theme)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithTheme { program, theme: f }
@@ -788,11 +843,85 @@ fn with_style<P: Definition>(
         fn theme(&self, state: &Self::State) -> Self::Theme {
             self.program.theme(state)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithStyle { program, style: f }
 }
 
+fn with_time_travel<P: Definition>(
+    program: P,
+) -> impl Definition<State = P::State, Message = P::Message, Theme = P::Theme>
+where
+    P::State: Clone,
+{
+    struct WithTimeTravel<P> {
+        program: P,
+    }
+
+    impl<P: Definition> Definition for WithTimeTravel<P>
+    where
+        P::State: Clone,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            Some(state.clone())
+        }
+
+        fn load(&self) -> Command<Self::Message> {
+            self.program.load()
+        }
+
+        fn title(&self, state: &Self::State) -> String {
+            self.program.title(state)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Command<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(&self, state: &Self::State) -> Self::Theme {
+            self.program.theme(state)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+    }
+
+    WithTimeTravel { program }
+}
+
 /// The title logic of some [`Program`].
 ///
 /// This trait is implemented both for `&static str` and
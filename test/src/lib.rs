@@ -0,0 +1,649 @@
+//! Test your iced user interfaces in headless mode.
+//!
+//! A [`Simulator`] drives an [`Element`] through the same
+//! [`UserInterface`] lifecycle used by the native shells, without needing
+//! a window or a GPU.
+//!
+//! # Example
+//! ```no_run
+//! # use iced_widget::{button, column, text};
+//! # type Element<'a, Message> = iced_runtime::core::Element<'a, Message, iced_runtime::core::Theme, iced_renderer::Renderer>;
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Save,
+//! }
+//!
+//! fn view<'a>() -> Element<'a, Message> {
+//!     column![text("Settings"), button("Save").on_press(Message::Save)]
+//!         .into()
+//! }
+//!
+//! let mut ui = iced_test::simulator(view());
+//!
+//! let _ = ui.click("Save").expect("Find save button");
+//!
+//! assert_eq!(ui.into_messages().collect::<Vec<_>>(), vec![Message::Save]);
+//! ```
+//!
+//! [`UserInterface`]: iced_runtime::UserInterface
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod snapshot;
+
+pub use iced_renderer as renderer;
+pub use iced_runtime as runtime;
+pub use iced_runtime::core;
+pub use iced_runtime::selector;
+pub use iced_runtime::session;
+
+pub use selector::{Selector, Target};
+pub use session::Session;
+pub use snapshot::Snapshot;
+
+use crate::core::clipboard;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::renderer::{Headless, Style};
+use crate::core::widget::operation::{self, Operation, Outcome};
+use crate::core::widget::selector as query;
+use crate::core::{Element, Font, Pixels, Point, Size, SmolStr};
+use crate::runtime::program::DefaultStyle;
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::window::Screenshot;
+
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Creates a new [`Simulator`] for the given [`Element`] with the default
+/// [`Settings`].
+pub fn simulator<'a, Message, Theme, Renderer>(
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + Headless,
+{
+    Simulator::new(element)
+}
+
+/// A user interface that can be interacted with programmatically.
+///
+/// Every interaction is translated into [`Event`]s that are processed by the
+/// underlying [`UserInterface`]. The produced messages are collected and can
+/// be obtained with [`Simulator::into_messages`].
+#[allow(missing_debug_implementations)]
+pub struct Simulator<
+    'a,
+    Message,
+    Theme = core::Theme,
+    Renderer = renderer::Renderer,
+> {
+    raw: UserInterface<'a, Message, Theme, Renderer>,
+    renderer: Renderer,
+    size: Size,
+    scale_factor: f32,
+    cursor: mouse::Cursor,
+    modifiers: keyboard::Modifiers,
+    clipboard: Clipboard,
+    messages: Vec<Message>,
+}
+
+/// The settings of a [`Simulator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settings {
+    /// The size of the simulated window.
+    ///
+    /// By default, it will be set to `1024x768`.
+    pub size: Size,
+
+    /// The scale factor used to render snapshots.
+    ///
+    /// By default, it will be set to `1.0`.
+    pub scale_factor: f32,
+
+    /// The default [`Font`] of the renderer.
+    pub default_font: Font,
+
+    /// The default size of text.
+    ///
+    /// By default, it will be set to `16.0`.
+    pub default_text_size: Pixels,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            size: Size::new(1024.0, 768.0),
+            scale_factor: 1.0,
+            default_font: Font::default(),
+            default_text_size: Pixels(16.0),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + Headless,
+{
+    /// Creates a new [`Simulator`] for the given [`Element`] with the default
+    /// [`Settings`].
+    pub fn new(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::with_settings(Settings::default(), element)
+    }
+
+    /// Creates a new [`Simulator`] for the given [`Element`] with the given
+    /// [`Settings`].
+    pub fn with_settings(
+        settings: Settings,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let mut renderer =
+            Renderer::new(settings.default_font, settings.default_text_size);
+
+        let raw = UserInterface::build(
+            element,
+            settings.size,
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        Self {
+            raw,
+            renderer,
+            size: settings.size,
+            scale_factor: settings.scale_factor,
+            cursor: mouse::Cursor::Unavailable,
+            modifiers: keyboard::Modifiers::default(),
+            clipboard: Clipboard::default(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Finds the first widget matching the given [`Selector`].
+    pub fn find(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let selector = selector.into();
+
+        self.query(query::find(selector.clone()))
+            .flatten()
+            .ok_or(Error::NotFound(selector))
+    }
+
+    /// Finds all the widgets matching the given [`Selector`], in tree order.
+    pub fn find_all(&mut self, selector: impl Into<Selector>) -> Vec<Target> {
+        self.query(query::find_all(selector.into()))
+            .unwrap_or_default()
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn point_at(&mut self, position: impl Into<Point>) {
+        let position = position.into();
+
+        self.cursor = mouse::Cursor::Available(position);
+
+        let _ = self
+            .simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+    }
+
+    /// Clicks the center of the first widget matching the given [`Selector`]
+    /// with the left mouse button.
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let target = self.find(selector)?;
+
+        self.point_at(target.bounds.center());
+
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok(target)
+    }
+
+    /// Scrolls the mouse wheel at the current cursor position.
+    pub fn scroll(&mut self, delta: mouse::ScrollDelta) -> event::Status {
+        self.simulate([Event::Mouse(mouse::Event::WheelScrolled { delta })])
+            .into_iter()
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Changes the state of the keyboard modifiers.
+    ///
+    /// The new modifiers will be used by any subsequent key presses.
+    pub fn modifiers(&mut self, modifiers: keyboard::Modifiers) {
+        self.modifiers = modifiers;
+
+        let _ = self.simulate([Event::Keyboard(
+            keyboard::Event::ModifiersChanged(modifiers),
+        )]);
+    }
+
+    /// Presses and releases the given key.
+    pub fn press_key(
+        &mut self,
+        key: impl Into<keyboard::Key>,
+    ) -> event::Status {
+        let key = key.into();
+
+        let text = match key.as_ref() {
+            keyboard::Key::Character(c) => Some(SmolStr::new(c)),
+            keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                Some(SmolStr::new("\r"))
+            }
+            keyboard::Key::Named(keyboard::key::Named::Tab) => {
+                Some(SmolStr::new("\t"))
+            }
+            keyboard::Key::Named(keyboard::key::Named::Space) => {
+                Some(SmolStr::new(" "))
+            }
+            _ => None,
+        };
+
+        self.key_events(key, text)
+    }
+
+    /// Types the given text, pressing and releasing a key for every
+    /// character.
+    pub fn type_text(&mut self, text: impl AsRef<str>) -> event::Status {
+        let mut status = event::Status::Ignored;
+
+        for c in text.as_ref().chars() {
+            let text = SmolStr::new(c.encode_utf8(&mut [0; 4]));
+
+            let key = if c == ' ' {
+                keyboard::Key::Named(keyboard::key::Named::Space)
+            } else {
+                keyboard::Key::Character(text.clone())
+            };
+
+            status = status.merge(self.key_events(key, Some(text)));
+        }
+
+        status
+    }
+
+    /// Processes the given [`Event`]s and returns their resulting
+    /// [`event::Status`].
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+
+        let (_state, statuses) = self.raw.update(
+            &events,
+            self.cursor,
+            &mut self.renderer,
+            &mut self.clipboard,
+            &mut self.messages,
+        );
+
+        statuses
+    }
+
+    /// Replays the [`Event`]s of the given [`Session`] in order, ignoring
+    /// their timestamps, and returns their resulting [`event::Status`].
+    ///
+    /// The cursor position and the keyboard modifiers of the [`Simulator`]
+    /// are updated as the [`Event`]s are replayed.
+    pub fn replay(&mut self, session: &Session) -> Vec<event::Status> {
+        let mut statuses = Vec::new();
+
+        for event in session.events() {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    self.cursor = mouse::Cursor::Available(*position);
+                }
+                Event::Mouse(mouse::Event::CursorLeft) => {
+                    self.cursor = mouse::Cursor::Unavailable;
+                }
+                Event::Keyboard(keyboard::Event::ModifiersChanged(
+                    modifiers,
+                )) => {
+                    self.modifiers = *modifiers;
+                }
+                _ => {}
+            }
+
+            statuses.extend(self.simulate([event.clone()]));
+        }
+
+        statuses
+    }
+
+    /// Draws the [`Simulator`] with the given `Theme` and [`Style`] and
+    /// returns the resulting [`mouse::Interaction`].
+    pub fn draw(&mut self, theme: &Theme, style: &Style) -> mouse::Interaction {
+        self.raw.draw(&mut self.renderer, theme, style, self.cursor)
+    }
+
+    /// Draws the [`Simulator`] with the given `Theme` and renders it
+    /// offscreen into a [`Snapshot`].
+    pub fn snapshot(&mut self, theme: &Theme) -> Snapshot
+    where
+        Theme: DefaultStyle,
+    {
+        let appearance = theme.default_style();
+
+        let _ = self.draw(
+            theme,
+            &Style {
+                text_color: appearance.text_color,
+            },
+        );
+
+        let physical_size = Size::new(
+            (self.size.width * self.scale_factor).round() as u32,
+            (self.size.height * self.scale_factor).round() as u32,
+        );
+
+        let rgba = self.renderer.screenshot(
+            physical_size,
+            self.scale_factor,
+            appearance.background_color,
+        );
+
+        Snapshot::new(Screenshot::new(rgba, physical_size))
+    }
+
+    /// Returns the size of the [`Simulator`].
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the [`Clipboard`] of the [`Simulator`].
+    pub fn clipboard(&self) -> &Clipboard {
+        &self.clipboard
+    }
+
+    /// Returns a mutable reference to the [`Clipboard`] of the [`Simulator`].
+    pub fn clipboard_mut(&mut self) -> &mut Clipboard {
+        &mut self.clipboard
+    }
+
+    /// Consumes the [`Simulator`] and returns the messages produced by the
+    /// simulated interactions.
+    pub fn into_messages(self) -> impl Iterator<Item = Message> {
+        self.messages.into_iter()
+    }
+
+    fn query<T>(&mut self, mut operation: impl Operation<T>) -> Option<T> {
+        self.raw
+            .operate(&self.renderer, &mut operation::black_box(&mut operation));
+
+        match operation.finish() {
+            Outcome::Some(output) => Some(output),
+            Outcome::None | Outcome::Chain(_) => None,
+        }
+    }
+
+    fn key_events(
+        &mut self,
+        key: keyboard::Key,
+        text: Option<SmolStr>,
+    ) -> event::Status {
+        let statuses = self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                location: keyboard::Location::Standard,
+                modifiers: self.modifiers,
+                text,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key,
+                location: keyboard::Location::Standard,
+                modifiers: self.modifiers,
+            }),
+        ]);
+
+        statuses[0]
+    }
+}
+
+/// An in-memory [`Clipboard`](core::Clipboard).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Clipboard {
+    /// The contents of the standard clipboard.
+    pub standard: Option<String>,
+
+    /// The contents of the primary clipboard.
+    pub primary: Option<String>,
+}
+
+impl core::Clipboard for Clipboard {
+    fn read(&self, kind: clipboard::Kind) -> Option<String> {
+        match kind {
+            clipboard::Kind::Standard => self.standard.clone(),
+            clipboard::Kind::Primary => self.primary.clone(),
+        }
+    }
+
+    fn write(&mut self, kind: clipboard::Kind, contents: String) {
+        match kind {
+            clipboard::Kind::Standard => self.standard = Some(contents),
+            clipboard::Kind::Primary => self.primary = Some(contents),
+        }
+    }
+}
+
+/// An error produced by a [`Simulator`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// No widget matched the given [`Selector`].
+    #[error("no widget matched the selector: {0:?}")]
+    NotFound(Selector),
+
+    /// An IO operation failed.
+    #[error("an IO operation failed: {0}")]
+    IOFailed(Arc<io::Error>),
+
+    /// The golden image of a snapshot does not exist.
+    #[error("the golden image does not exist: {0:?}")]
+    GoldenMissing(PathBuf),
+
+    /// A PNG image could not be decoded.
+    #[error("the PNG image could not be decoded: {0}")]
+    PngDecodingFailed(Arc<png::DecodingError>),
+
+    /// A PNG image could not be encoded.
+    #[error("the PNG image could not be encoded: {0}")]
+    PngEncodingFailed(Arc<png::EncodingError>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::IOFailed(Arc::new(error))
+    }
+}
+
+impl From<png::DecodingError> for Error {
+    fn from(error: png::DecodingError) -> Self {
+        Self::PngDecodingFailed(Arc::new(error))
+    }
+}
+
+impl From<png::EncodingError> for Error {
+    fn from(error: png::EncodingError) -> Self {
+        Self::PngEncodingFailed(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::widget;
+    use crate::selector::Kind;
+
+    use iced_widget::{button, column, container, text, text_input};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Save,
+        Cancel,
+        NameChanged(String),
+    }
+
+    type Element<'a> =
+        core::Element<'a, Message, core::Theme, renderer::Renderer>;
+
+    #[test]
+    fn it_clicks_buttons_by_text() {
+        let view: Element<'_> = column![
+            button("Save").on_press(Message::Save),
+            button("Cancel").on_press(Message::Cancel),
+        ]
+        .into();
+
+        let mut ui = simulator(view);
+
+        let _ = ui.click("Cancel").expect("Find cancel button");
+        let _ = ui.click("Save").expect("Find save button");
+
+        assert!(matches!(ui.find("Delete"), Err(Error::NotFound(_))));
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            vec![Message::Cancel, Message::Save]
+        );
+    }
+
+    #[test]
+    fn it_types_text() {
+        let view: Element<'_> = column![
+            text("Name"),
+            text_input("", "").on_input(Message::NameChanged),
+        ]
+        .into();
+
+        let mut ui = simulator(view);
+
+        let _ = ui.click(Kind::TextInput).expect("Find text input");
+        let _ = ui.type_text("Hi");
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            vec![
+                Message::NameChanged(String::from("H")),
+                Message::NameChanged(String::from("Hi")),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_finds_widgets_by_ancestry() {
+        let view: Element<'_> = column![
+            container(column![text("Item"), text("Other")])
+                .id(container::Id::new("sidebar")),
+            text("Item"),
+        ]
+        .into();
+
+        let mut ui = simulator(view);
+
+        let items = ui.find_all("Item");
+        let sidebar =
+            ui.find(widget::Id::new("sidebar")).expect("Find sidebar");
+        let item = ui
+            .find(Selector::from("Item").inside(widget::Id::new("sidebar")))
+            .expect("Find item in sidebar");
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(item, items[0]);
+        assert!(sidebar.bounds.contains(item.bounds.center()));
+        assert_eq!(ui.find_all(Kind::Text).len(), 3);
+    }
+
+    #[test]
+    fn it_replays_recorded_sessions() {
+        let view = || -> Element<'_> {
+            column![
+                button("Save").on_press(Message::Save),
+                button("Cancel").on_press(Message::Cancel),
+            ]
+            .into()
+        };
+
+        let cancel = simulator(view()).find("Cancel").expect("Find cancel");
+        let path = std::env::temp_dir()
+            .join(format!("iced_test-session-{}.json", std::process::id()));
+
+        let mut session = Session::new();
+
+        for (at, event) in [
+            mouse::Event::CursorMoved {
+                position: cancel.bounds.center(),
+            },
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            session.push(
+                std::time::Duration::from_millis(at as u64 * 100),
+                None,
+                Event::Mouse(event),
+            );
+        }
+
+        session.save(&path).expect("Save session");
+        let loaded = Session::load(&path).expect("Load session");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(loaded, session);
+
+        let mut ui = simulator(view());
+        let _ = ui.replay(&loaded);
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            vec![Message::Cancel]
+        );
+    }
+
+    #[test]
+    fn it_compares_snapshots_against_golden_images() {
+        let golden = std::env::temp_dir()
+            .join(format!("iced_test-{}", std::process::id()))
+            .join("snapshot.png");
+
+        let settings = Settings {
+            size: Size::new(100.0, 50.0),
+            scale_factor: 2.0,
+            ..Settings::default()
+        };
+
+        let snapshot = |label: &'static str| {
+            let view: Element<'_> = button(label).into();
+
+            Simulator::with_settings(settings, view)
+                .snapshot(&core::Theme::Dark)
+        };
+
+        let original = snapshot("Save");
+
+        assert_eq!(original.screenshot().size, core::Size::new(200, 100));
+        assert!(matches!(
+            original.matches_image(&golden),
+            Err(Error::GoldenMissing(_))
+        ));
+        assert!(!golden.exists());
+
+        std::fs::create_dir_all(golden.parent().unwrap())
+            .expect("Create golden directory");
+        original.save(&golden).expect("Save golden");
+
+        assert!(snapshot("Save").matches_image(&golden).expect("Compare"));
+        assert!(!snapshot("Load").matches_image(&golden).expect("Compare"));
+        assert!(golden.with_extension("diff.png").exists());
+
+        let _ = std::fs::remove_dir_all(golden.parent().unwrap());
+    }
+}
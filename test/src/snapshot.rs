@@ -0,0 +1,174 @@
+//! Compare the rendered pixels of a user interface against golden images.
+use crate::runtime::window::Screenshot;
+use crate::Error;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that allows missing golden images to be created
+/// by [`Snapshot::matches_image`].
+const UPDATE_GOLDENS: &str = "ICED_TEST_UPDATE_GOLDENS";
+
+/// The rendered pixels of a [`Simulator`](crate::Simulator).
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    screenshot: Screenshot,
+    tolerance: u8,
+}
+
+impl Snapshot {
+    /// Creates a new [`Snapshot`] from the given [`Screenshot`].
+    pub fn new(screenshot: Screenshot) -> Self {
+        Self {
+            screenshot,
+            tolerance: 0,
+        }
+    }
+
+    /// Sets the maximum difference allowed for every color channel of a
+    /// pixel when comparing the [`Snapshot`] against an image.
+    ///
+    /// By default, it is `0`; that is, pixels must match exactly.
+    pub fn tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Returns the [`Screenshot`] of the [`Snapshot`].
+    pub fn screenshot(&self) -> &Screenshot {
+        &self.screenshot
+    }
+
+    /// Encodes the [`Snapshot`] as a PNG image and writes it to the given
+    /// path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        save(
+            path.as_ref(),
+            &self.screenshot.bytes,
+            self.screenshot.size.width,
+            self.screenshot.size.height,
+        )
+    }
+
+    /// Compares the [`Snapshot`] against the golden PNG image at the given
+    /// path, returning `true` if every pixel is within the configured
+    /// tolerance.
+    ///
+    /// If the golden image does not exist, an [`Error::GoldenMissing`] is
+    /// returned. Set the `ICED_TEST_UPDATE_GOLDENS` environment variable to
+    /// save the [`Snapshot`] in its place instead.
+    ///
+    /// If the comparison fails, an image highlighting the differing pixels
+    /// is written next to the golden image with a `.diff.png` extension.
+    pub fn matches_image(&self, path: impl AsRef<Path>) -> Result<bool, Error> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            if std::env::var_os(UPDATE_GOLDENS).is_none() {
+                return Err(Error::GoldenMissing(path.to_path_buf()));
+            }
+
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            self.save(path)?;
+
+            return Ok(true);
+        }
+
+        let (golden, width, height) = load(path)?;
+        let size = self.screenshot.size;
+
+        let mut is_match = width == size.width && height == size.height;
+        let mut diff = Vec::with_capacity(self.screenshot.bytes.len());
+
+        for (i, pixel) in self.screenshot.bytes.chunks_exact(4).enumerate() {
+            let x = i as u32 % size.width;
+            let y = i as u32 / size.width;
+
+            let expected = (x < width && y < height).then(|| {
+                let offset = (y * width + x) as usize * 4;
+
+                &golden[offset..offset + 4]
+            });
+
+            let is_pixel_match = expected.is_some_and(|expected| {
+                pixel
+                    .iter()
+                    .zip(expected)
+                    .all(|(a, b)| a.abs_diff(*b) <= self.tolerance)
+            });
+
+            if is_pixel_match {
+                // Fade matching pixels to make differences stand out
+                diff.extend([pixel[0], pixel[1], pixel[2], pixel[3] / 4]);
+            } else {
+                is_match = false;
+                diff.extend([255, 0, 0, 255]);
+            }
+        }
+
+        if !is_match {
+            save(&diff_path(path), &diff, size.width, size.height)?;
+        }
+
+        Ok(is_match)
+    }
+}
+
+fn diff_path(path: &Path) -> PathBuf {
+    path.with_extension("diff.png")
+}
+
+fn save(
+    path: &Path,
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+) -> Result<(), Error> {
+    let file = io::BufWriter::new(fs::File::create(path)?);
+
+    let mut encoder = png::Encoder::new(file, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(rgba)?;
+    writer.finish()?;
+
+    Ok(())
+}
+
+fn load(path: &Path) -> Result<(Vec<u8>, u32, u32), Error> {
+    let file = io::BufReader::new(fs::File::open(path)?);
+
+    let mut decoder = png::Decoder::new(file);
+    decoder.set_transformations(
+        png::Transformations::EXPAND | png::Transformations::STRIP_16,
+    );
+
+    let mut reader = decoder.read_info()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer)?;
+
+    buffer.truncate(info.buffer_size());
+
+    let rgba = match info.color_type {
+        png::ColorType::Rgba => buffer,
+        png::ColorType::Rgb => buffer
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buffer
+            .chunks_exact(2)
+            .flat_map(|ga| [ga[0], ga[0], ga[0], ga[1]])
+            .collect(),
+        png::ColorType::Grayscale | png::ColorType::Indexed => {
+            buffer.into_iter().flat_map(|g| [g, g, g, 255]).collect()
+        }
+    };
+
+    Ok((rgba, info.width, info.height))
+}
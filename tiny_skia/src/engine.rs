@@ -402,6 +402,8 @@ impl Engine {
                 horizontal_alignment,
                 vertical_alignment,
                 shaping,
+                wrapping,
+                overflow,
                 clip_bounds: text_bounds, // TODO
             } => {
                 let physical_bounds = *text_bounds * transformation;
@@ -423,6 +425,8 @@ impl Engine {
                     *horizontal_alignment,
                     *vertical_alignment,
                     *shaping,
+                    *wrapping,
+                    *overflow,
                     pixels,
                     clip_mask,
                     transformation,
@@ -1,4 +1,4 @@
-use crate::core::text::LineHeight;
+use crate::core::text::{LineHeight, Overflow, Wrapping};
 use crate::core::{Pixels, Point, Radians, Rectangle, Size, Vector};
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
@@ -219,6 +219,8 @@ impl geometry::frame::Backend for Frame {
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
                 shaping: text.shaping,
+                wrapping: Wrapping::default(),
+                overflow: Overflow::default(),
                 clip_bounds: Rectangle::with_size(Size::INFINITY),
             });
         } else {
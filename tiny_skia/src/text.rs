@@ -1,5 +1,5 @@
 use crate::core::alignment;
-use crate::core::text::Shaping;
+use crate::core::text::{Overflow, Shaping, Wrapping};
 use crate::core::{
     Color, Font, Pixels, Point, Rectangle, Size, Transformation,
 };
@@ -111,6 +111,8 @@ impl Pipeline {
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
         shaping: Shaping,
+        wrapping: Wrapping,
+        overflow: Overflow,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
         transformation: Transformation,
@@ -127,6 +129,8 @@ impl Pipeline {
             size: size.into(),
             line_height,
             shaping,
+            wrapping,
+            overflow,
         };
 
         let (_, entry) = self.cache.get_mut().allocate(font_system, key);
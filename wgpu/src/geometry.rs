@@ -1,5 +1,5 @@
 //! Build and draw geometry.
-use crate::core::text::LineHeight;
+use crate::core::text::{LineHeight, Overflow, Wrapping};
 use crate::core::{
     Pixels, Point, Radians, Rectangle, Size, Transformation, Vector,
 };
@@ -280,6 +280,8 @@ impl geometry::frame::Backend for Frame {
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
                 shaping: text.shaping,
+                wrapping: Wrapping::default(),
+                overflow: Overflow::default(),
                 clip_bounds: self.clip_bounds,
             });
         } else {
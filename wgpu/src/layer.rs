@@ -106,6 +106,8 @@ impl Layer {
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            wrapping: text.wrapping,
+            overflow: text.overflow,
             clip_bounds: clip_bounds * transformation,
         };
 
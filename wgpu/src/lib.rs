@@ -393,6 +393,8 @@ impl Renderer {
                         horizontal_alignment: alignment::Horizontal::Left,
                         vertical_alignment: alignment::Vertical::Top,
                         shaping: core::text::Shaping::Basic,
+                        wrapping: core::text::Wrapping::default(),
+                        overflow: core::text::Overflow::default(),
                     };
 
                     renderer.fill_text(
@@ -441,6 +441,8 @@ fn prepare(
                 line_height,
                 font,
                 shaping,
+                wrapping,
+                overflow,
                 ..
             } => {
                 let (key, _) = buffer_cache.allocate(
@@ -455,6 +457,8 @@ fn prepare(
                             height: bounds.height,
                         },
                         shaping: *shaping,
+                        wrapping: *wrapping,
+                        overflow: *overflow,
                     },
                 );
 
@@ -46,6 +46,7 @@ pub struct ComboBox<
     menu_class: <Theme as menu::Catalog>::Class<'a>,
     padding: Padding,
     size: Option<f32>,
+    text_overflow: text::Overflow,
 }
 
 impl<'a, T, Message, Theme, Renderer> ComboBox<'a, T, Message, Theme, Renderer>
@@ -81,6 +82,7 @@ where
             menu_class: <Theme as Catalog>::default_menu(),
             padding: text_input::DEFAULT_PADDING,
             size: None,
+            text_overflow: text::Overflow::default(),
         }
     }
 
@@ -148,6 +150,13 @@ where
         }
     }
 
+    /// Sets the [`text::Overflow`] strategy of the options of the
+    /// [`ComboBox`].
+    pub fn text_overflow(mut self, overflow: text::Overflow) -> Self {
+        self.text_overflow = overflow;
+        self
+    }
+
     /// Sets the width of the [`ComboBox`].
     pub fn width(self, width: impl Into<Length>) -> Self {
         Self {
@@ -724,7 +733,8 @@ where
                     &self.menu_class,
                 )
                 .width(bounds.width)
-                .padding(self.padding);
+                .padding(self.padding)
+                .text_overflow(self.text_overflow);
 
                 if let Some(font) = self.font {
                     menu = menu.font(font);
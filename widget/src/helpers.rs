@@ -65,6 +65,24 @@ macro_rules! stack {
     );
 }
 
+/// Creates a new [`Rich`] text with the given [`Span`]s.
+///
+/// Any value that can be turned into a [`Span`] is accepted, like plain
+/// strings or the result of the [`span`] helper.
+///
+/// [`Rich`]: crate::text::Rich
+/// [`Span`]: crate::text::Span
+/// [`span`]: crate::span
+#[macro_export]
+macro_rules! rich_text {
+    () => (
+        $crate::text::Rich::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::text::Rich::with_spans([$($crate::text::Span::from($x)),+])
+    );
+}
+
 /// Creates a new [`Container`] with the provided content.
 ///
 /// [`Container`]: crate::Container
@@ -610,6 +628,42 @@ where
     Text::new(text)
 }
 
+/// Creates a new [`Rich`] text widget with the provided spans.
+///
+/// [`Rich`]: text::Rich
+pub fn rich_text<'a, Link, Message, Theme, Renderer>(
+    spans: impl IntoIterator<Item = text::Span<'a, Link, Renderer::Font>>,
+) -> text::Rich<'a, Link, Message, Theme, Renderer>
+where
+    Link: Clone,
+    Theme: text::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    text::Rich::with_spans(spans)
+}
+
+/// Creates a new [`Selectable`] text widget with the provided content.
+///
+/// [`Selectable`]: text::Selectable
+pub fn selectable_text<'a, Theme, Renderer>(
+    text: impl text::IntoFragment<'a>,
+) -> text::Selectable<'a, Theme, Renderer>
+where
+    Theme: text::selectable::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    text::Selectable::new(text)
+}
+
+/// Creates a new [`Span`] of text with the provided content.
+///
+/// [`Span`]: text::Span
+pub fn span<'a, Link, Font>(
+    text: impl text::IntoFragment<'a>,
+) -> text::Span<'a, Link, Font> {
+    text::Span::new(text)
+}
+
 /// Creates a new [`Text`] widget that displays the provided value.
 ///
 /// [`Text`]: core::widget::Text
@@ -0,0 +1,870 @@
+//! Parse and display Markdown.
+//!
+//! # Example
+//! ```no_run
+//! # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! # use iced_widget::{markdown, Theme};
+//! #
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     LinkClicked(String),
+//! }
+//!
+//! fn view<'a>(items: &'a [markdown::Item], theme: &Theme) -> Element<'a, Message> {
+//!     markdown::view(
+//!         items,
+//!         markdown::Settings::default(),
+//!         theme,
+//!         Message::LinkClicked,
+//!     )
+//! }
+//!
+//! let items = markdown::parse("This is some **Markdown**!");
+//! ```
+use crate::container;
+use crate::core::alignment;
+use crate::core::font::{self, Font};
+use crate::core::text::highlighter::Kind;
+use crate::core::theme::palette;
+use crate::core::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Theme,
+};
+use crate::rule;
+use crate::text;
+use crate::{Column, Row};
+
+/// A Markdown item.
+#[derive(Debug, Clone)]
+pub enum Item {
+    /// A heading, with its level from 1 to 6.
+    Heading(u8, Text),
+    /// A paragraph.
+    Paragraph(Text),
+    /// A block of code, with one [`Text`] per line.
+    CodeBlock {
+        /// The language of the code, if specified.
+        language: Option<String>,
+        /// The lines of code.
+        lines: Vec<Text>,
+    },
+    /// A list.
+    List {
+        /// The number of the first item, if the list is ordered.
+        start: Option<u64>,
+        /// The items of the list.
+        items: Vec<Vec<Item>>,
+    },
+    /// A block quote.
+    Quote(Vec<Item>),
+    /// A table.
+    Table {
+        /// The alignment of each column.
+        alignments: Vec<alignment::Horizontal>,
+        /// The cells of the header.
+        header: Vec<Text>,
+        /// The cells of each row.
+        rows: Vec<Vec<Text>>,
+    },
+    /// A horizontal rule.
+    Rule,
+}
+
+/// A bunch of parsed Markdown text.
+#[derive(Debug, Clone, Default)]
+pub struct Text {
+    spans: Vec<Span>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Span {
+    text: String,
+    is_strong: bool,
+    is_emphasis: bool,
+    is_code: bool,
+    link: Option<String>,
+    highlight: Option<Kind>,
+}
+
+impl Text {
+    /// Returns whether the [`Text`] is empty or not.
+    pub fn is_empty(&self) -> bool {
+        self.spans.iter().all(|span| span.text.is_empty())
+    }
+
+    fn spans(
+        &self,
+        font: Font,
+        settings: &Settings,
+        style: &Style,
+    ) -> Vec<text::Span<'static, String>> {
+        self.spans
+            .iter()
+            .map(|span| {
+                let font = Font {
+                    weight: if span.is_strong {
+                        font::Weight::Bold
+                    } else {
+                        font.weight
+                    },
+                    style: if span.is_emphasis {
+                        font::Style::Italic
+                    } else {
+                        font.style
+                    },
+                    ..if span.is_code { Font::MONOSPACE } else { font }
+                };
+
+                let color = if span.link.is_some() {
+                    Some(style.link)
+                } else if span.is_code {
+                    Some(span.color(style))
+                } else {
+                    None
+                };
+
+                let text = text::Span::new(span.text.clone())
+                    .font(font)
+                    .color_maybe(color)
+                    .link_maybe(span.link.clone())
+                    .underline(span.link.is_some());
+
+                if span.is_code {
+                    text.size(settings.code_size)
+                } else {
+                    text
+                }
+            })
+            .collect()
+    }
+}
+
+impl Span {
+    fn color(&self, style: &Style) -> Color {
+        self.highlight
+            .map_or(style.inline_code, |kind| style.syntax.color(kind))
+    }
+}
+
+/// Parses the given Markdown into a list of [`Item`].
+pub fn parse(markdown: &str) -> Vec<Item> {
+    use pulldown_cmark::{CodeBlockKind, Event, Tag, TagEnd};
+
+    let mut parser = Parser::default();
+
+    for event in pulldown_cmark::Parser::new_ext(
+        markdown,
+        pulldown_cmark::Options::ENABLE_TABLES
+            | pulldown_cmark::Options::ENABLE_TASKLISTS,
+    ) {
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Paragraph | Tag::Heading { .. } => {}
+                Tag::BlockQuote(_) => {
+                    parser.open(Block::Quote);
+                }
+                Tag::CodeBlock(kind) => {
+                    parser.code = Some((
+                        match kind {
+                            CodeBlockKind::Fenced(info) => info
+                                .split_whitespace()
+                                .next()
+                                .map(str::to_owned),
+                            CodeBlockKind::Indented => None,
+                        },
+                        String::new(),
+                    ));
+                }
+                Tag::List(start) => {
+                    parser.open(Block::List {
+                        start,
+                        items: Vec::new(),
+                    });
+                }
+                Tag::Item => {
+                    parser.open(Block::Item);
+                }
+                Tag::Table(alignments) => {
+                    parser.table = Some(Table {
+                        alignments: alignments
+                            .into_iter()
+                            .map(|alignment| match alignment {
+                                pulldown_cmark::Alignment::Center => {
+                                    alignment::Horizontal::Center
+                                }
+                                pulldown_cmark::Alignment::Right => {
+                                    alignment::Horizontal::Right
+                                }
+                                pulldown_cmark::Alignment::Left
+                                | pulldown_cmark::Alignment::None => {
+                                    alignment::Horizontal::Left
+                                }
+                            })
+                            .collect(),
+                        ..Table::default()
+                    });
+                }
+                Tag::Emphasis => {
+                    parser.emphasis += 1;
+                }
+                Tag::Strong => {
+                    parser.strong += 1;
+                }
+                Tag::Link { dest_url, .. } => {
+                    parser.link = Some(dest_url.into_string());
+                }
+                _ => {}
+            },
+            Event::End(tag) => match tag {
+                TagEnd::Paragraph => {
+                    let text = parser.take_text();
+                    parser.push(Item::Paragraph(text));
+                }
+                TagEnd::Heading(level) => {
+                    let text = parser.take_text();
+                    parser.push(Item::Heading(level as u8, text));
+                }
+                TagEnd::BlockQuote => {
+                    if let Some((Block::Quote, items)) = parser.close() {
+                        parser.push(Item::Quote(items));
+                    }
+                }
+                TagEnd::CodeBlock => {
+                    if let Some((language, code)) = parser.code.take() {
+                        let lines = highlight(&code, language.as_deref());
+
+                        parser.push(Item::CodeBlock { language, lines });
+                    }
+                }
+                TagEnd::Item => {
+                    parser.flush();
+                    parser.close_item();
+                }
+                TagEnd::List(_) => {
+                    if let Some((Block::List { start, items }, _)) =
+                        parser.close()
+                    {
+                        parser.push(Item::List { start, items });
+                    }
+                }
+                TagEnd::TableCell => {
+                    let text = parser.take_text();
+
+                    if let Some(table) = &mut parser.table {
+                        table.row.push(text);
+                    }
+                }
+                TagEnd::TableHead => {
+                    if let Some(table) = &mut parser.table {
+                        table.header = std::mem::take(&mut table.row);
+                    }
+                }
+                TagEnd::TableRow => {
+                    if let Some(table) = &mut parser.table {
+                        let row = std::mem::take(&mut table.row);
+                        table.rows.push(row);
+                    }
+                }
+                TagEnd::Table => {
+                    if let Some(table) = parser.table.take() {
+                        parser.push(Item::Table {
+                            alignments: table.alignments,
+                            header: table.header,
+                            rows: table.rows,
+                        });
+                    }
+                }
+                TagEnd::Emphasis => {
+                    parser.emphasis = parser.emphasis.saturating_sub(1);
+                }
+                TagEnd::Strong => {
+                    parser.strong = parser.strong.saturating_sub(1);
+                }
+                TagEnd::Link => {
+                    parser.link = None;
+                }
+                _ => {}
+            },
+            Event::Text(text) => {
+                if let Some((_, code)) = &mut parser.code {
+                    code.push_str(&text);
+                } else {
+                    parser.span(text.into_string(), false);
+                }
+            }
+            Event::Code(code) => {
+                parser.span(code.into_string(), true);
+            }
+            Event::SoftBreak => {
+                parser.span(String::from(" "), false);
+            }
+            Event::HardBreak => {
+                parser.span(String::from("\n"), false);
+            }
+            Event::Rule => {
+                parser.push(Item::Rule);
+            }
+            Event::TaskListMarker(is_checked) => {
+                parser.span(
+                    String::from(if is_checked { "☑ " } else { "☐ " }),
+                    false,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    parser.flush();
+
+    parser
+        .blocks
+        .into_iter()
+        .next()
+        .map(|(_, items)| items)
+        .unwrap_or_default()
+}
+
+#[derive(Debug)]
+enum Block {
+    Root,
+    Quote,
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Item>>,
+    },
+    Item,
+}
+
+#[derive(Debug, Default)]
+struct Table {
+    alignments: Vec<alignment::Horizontal>,
+    header: Vec<Text>,
+    rows: Vec<Vec<Text>>,
+    row: Vec<Text>,
+}
+
+#[derive(Debug)]
+struct Parser {
+    blocks: Vec<(Block, Vec<Item>)>,
+    spans: Vec<Span>,
+    code: Option<(Option<String>, String)>,
+    table: Option<Table>,
+    strong: usize,
+    emphasis: usize,
+    link: Option<String>,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self {
+            blocks: vec![(Block::Root, Vec::new())],
+            spans: Vec::new(),
+            code: None,
+            table: None,
+            strong: 0,
+            emphasis: 0,
+            link: None,
+        }
+    }
+}
+
+impl Parser {
+    fn span(&mut self, text: String, is_code: bool) {
+        self.spans.push(Span {
+            text,
+            is_strong: self.strong > 0,
+            is_emphasis: self.emphasis > 0,
+            is_code,
+            link: self.link.clone(),
+            highlight: None,
+        });
+    }
+
+    fn take_text(&mut self) -> Text {
+        Text {
+            spans: std::mem::take(&mut self.spans),
+        }
+    }
+
+    fn push(&mut self, item: Item) {
+        if let Some((_, items)) = self.blocks.last_mut() {
+            items.push(item);
+        }
+    }
+
+    /// Turns any loose text into a paragraph, like the text of the items
+    /// of tight lists.
+    fn flush(&mut self) {
+        if !self.spans.is_empty() {
+            let text = self.take_text();
+            self.push(Item::Paragraph(text));
+        }
+    }
+
+    fn open(&mut self, block: Block) {
+        self.flush();
+        self.blocks.push((block, Vec::new()));
+    }
+
+    fn close(&mut self) -> Option<(Block, Vec<Item>)> {
+        self.flush();
+
+        if self.blocks.len() > 1 {
+            self.blocks.pop()
+        } else {
+            None
+        }
+    }
+
+    fn close_item(&mut self) {
+        let Some((Block::Item, items)) = self.close() else {
+            return;
+        };
+
+        if let Some((Block::List { items: list, .. }, _)) =
+            self.blocks.last_mut()
+        {
+            list.push(items);
+        }
+    }
+}
+
+#[cfg(feature = "highlighter")]
+fn highlight(code: &str, language: Option<&str>) -> Vec<Text> {
+    use crate::core::text::highlighter::Highlighter as _;
+
+    let mut highlighter =
+        iced_highlighter::Highlighter::new(&iced_highlighter::Settings {
+            theme: iced_highlighter::Theme::Palette,
+            extension: language.unwrap_or("txt").to_owned(),
+        });
+
+    code.lines()
+        .map(|line| {
+            let mut spans = Vec::new();
+            let mut last = 0;
+
+            for (range, highlight) in highlighter.highlight_line(line) {
+                if range.start > last {
+                    spans.push(code_span(&line[last..range.start], None));
+                }
+
+                spans.push(code_span(&line[range.clone()], highlight.kind()));
+                last = range.end;
+            }
+
+            if last < line.len() || spans.is_empty() {
+                spans.push(code_span(&line[last..], None));
+            }
+
+            Text { spans }
+        })
+        .collect()
+}
+
+#[cfg(feature = "highlighter")]
+fn code_span(text: &str, highlight: Option<Kind>) -> Span {
+    Span {
+        text: text.to_owned(),
+        is_code: true,
+        highlight,
+        ..Span::default()
+    }
+}
+
+#[cfg(not(feature = "highlighter"))]
+fn highlight(code: &str, _language: Option<&str>) -> Vec<Text> {
+    code.lines()
+        .map(|line| Text {
+            spans: vec![Span {
+                text: line.to_owned(),
+                is_code: true,
+                ..Span::default()
+            }],
+        })
+        .collect()
+}
+
+/// The sizes and spacing used to display Markdown.
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    /// The size of paragraphs.
+    pub text_size: Pixels,
+    /// The size of level 1 headings.
+    pub h1_size: Pixels,
+    /// The size of level 2 headings.
+    pub h2_size: Pixels,
+    /// The size of level 3 headings.
+    pub h3_size: Pixels,
+    /// The size of level 4 headings.
+    pub h4_size: Pixels,
+    /// The size of level 5 headings.
+    pub h5_size: Pixels,
+    /// The size of level 6 headings.
+    pub h6_size: Pixels,
+    /// The size of code.
+    pub code_size: Pixels,
+    /// The spacing between items.
+    pub spacing: Pixels,
+}
+
+impl Settings {
+    /// Creates new [`Settings`] scaled from the given text size.
+    pub fn with_text_size(text_size: impl Into<Pixels>) -> Self {
+        let text_size = text_size.into();
+
+        Self {
+            text_size,
+            h1_size: text_size * 2.0,
+            h2_size: text_size * 1.75,
+            h3_size: text_size * 1.5,
+            h4_size: text_size * 1.25,
+            h5_size: text_size,
+            h6_size: text_size,
+            code_size: text_size * 0.875,
+            spacing: text_size * 0.875,
+        }
+    }
+
+    fn heading_size(&self, level: u8) -> Pixels {
+        match level {
+            1 => self.h1_size,
+            2 => self.h2_size,
+            3 => self.h3_size,
+            4 => self.h4_size,
+            5 => self.h5_size,
+            _ => self.h6_size,
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::with_text_size(16)
+    }
+}
+
+/// Displays a list of Markdown [`Item`] with the given [`Settings`] and the
+/// [`Style`] of the given theme.
+///
+/// The destination of any clicked link is passed to `on_link_click`.
+pub fn view<'a, Message, Theme, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: Settings,
+    theme: &Theme,
+    on_link_click: impl Fn(String) -> Message + Clone + 'a,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>:
+        From<container::StyleFn<'a, Theme>>,
+    Renderer: crate::core::text::Renderer<Font = Font> + 'a,
+{
+    let style = theme.markdown();
+
+    blocks(items, &settings, &style, &on_link_click)
+}
+
+fn blocks<'a, Message, Theme, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: &Settings,
+    style: &Style,
+    on_link_click: &(impl Fn(String) -> Message + Clone + 'a),
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>:
+        From<container::StyleFn<'a, Theme>>,
+    Renderer: crate::core::text::Renderer<Font = Font> + 'a,
+{
+    Column::with_children(
+        items
+            .into_iter()
+            .map(|item| self::item(item, settings, style, on_link_click)),
+    )
+    .spacing(settings.spacing)
+    .width(Length::Fill)
+    .into()
+}
+
+fn item<'a, Message, Theme, Renderer>(
+    item: &'a Item,
+    settings: &Settings,
+    style: &Style,
+    on_link_click: &(impl Fn(String) -> Message + Clone + 'a),
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>:
+        From<container::StyleFn<'a, Theme>>,
+    Renderer: crate::core::text::Renderer<Font = Font> + 'a,
+{
+    let paragraph = |text: &Text, font: Font, size: Pixels| {
+        text::Rich::with_spans(text.spans(font, settings, style))
+            .size(size)
+            .width(Length::Fill)
+            .on_link_click(on_link_click.clone())
+    };
+
+    match item {
+        Item::Heading(level, text) => {
+            paragraph(text, BOLD, settings.heading_size(*level)).into()
+        }
+        Item::Paragraph(text) => {
+            paragraph(text, Font::DEFAULT, settings.text_size).into()
+        }
+        Item::CodeBlock { lines, .. } => {
+            let background = style.code_block;
+            let radius = settings.spacing.0 / 4.0;
+
+            boxed(
+                Column::with_children(lines.iter().map(|line| {
+                    paragraph(line, Font::MONOSPACE, settings.code_size).into()
+                }))
+                .width(Length::Fill),
+            )
+            .padding(settings.spacing.0 / 2.0)
+            .style(move |_theme| container::Style {
+                background: Some(background),
+                border: Border::rounded(radius),
+                ..container::Style::default()
+            })
+            .into()
+        }
+        Item::List { start, items } => {
+            Column::with_children(items.iter().enumerate().map(|(i, item)| {
+                let marker = match start {
+                    Some(start) => format!("{}.", *start + i as u64),
+                    None => String::from("•"),
+                };
+
+                Row::new()
+                    .push(text::Text::new(marker).size(settings.text_size))
+                    .push(blocks(item, settings, style, on_link_click))
+                    .spacing(settings.spacing.0 / 2.0)
+                    .into()
+            }))
+            .spacing(settings.spacing.0 / 2.0)
+            .padding(Padding {
+                left: settings.spacing.0 / 2.0,
+                ..Padding::ZERO
+            })
+            .into()
+        }
+        Item::Quote(items) => {
+            let background = style.quote;
+            let radius = settings.spacing.0 / 4.0;
+
+            boxed(blocks(items, settings, style, on_link_click))
+                .padding(settings.spacing.0 / 2.0)
+                .style(move |_theme| container::Style {
+                    background: Some(background),
+                    border: Border::rounded(radius),
+                    ..container::Style::default()
+                })
+                .into()
+        }
+        Item::Table {
+            alignments,
+            header,
+            rows,
+        } => {
+            let row = |cells: &'a [Text], is_header: bool| {
+                Row::with_children(cells.iter().enumerate().map(|(i, cell)| {
+                    let alignment = alignments
+                        .get(i)
+                        .copied()
+                        .unwrap_or(alignment::Horizontal::Left);
+
+                    let font = if is_header { BOLD } else { Font::DEFAULT };
+
+                    paragraph(cell, font, settings.text_size)
+                        .horizontal_alignment(alignment)
+                        .into()
+                }))
+                .spacing(settings.spacing)
+            };
+
+            let header_background = style.table_header;
+
+            Column::new()
+                .push(
+                    boxed(row(header, true))
+                        .padding(settings.spacing.0 / 4.0)
+                        .style(move |_theme| container::Style {
+                            background: Some(header_background),
+                            ..container::Style::default()
+                        }),
+                )
+                .extend(rows.iter().map(|cells| {
+                    boxed(row(cells, false))
+                        .padding(settings.spacing.0 / 4.0)
+                        .into()
+                }))
+                .into()
+        }
+        Item::Rule => rule::Rule::horizontal(settings.spacing).into(),
+    }
+}
+
+const BOLD: Font = Font {
+    weight: font::Weight::Bold,
+    ..Font::DEFAULT
+};
+
+fn boxed<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> crate::Container<'a, Message, Theme, Renderer>
+where
+    Theme: container::Catalog + 'a,
+    Renderer: crate::core::Renderer,
+{
+    crate::Container::new(content).width(Length::Fill)
+}
+
+/// The appearance of Markdown.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The [`Color`] of links.
+    pub link: Color,
+    /// The [`Color`] of inline code.
+    pub inline_code: Color,
+    /// The [`Background`] of code blocks.
+    pub code_block: Background,
+    /// The [`Background`] of block quotes.
+    pub quote: Background,
+    /// The [`Background`] of the header of tables.
+    pub table_header: Background,
+    /// The colors of highlighted code.
+    ///
+    /// They are only used when the `highlighter` feature is enabled.
+    pub syntax: Syntax,
+}
+
+/// The colors of highlighted code, by kind of syntax.
+#[derive(Debug, Clone, Copy)]
+pub struct Syntax {
+    /// The [`Color`] of keywords.
+    pub keyword: Color,
+    /// The [`Color`] of the names of types.
+    pub type_name: Color,
+    /// The [`Color`] of string literals.
+    pub string: Color,
+    /// The [`Color`] of constants.
+    pub constant: Color,
+    /// The [`Color`] of comments.
+    pub comment: Color,
+    /// The [`Color`] of invalid syntax.
+    pub error: Color,
+}
+
+impl Syntax {
+    /// Creates the [`Syntax`] colors of the given palette.
+    pub fn from_palette(palette: &palette::Extended) -> Self {
+        Self {
+            keyword: Kind::Keyword.color(palette),
+            type_name: Kind::Type.color(palette),
+            string: Kind::String.color(palette),
+            constant: Kind::Constant.color(palette),
+            comment: Kind::Comment.color(palette),
+            error: Kind::Error.color(palette),
+        }
+    }
+
+    /// Returns the [`Color`] of the given [`Kind`].
+    pub fn color(&self, kind: Kind) -> Color {
+        match kind {
+            Kind::Keyword => self.keyword,
+            Kind::Type => self.type_name,
+            Kind::String => self.string,
+            Kind::Constant => self.constant,
+            Kind::Comment => self.comment,
+            Kind::Error => self.error,
+        }
+    }
+}
+
+/// The theme catalog of Markdown.
+pub trait Catalog:
+    container::Catalog + rule::Catalog + text::Catalog + Sized
+{
+    /// Returns the [`Style`] of Markdown.
+    fn markdown(&self) -> Style;
+}
+
+impl Catalog for Theme {
+    fn markdown(&self) -> Style {
+        default(self.extended_palette())
+    }
+}
+
+/// The default [`Style`] of Markdown, derived from the given palette.
+pub fn default(palette: &palette::Extended) -> Style {
+    Style {
+        link: palette.primary.base.color,
+        inline_code: palette.secondary.strong.color,
+        code_block: palette.background.weak.color.into(),
+        quote: palette.background.weak.color.scale_alpha(0.5).into(),
+        table_header: palette.background.weak.color.into(),
+        syntax: Syntax::from_palette(palette),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::mouse;
+    use crate::core::{Event, Point};
+
+    use iced_test::simulator;
+
+    fn show(items: &[Item]) -> Element<'_, String, Theme, crate::Renderer> {
+        view(items, Settings::default(), &Theme::Light, |url| url)
+    }
+
+    #[test]
+    fn it_clicks_links() {
+        let items = parse("# Title\n\n[Read the docs](docs)");
+        let mut ui = simulator(show(&items));
+
+        let _ = ui.find("Title").expect("Find heading");
+        let link = ui.find("Read the docs").expect("Find link");
+
+        ui.point_at(Point::new(link.bounds.x + 2.0, link.bounds.center_y()));
+
+        let _ = ui.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        assert_eq!(ui.into_messages().collect::<Vec<_>>(), ["docs"]);
+    }
+
+    #[test]
+    fn it_renders_task_lists() {
+        let items = parse("- [x] Done\n- [ ] Pending");
+        let mut ui = simulator(show(&items));
+
+        let _ = ui.find("☑ Done").expect("Find checked item");
+        let _ = ui.find("☐ Pending").expect("Find unchecked item");
+    }
+
+    #[cfg(feature = "highlighter")]
+    #[test]
+    fn it_highlights_code_by_kind() {
+        let lines = highlight("let answer = 42; // Truth", Some("rs"));
+
+        let kinds: Vec<_> = lines[0]
+            .spans
+            .iter()
+            .filter_map(|span| Some((span.text.trim(), span.highlight?)))
+            .collect();
+
+        assert!(kinds.contains(&("let", Kind::Keyword)), "{kinds:?}");
+        assert!(kinds.contains(&("42", Kind::Constant)), "{kinds:?}");
+        assert!(kinds.contains(&("Truth", Kind::Comment)), "{kinds:?}");
+    }
+}
@@ -39,6 +39,7 @@ pub struct Menu<
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
+    text_overflow: text::Overflow,
     font: Option<Renderer::Font>,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
@@ -73,6 +74,7 @@ where
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Basic,
+            text_overflow: text::Overflow::default(),
             font: None,
             class,
         }
@@ -111,6 +113,12 @@ where
         self
     }
 
+    /// Sets the [`text::Overflow`] strategy of the [`Menu`].
+    pub fn text_overflow(mut self, overflow: text::Overflow) -> Self {
+        self.text_overflow = overflow;
+        self
+    }
+
     /// Sets the font of the [`Menu`].
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
         self.font = Some(font.into());
@@ -197,6 +205,7 @@ where
             text_size,
             text_line_height,
             text_shaping,
+            text_overflow,
             class,
         } = menu;
 
@@ -210,6 +219,7 @@ where
                 text_size,
                 text_line_height,
                 text_shaping,
+                text_overflow,
                 padding,
                 class,
             },
@@ -332,6 +342,7 @@ where
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
+    text_overflow: text::Overflow,
     font: Option<Renderer::Font>,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
@@ -527,13 +538,18 @@ where
             renderer.fill_text(
                 Text {
                     content: option.to_string(),
-                    bounds: Size::new(f32::INFINITY, bounds.height),
+                    bounds: Size::new(
+                        bounds.width - self.padding.horizontal(),
+                        bounds.height,
+                    ),
                     size: text_size,
                     line_height: self.text_line_height,
                     font: self.font.unwrap_or_else(|| renderer.default_font()),
                     horizontal_alignment: alignment::Horizontal::Left,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
+                    wrapping: text::Wrapping::None,
+                    overflow: self.text_overflow,
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
                 if is_selected {
@@ -46,6 +46,7 @@ pub struct PickList<
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
+    text_overflow: text::Overflow,
     font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
     class: <Theme as Catalog>::Class<'a>,
@@ -81,6 +82,7 @@ where
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Basic,
+            text_overflow: text::Overflow::default(),
             font: None,
             handle: Handle::default(),
             class: <Theme as Catalog>::default(),
@@ -127,6 +129,14 @@ where
         self
     }
 
+    /// Sets the [`text::Overflow`] strategy of the [`PickList`].
+    ///
+    /// It applies to both the selected label and the options in the menu.
+    pub fn text_overflow(mut self, overflow: text::Overflow) -> Self {
+        self.text_overflow = overflow;
+        self
+    }
+
     /// Sets the font of the [`PickList`].
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
         self.font = Some(font.into());
@@ -225,6 +235,8 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
             shaping: self.text_shaping,
+            wrapping: text::Wrapping::default(),
+            overflow: text::Overflow::default(),
         };
 
         for (option, paragraph) in options.iter().zip(state.options.iter_mut())
@@ -474,9 +486,14 @@ where
             Handle::None => None,
         };
 
+        // The space taken by the handle, including its spacing with the label
+        let mut handle_width = 0.0;
+
         if let Some((font, code_point, size, line_height, shaping)) = handle {
             let size = size.unwrap_or_else(|| renderer.default_size());
 
+            handle_width = size.0 + self.padding.left;
+
             renderer.fill_text(
                 Text {
                     content: code_point.to_string(),
@@ -490,6 +507,8 @@ where
                     horizontal_alignment: alignment::Horizontal::Right,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping,
+                    wrapping: text::Wrapping::default(),
+                    overflow: text::Overflow::default(),
                 },
                 Point::new(
                     bounds.x + bounds.width - self.padding.right,
@@ -513,12 +532,17 @@ where
                     line_height: self.text_line_height,
                     font,
                     bounds: Size::new(
-                        bounds.width - self.padding.horizontal(),
+                        (bounds.width
+                            - self.padding.horizontal()
+                            - handle_width)
+                            .max(0.0),
                         f32::from(self.text_line_height.to_absolute(text_size)),
                     ),
                     horizontal_alignment: alignment::Horizontal::Left,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
+                    wrapping: text::Wrapping::default(),
+                    overflow: self.text_overflow,
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
                 if is_selected {
@@ -561,7 +585,8 @@ where
             .width(bounds.width)
             .padding(self.padding)
             .font(font)
-            .text_shaping(self.text_shaping);
+            .text_shaping(self.text_shaping)
+            .text_overflow(self.text_overflow);
 
             if let Some(text_size) = self.text_size {
                 menu = menu.text_size(text_size);
@@ -1,6 +1,52 @@
 //! Draw and interact with text.
+mod rich;
+
+pub mod selectable;
+
+pub use crate::core::text::Span;
 pub use crate::core::widget::text::*;
+pub use rich::Rich;
+pub use selectable::Selectable;
+
+use crate::core::alignment;
+use crate::core::text::Paragraph;
+use crate::core::{Point, Rectangle, Vector};
 
 /// A paragraph.
 pub type Text<'a, Theme = crate::Theme, Renderer = crate::Renderer> =
     crate::core::widget::Text<'a, Theme, Renderer>;
+
+/// Returns the offset from the top-left corner of the [`Paragraph`] to the
+/// point it is drawn at, given its alignment.
+fn alignment_offset(paragraph: &impl Paragraph) -> Vector {
+    let min_bounds = paragraph.min_bounds();
+
+    Vector::new(
+        match paragraph.horizontal_alignment() {
+            alignment::Horizontal::Left => 0.0,
+            alignment::Horizontal::Center => min_bounds.width / 2.0,
+            alignment::Horizontal::Right => min_bounds.width,
+        },
+        match paragraph.vertical_alignment() {
+            alignment::Vertical::Top => 0.0,
+            alignment::Vertical::Center => min_bounds.height / 2.0,
+            alignment::Vertical::Bottom => min_bounds.height,
+        },
+    )
+}
+
+/// Returns the point a [`Paragraph`] is drawn at inside the given bounds.
+fn anchor(paragraph: &impl Paragraph, bounds: Rectangle) -> Point {
+    Point::new(
+        match paragraph.horizontal_alignment() {
+            alignment::Horizontal::Left => bounds.x,
+            alignment::Horizontal::Center => bounds.center_x(),
+            alignment::Horizontal::Right => bounds.x + bounds.width,
+        },
+        match paragraph.vertical_alignment() {
+            alignment::Vertical::Top => bounds.y,
+            alignment::Vertical::Center => bounds.center_y(),
+            alignment::Vertical::Bottom => bounds.y + bounds.height,
+        },
+    )
+}
@@ -0,0 +1,623 @@
+use crate::core::alignment;
+use crate::core::event;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{Difference, Paragraph, Span};
+use crate::core::touch;
+use crate::core::widget::text::{
+    Catalog, LineHeight, Overflow, Shaping, Style, StyleFn, Wrapping,
+};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    self, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+use crate::text::{alignment_offset, anchor};
+
+use std::borrow::Cow;
+
+/// A bunch of [`Span`]s laid out in a single paragraph.
+///
+/// Each [`Span`] can have its own font, color, and underline. Spans with a
+/// link can be clicked and hovered, producing messages through the
+/// [`on_link_click`] and [`on_link_hover`] handlers.
+///
+/// [`on_link_click`]: Self::on_link_click
+/// [`on_link_hover`]: Self::on_link_hover
+#[allow(missing_debug_implementations)]
+pub struct Rich<
+    'a,
+    Link,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Link: Clone,
+    Theme: Catalog,
+    Renderer: core::text::Renderer,
+{
+    spans: Vec<Span<'a, Link, Renderer::Font>>,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    font: Option<Renderer::Font>,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    shaping: Shaping,
+    wrapping: Wrapping,
+    overflow: Overflow,
+    class: Theme::Class<'a>,
+    on_link_click: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    on_link_hover: Option<Box<dyn Fn(Option<Link>) -> Message + 'a>>,
+}
+
+impl<'a, Link, Message, Theme, Renderer>
+    Rich<'a, Link, Message, Theme, Renderer>
+where
+    Link: Clone,
+    Theme: Catalog,
+    Renderer: core::text::Renderer,
+{
+    /// Creates a new empty [`Rich`] text.
+    pub fn new() -> Self {
+        Self {
+            spans: Vec::new(),
+            size: None,
+            line_height: LineHeight::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            font: None,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::default(),
+            overflow: Overflow::default(),
+            class: Theme::default(),
+            on_link_click: None,
+            on_link_hover: None,
+        }
+    }
+
+    /// Creates a new [`Rich`] text with the given [`Span`]s.
+    pub fn with_spans(
+        spans: impl IntoIterator<Item = Span<'a, Link, Renderer::Font>>,
+    ) -> Self {
+        Self {
+            spans: spans.into_iter().collect(),
+            ..Self::new()
+        }
+    }
+
+    /// Adds a [`Span`] to the [`Rich`] text.
+    pub fn push(
+        mut self,
+        span: impl Into<Span<'a, Link, Renderer::Font>>,
+    ) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Sets the default size of the [`Rich`] text.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Rich`] text.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the default font of the [`Rich`] text.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the width of the [`Rich`] text boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Rich`] text boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`alignment::Horizontal`] of the [`Rich`] text.
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: alignment::Horizontal,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`alignment::Vertical`] of the [`Rich`] text.
+    pub fn vertical_alignment(
+        mut self,
+        alignment: alignment::Vertical,
+    ) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`Shaping`] strategy of the [`Rich`] text.
+    ///
+    /// By default, [`Shaping::Advanced`] is used, since the spans of a
+    /// [`Rich`] text normally mix different fonts.
+    pub fn shaping(mut self, shaping: Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// Sets the [`Wrapping`] strategy of the [`Rich`] text.
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the [`Overflow`] strategy of the [`Rich`] text.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the message that will be produced when a link [`Span`] is
+    /// clicked.
+    pub fn on_link_click(
+        mut self,
+        on_click: impl Fn(Link) -> Message + 'a,
+    ) -> Self {
+        self.on_link_click = Some(Box::new(on_click));
+        self
+    }
+
+    /// Sets the message that will be produced when the mouse cursor enters
+    /// or leaves a link [`Span`].
+    ///
+    /// The hovered link is provided, or `None` if no link is hovered anymore.
+    pub fn on_link_hover(
+        mut self,
+        on_hover: impl Fn(Option<Link>) -> Message + 'a,
+    ) -> Self {
+        self.on_link_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    /// Sets the default style of the [`Rich`] text.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the default [`Color`] of the [`Rich`] text.
+    pub fn color(self, color: impl Into<Color>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        let color = color.into();
+
+        self.style(move |_theme| Style { color: Some(color) })
+    }
+
+    /// Sets the default style class of the [`Rich`] text.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn hovered_link(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let bounds = layout.bounds();
+        let position = cursor.position_over(bounds)?;
+
+        let origin = anchor(&state.paragraph, bounds)
+            - alignment_offset(&state.paragraph);
+
+        let index = state.paragraph.hit_span(Point::new(
+            position.x - origin.x,
+            position.y - origin.y,
+        ))?;
+
+        self.spans
+            .get(index)
+            .filter(|span| span.link.is_some())
+            .map(|_| index)
+    }
+}
+
+impl<'a, Link, Message, Theme, Renderer> Default
+    for Rich<'a, Link, Message, Theme, Renderer>
+where
+    Link: Clone,
+    Theme: Catalog,
+    Renderer: core::text::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct State<P: Paragraph> {
+    spans: Vec<Span<'static, (), P::Font>>,
+    content: String,
+    paragraph: P,
+    hovered_link: Option<usize>,
+    pressed_link: Option<usize>,
+}
+
+impl<'a, Link, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Rich<'a, Link, Message, Theme, Renderer>
+where
+    Link: Clone,
+    Theme: Catalog,
+    Renderer: core::text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            spans: Vec::new(),
+            content: String::new(),
+            paragraph: Renderer::Paragraph::default(),
+            hovered_link: None,
+            pressed_link: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        layout::sized(limits, self.width, self.height, |limits| {
+            let bounds = limits.max();
+
+            let text = core::Text {
+                content: self.spans.as_slice(),
+                bounds,
+                size: self.size.unwrap_or_else(|| renderer.default_size()),
+                line_height: self.line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                horizontal_alignment: self.horizontal_alignment,
+                vertical_alignment: self.vertical_alignment,
+                shaping: self.shaping,
+                wrapping: self.wrapping,
+                overflow: self.overflow,
+            };
+
+            let is_outdated = state.spans.len() != self.spans.len()
+                || state
+                    .spans
+                    .iter()
+                    .zip(&self.spans)
+                    .any(|(old, new)| !is_same_shape(old, new));
+
+            if is_outdated {
+                state.spans = self.spans.iter().map(without_link).collect();
+                state.content =
+                    self.spans.iter().map(|span| span.text.as_ref()).collect();
+                state.paragraph = Renderer::Paragraph::with_spans(text);
+            } else {
+                match state.paragraph.compare(core::Text {
+                    content: state.content.as_str(),
+                    bounds: text.bounds,
+                    size: text.size,
+                    line_height: text.line_height,
+                    font: text.font,
+                    horizontal_alignment: text.horizontal_alignment,
+                    vertical_alignment: text.vertical_alignment,
+                    shaping: text.shaping,
+                    wrapping: text.wrapping,
+                    overflow: text.overflow,
+                }) {
+                    Difference::None => {}
+                    Difference::Bounds => state.paragraph.resize(bounds),
+                    Difference::Shape => {
+                        state.paragraph = Renderer::Paragraph::with_spans(text);
+                    }
+                }
+            }
+
+            state.paragraph.min_bounds()
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        operation.text(None, layout.bounds(), &state.content);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let hovered_link = self.hovered_link(state, layout, cursor);
+
+        if hovered_link != state.hovered_link {
+            state.hovered_link = hovered_link;
+
+            if let Some(on_link_hover) = &self.on_link_hover {
+                let link = hovered_link
+                    .and_then(|index| self.spans[index].link.clone());
+
+                shell.publish(on_link_hover(link));
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if hovered_link.is_some() =>
+            {
+                state.pressed_link = hovered_link;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let Some(pressed_link) = state.pressed_link.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if hovered_link == Some(pressed_link) {
+                    if let (Some(on_link_click), Some(link)) =
+                        (&self.on_link_click, &self.spans[pressed_link].link)
+                    {
+                        shell.publish(on_link_click(link.clone()));
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed_link = None;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if self.hovered_link(state, layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+        let color = style.color.unwrap_or(defaults.text_color);
+
+        let bounds = layout.bounds();
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        let position = anchor(&state.paragraph, bounds);
+
+        let origin = position - alignment_offset(&state.paragraph);
+
+        for (index, span) in self.spans.iter().enumerate() {
+            let is_hovered_link = state.hovered_link == Some(index);
+
+            if !span.underline && !is_hovered_link {
+                continue;
+            }
+
+            for line in state.paragraph.span_bounds(index) {
+                // We place the underline slightly below the baseline, which
+                // we estimate from the size of the text
+                let underline = Rectangle {
+                    x: origin.x + line.x,
+                    y: origin.y + line.y + line.height / 2.0 + size.0 * 0.4,
+                    width: line.width,
+                    height: 1.0,
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: underline,
+                        ..renderer::Quad::default()
+                    },
+                    span.color.unwrap_or(color),
+                );
+            }
+        }
+
+        renderer.fill_paragraph(&state.paragraph, position, color, *viewport);
+    }
+}
+
+fn is_same_shape<Link, Font: PartialEq>(
+    old: &Span<'_, (), Font>,
+    new: &Span<'_, Link, Font>,
+) -> bool {
+    old.text == new.text
+        && old.size == new.size
+        && old.line_height == new.line_height
+        && old.font == new.font
+        && old.color == new.color
+        && old.underline == new.underline
+}
+
+fn without_link<Link, Font: Copy>(
+    span: &Span<'_, Link, Font>,
+) -> Span<'static, (), Font> {
+    Span {
+        text: Cow::Owned(span.text.to_string()),
+        size: span.size,
+        line_height: span.line_height,
+        font: span.font,
+        color: span.color,
+        link: None,
+        underline: span.underline,
+    }
+}
+
+impl<'a, Link, Message, Theme, Renderer>
+    From<Rich<'a, Link, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Link: Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer + 'a,
+{
+    fn from(
+        rich: Rich<'a, Link, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(rich)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        LinkClicked(&'static str),
+    }
+
+    #[test]
+    fn it_clicks_links_of_aligned_text() {
+        for (horizontal, vertical, x, y) in [
+            (
+                alignment::Horizontal::Left,
+                alignment::Vertical::Top,
+                0.0,
+                0.0,
+            ),
+            (
+                alignment::Horizontal::Center,
+                alignment::Vertical::Center,
+                0.5,
+                0.5,
+            ),
+            (
+                alignment::Horizontal::Right,
+                alignment::Vertical::Bottom,
+                1.0,
+                1.0,
+            ),
+        ] {
+            let rich: Rich<'_, _, Message> =
+                Rich::with_spans([Span::new("Read the docs").link("docs")])
+                    .width(Length::Fill)
+                    .height(100)
+                    .horizontal_alignment(horizontal)
+                    .vertical_alignment(vertical)
+                    .on_link_click(Message::LinkClicked);
+
+            let mut ui = iced_test::simulator(rich);
+            let bounds = ui.find("Read the docs").expect("Find link").bounds;
+
+            // Aim a few pixels inside the text, away from its edges
+            ui.point_at(Point::new(
+                bounds.x + 4.0 + (bounds.width - 8.0) * x,
+                bounds.y + 4.0 + (bounds.height - 8.0) * y,
+            ));
+
+            let _ = ui.simulate([
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            ]);
+
+            assert_eq!(
+                ui.into_messages().collect::<Vec<_>>(),
+                vec![Message::LinkClicked("docs")]
+            );
+        }
+    }
+
+    #[test]
+    fn it_clicks_only_the_spans_with_links() {
+        let rich: Rich<'_, _, Message> = Rich::with_spans([
+            Span::new("Read the "),
+            Span::new("docs").link("docs"),
+        ])
+        .on_link_click(Message::LinkClicked);
+
+        let mut ui = iced_test::simulator(rich);
+        let bounds = ui.find("Read the docs").expect("Find paragraph").bounds;
+
+        for x in [bounds.x + 2.0, bounds.x + bounds.width - 2.0] {
+            ui.point_at(Point::new(x, bounds.center_y()));
+
+            let _ = ui.simulate([
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            ]);
+        }
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            vec![Message::LinkClicked("docs")]
+        );
+    }
+}
@@ -0,0 +1,561 @@
+//! Display read-only text that can be selected and copied.
+use crate::core::alignment;
+use crate::core::clipboard::{self, Clipboard};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse::{self, click};
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph};
+use crate::core::touch;
+use crate::core::widget::text::{
+    Fragment, IntoFragment, LineHeight, Shaping, Wrapping,
+};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Theme, Widget,
+};
+use crate::text::{alignment_offset, anchor};
+
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A paragraph of read-only text that can be selected.
+///
+/// The text can be selected by dragging the mouse, double clicking a word,
+/// or triple clicking a line. Once clicked, the whole text can be selected
+/// with `Ctrl+A` and the selection can be copied with `Ctrl+C`.
+#[allow(missing_debug_implementations)]
+pub struct Selectable<'a, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fragment: Fragment<'a>,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    font: Option<Renderer::Font>,
+    wrapping: Wrapping,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme, Renderer> Selectable<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Selectable`] text with the given contents.
+    pub fn new(fragment: impl IntoFragment<'a>) -> Self {
+        Self {
+            fragment: fragment.into_fragment(),
+            size: None,
+            line_height: LineHeight::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            font: None,
+            wrapping: Wrapping::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the size of the [`Selectable`] text.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Selectable`] text.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Selectable`] text.
+    ///
+    /// [`Font`]: crate::core::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the width of the [`Selectable`] text boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Selectable`] text boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`alignment::Horizontal`] of the [`Selectable`] text.
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: alignment::Horizontal,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`alignment::Vertical`] of the [`Selectable`] text.
+    pub fn vertical_alignment(
+        mut self,
+        alignment: alignment::Vertical,
+    ) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`Wrapping`] strategy of the [`Selectable`] text.
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the style of the [`Selectable`] text.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Selectable`] text.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+struct State<P: Paragraph> {
+    content: String,
+    paragraph: P,
+    anchor: usize,
+    focus: usize,
+    is_focused: bool,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl<P: Paragraph> State<P> {
+    fn selection(&self) -> Range<usize> {
+        let end = self.content.len();
+
+        self.anchor.min(self.focus).min(end)
+            ..self.anchor.max(self.focus).min(end)
+    }
+
+    fn select(&mut self, range: Range<usize>) {
+        self.anchor = range.start;
+        self.focus = range.end;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Selectable<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            content: String::new(),
+            paragraph: Renderer::Paragraph::default(),
+            anchor: 0,
+            focus: 0,
+            is_focused: false,
+            is_dragging: false,
+            last_click: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if state.content != self.fragment.as_ref() {
+            state.content = self.fragment.to_string();
+            state.select(0..0);
+        }
+
+        layout::sized(limits, self.width, self.height, |limits| {
+            state.paragraph.update(text::Text {
+                content: &self.fragment,
+                bounds: limits.max(),
+                size: self.size.unwrap_or_else(|| renderer.default_size()),
+                line_height: self.line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                horizontal_alignment: self.horizontal_alignment,
+                vertical_alignment: self.vertical_alignment,
+                // Basic shaping does not keep track of the byte offsets
+                // of glyphs, which selection needs
+                shaping: Shaping::Advanced,
+                wrapping: self.wrapping,
+                overflow: text::Overflow::default(),
+            });
+
+            state.paragraph.min_bounds()
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        operation.text(None, layout.bounds(), &state.content);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+                    state.select(0..0);
+
+                    return event::Status::Ignored;
+                };
+
+                let offset = find_offset(state, bounds, position);
+                let click = mouse::Click::new(position, state.last_click);
+
+                match click.kind() {
+                    click::Kind::Single => {
+                        if state.keyboard_modifiers.shift() {
+                            state.focus = offset;
+                        } else {
+                            state.select(offset..offset);
+                        }
+
+                        state.is_dragging = true;
+                    }
+                    click::Kind::Double => {
+                        state.select(word_at(&state.content, offset));
+                        state.is_dragging = false;
+                    }
+                    click::Kind::Triple => {
+                        state.select(line_at(&state.content, offset));
+                        state.is_dragging = false;
+                    }
+                }
+
+                state.is_focused = true;
+                state.last_click = Some(click);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.is_dragging =>
+            {
+                state.focus = find_offset(state, bounds, position);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. })
+                if state.is_focused =>
+            {
+                match key.as_ref() {
+                    keyboard::Key::Character("c")
+                        if state.keyboard_modifiers.command() =>
+                    {
+                        let selection = state.selection();
+
+                        if !selection.is_empty() {
+                            clipboard.write(
+                                clipboard::Kind::Standard,
+                                state.content[selection].to_owned(),
+                            );
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::Key::Character("a")
+                        if state.keyboard_modifiers.command() =>
+                    {
+                        state.select(0..state.content.len());
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+
+        let bounds = layout.bounds();
+        let position = anchor(&state.paragraph, bounds);
+        let selection = state.selection();
+
+        if !selection.is_empty() {
+            let origin = position - alignment_offset(&state.paragraph);
+
+            for line in state.paragraph.range_bounds(selection) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: origin.x + line.x,
+                            y: origin.y + line.y,
+                            ..line
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.selection,
+                );
+            }
+        }
+
+        renderer.fill_paragraph(
+            &state.paragraph,
+            position,
+            style.color.unwrap_or(defaults.text_color),
+            *viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Selectable<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        text: Selectable<'a, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(text)
+    }
+}
+
+/// Finds the byte offset of the content closest to the given position.
+fn find_offset<P: Paragraph>(
+    state: &State<P>,
+    bounds: Rectangle,
+    position: Point,
+) -> usize {
+    let origin =
+        anchor(&state.paragraph, bounds) - alignment_offset(&state.paragraph);
+
+    state
+        .paragraph
+        .hit_test(Point::new(position.x - origin.x, position.y - origin.y))
+        .map(text::Hit::cursor)
+        .unwrap_or(0)
+        .min(state.content.len())
+}
+
+/// Returns the range of the word at the given byte offset.
+fn word_at(content: &str, offset: usize) -> Range<usize> {
+    content
+        .split_word_bound_indices()
+        .map(|(start, word)| start..start + word.len())
+        .find(|word| word.contains(&offset))
+        .unwrap_or(offset..offset)
+}
+
+/// Returns the range of the line at the given byte offset, without its line
+/// break.
+fn line_at(content: &str, offset: usize) -> Range<usize> {
+    let start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let end = content[offset..]
+        .find(['\r', '\n'])
+        .map_or(content.len(), |i| offset + i);
+
+    start..end
+}
+
+/// The appearance of some [`Selectable`] text.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The [`Color`] of the text.
+    ///
+    /// The default, `None`, means using the inherited color.
+    pub color: Option<Color>,
+    /// The [`Color`] of the selection.
+    pub selection: Color,
+}
+
+/// The theme catalog of some [`Selectable`] text.
+pub trait Catalog: Sized {
+    /// The item class of this [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by this [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for some [`Selectable`] text.
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of some [`Selectable`] text.
+pub fn default(theme: &Theme) -> Style {
+    Style {
+        color: None,
+        selection: theme.extended_palette().primary.weak.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_test::{simulator, Simulator};
+
+    const TEXT: &str = "Error 42: disk is full";
+
+    /// Creates a [`Simulator`] of some [`Selectable`] text, pointing at its
+    /// first word.
+    fn selectable() -> Simulator<'static, (), Theme, crate::Renderer> {
+        let mut ui = simulator(Selectable::new(TEXT));
+
+        let paragraph = ui.find(TEXT).expect("Find text");
+
+        ui.point_at(Point::new(
+            paragraph.bounds.x + 4.0,
+            paragraph.bounds.center_y(),
+        ));
+
+        ui
+    }
+
+    fn copy(ui: &mut Simulator<'_, (), Theme, crate::Renderer>) -> String {
+        ui.modifiers(keyboard::Modifiers::CTRL);
+        let _ = ui.press_key(keyboard::Key::Character("c".into()));
+
+        ui.clipboard().standard.clone().unwrap_or_default()
+    }
+
+    #[test]
+    fn it_copies_the_word_under_a_double_click() {
+        let mut ui = selectable();
+
+        for _ in 0..2 {
+            let _ = ui.simulate([
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            ]);
+        }
+
+        assert_eq!(copy(&mut ui), "Error");
+    }
+
+    #[test]
+    fn it_copies_all_the_text() {
+        let mut ui = selectable();
+
+        let _ = ui.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        ui.modifiers(keyboard::Modifiers::CTRL);
+        let _ = ui.press_key(keyboard::Key::Character("a".into()));
+
+        assert_eq!(copy(&mut ui), TEXT);
+    }
+}
@@ -1,6 +1,14 @@
 //! Display a multi-line text input for text editing.
+#[cfg(feature = "search")]
+pub mod search;
+
+#[cfg(feature = "search")]
+pub use search::Search;
+
+use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod};
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
@@ -8,19 +16,22 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text::editor::{Cursor, Editor as _};
 use crate::core::text::highlighter::{self, Highlighter};
-use crate::core::text::{self, LineHeight};
+use crate::core::text::Paragraph as _;
+use crate::core::text::{self, LineHeight, Wrapping};
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{
-    Background, Border, Color, Element, Length, Padding, Pixels, Rectangle,
-    Shell, Size, Theme, Vector,
+    Background, Border, Color, Element, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, SmolStr, Theme, Vector,
 };
+use crate::text_input;
 
 use std::cell::RefCell;
 use std::fmt;
 use std::ops::DerefMut;
 use std::sync::Arc;
 
-pub use text::editor::{Action, Edit, Motion};
+pub use text::editor::{Action, Edit, Indentation, Motion};
 
 /// A multi-line text input.
 #[allow(missing_debug_implementations)]
@@ -39,11 +50,18 @@ pub struct TextEditor<
     font: Option<Renderer::Font>,
     text_size: Option<Pixels>,
     line_height: LineHeight,
+    wrapping: Wrapping,
     width: Length,
     height: Length,
     padding: Padding,
+    indentation: Indentation,
+    auto_indent: bool,
+    line_numbers: bool,
+    highlight_current_line: bool,
+    line_marker: Option<Box<dyn Fn(usize) -> Option<Marker> + 'a>>,
     class: Theme::Class<'a>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(
         &Highlighter::Highlight,
@@ -64,11 +82,18 @@ where
             font: None,
             text_size: None,
             line_height: LineHeight::default(),
+            wrapping: Wrapping::default(),
             width: Length::Fill,
             height: Length::Shrink,
             padding: Padding::new(5.0),
+            indentation: Indentation::default(),
+            auto_indent: true,
+            line_numbers: false,
+            highlight_current_line: false,
+            line_marker: None,
             class: Theme::default(),
             on_edit: None,
+            key_binding: None,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| {
                 highlighter::Format::default()
@@ -102,6 +127,20 @@ where
         self
     }
 
+    /// Sets the closure that turns key presses into [`Binding`]s, replacing
+    /// the default ones.
+    ///
+    /// The default bindings are available with [`Binding::from_key_press`],
+    /// so they can be extended or remapped. Key presses without a
+    /// [`Binding`] are ignored.
+    pub fn key_binding(
+        mut self,
+        key_binding: impl Fn(KeyPress) -> Option<Binding<Message>> + 'a,
+    ) -> Self {
+        self.key_binding = Some(Box::new(key_binding));
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextEditor`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -125,12 +164,62 @@ where
         self
     }
 
+    /// Sets the [`Wrapping`] strategy of the [`TextEditor`].
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
     /// Sets the [`Padding`] of the [`TextEditor`].
     pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
         self.padding = padding.into();
         self
     }
 
+    /// Sets the [`Indentation`] inserted by the [`TextEditor`] when pressing
+    /// Tab.
+    ///
+    /// By default, lines are indented with 4 spaces.
+    pub fn indentation(mut self, indentation: Indentation) -> Self {
+        self.indentation = indentation;
+        self
+    }
+
+    /// Sets whether the [`TextEditor`] keeps the leading whitespace of a
+    /// line when breaking it with Enter.
+    ///
+    /// It is enabled by default.
+    pub fn auto_indent(mut self, auto_indent: bool) -> Self {
+        self.auto_indent = auto_indent;
+        self
+    }
+
+    /// Sets whether the [`TextEditor`] shows the number of every line in a
+    /// gutter on its left side.
+    ///
+    /// Wrapped lines are numbered only once.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets whether the [`TextEditor`] highlights the line of the cursor.
+    pub fn highlight_current_line(mut self, highlight: bool) -> Self {
+        self.highlight_current_line = highlight;
+        self
+    }
+
+    /// Sets the function that produces the [`Marker`] of a line, given its
+    /// index. Markers are drawn in the gutter of the [`TextEditor`] and can
+    /// be used to display breakpoints or diagnostics, for instance.
+    pub fn line_marker(
+        mut self,
+        line_marker: impl Fn(usize) -> Option<Marker> + 'a,
+    ) -> Self {
+        self.line_marker = Some(Box::new(line_marker));
+        self
+    }
+
     /// Highlights the [`TextEditor`] with the given [`Highlighter`] and
     /// a strategy to turn its highlights into some text format.
     pub fn highlight<H: text::Highlighter>(
@@ -146,11 +235,18 @@ where
             font: self.font,
             text_size: self.text_size,
             line_height: self.line_height,
+            wrapping: self.wrapping,
             width: self.width,
             height: self.height,
             padding: self.padding,
+            indentation: self.indentation,
+            auto_indent: self.auto_indent,
+            line_numbers: self.line_numbers,
+            highlight_current_line: self.highlight_current_line,
+            line_marker: self.line_marker,
             class: self.class,
             on_edit: self.on_edit,
+            key_binding: self.key_binding,
             highlighter_settings: settings,
             highlighter_format: to_format,
         }
@@ -173,6 +269,130 @@ where
         self.class = class.into();
         self
     }
+
+    fn gutter_width(
+        &self,
+        line_count: usize,
+        font: Renderer::Font,
+        text_size: Pixels,
+    ) -> f32 {
+        if !self.line_numbers && self.line_marker.is_none() {
+            return 0.0;
+        }
+
+        let spacing = gutter_spacing(text_size);
+
+        let markers = if self.line_marker.is_some() {
+            self.line_height.to_absolute(text_size).0
+        } else {
+            0.0
+        };
+
+        let numbers = if self.line_numbers {
+            let digits = line_count.max(1).ilog10() as usize + 1;
+
+            // Every digit is assumed to be as wide as an eight
+            Renderer::Paragraph::with_text(text::Text {
+                content: &"8".repeat(digits),
+                bounds: Size::INFINITY,
+                size: text_size,
+                line_height: self.line_height,
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+                wrapping: Wrapping::None,
+                overflow: text::Overflow::default(),
+            })
+            .min_bounds()
+            .width
+                + spacing
+        } else {
+            0.0
+        };
+
+        spacing + markers + numbers
+    }
+
+    fn apply_binding(
+        &self,
+        binding: Binding<Message>,
+        state: &mut State<Highlighter, Renderer::Paragraph>,
+        on_edit: &dyn Fn(Action) -> Message,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let mut publish = |action| shell.publish(on_edit(action));
+
+        match binding {
+            Binding::Unfocus => {
+                state.is_focused = false;
+                state.drag_click = None;
+            }
+            Binding::Copy => {
+                if let Some(selection) = self.content.selection() {
+                    clipboard.write(clipboard::Kind::Standard, selection);
+                }
+            }
+            Binding::Cut => {
+                if let Some(selection) = self.content.selection() {
+                    clipboard.write(clipboard::Kind::Standard, selection);
+                    publish(Action::Edit(Edit::Delete));
+                }
+            }
+            Binding::Paste => {
+                if let Some(contents) =
+                    clipboard.read(clipboard::Kind::Standard)
+                {
+                    publish(Action::Edit(Edit::Paste(Arc::new(contents))));
+                }
+            }
+            Binding::Move(motion) => publish(Action::Move(motion)),
+            Binding::Select(motion) => publish(Action::Select(motion)),
+            Binding::SelectWord => publish(Action::SelectWord),
+            Binding::SelectLine => publish(Action::SelectLine),
+            Binding::Insert(c) => publish(Action::Edit(Edit::Insert(c))),
+            Binding::Enter => {
+                publish(Action::Edit(if self.auto_indent {
+                    Edit::Enter
+                } else {
+                    Edit::LineBreak
+                }));
+            }
+            Binding::Backspace => publish(Action::Edit(Edit::Backspace)),
+            Binding::Delete => publish(Action::Edit(Edit::Delete)),
+            Binding::Indent => {
+                publish(Action::Edit(Edit::Indent(self.indentation)));
+            }
+            Binding::Unindent => {
+                publish(Action::Edit(Edit::Unindent(self.indentation)));
+            }
+            Binding::Undo => publish(Action::Undo),
+            Binding::Redo => publish(Action::Redo),
+            Binding::Sequence(sequence) => {
+                for binding in sequence {
+                    self.apply_binding(
+                        binding, state, on_edit, clipboard, shell,
+                    );
+                }
+            }
+            Binding::Custom(message) => shell.publish(message),
+        }
+    }
+
+    fn text_padding<H: text::Highlighter, P: text::Paragraph>(
+        &self,
+        state: &State<H, P>,
+    ) -> Padding {
+        Padding {
+            left: self.padding.left + state.gutter_width,
+            ..self.padding
+        }
+    }
+}
+
+fn gutter_spacing(text_size: Pixels) -> f32 {
+    text_size.0 / 2.0
 }
 
 /// The content of a [`TextEditor`].
@@ -213,6 +433,67 @@ where
         internal.is_dirty = true;
     }
 
+    /// Moves the cursor of the [`Content`] to the given line and column,
+    /// clearing the selection.
+    ///
+    /// Columns are byte offsets in the line. Positions out of bounds are
+    /// clamped.
+    pub fn move_to(&mut self, line: usize, column: usize) {
+        self.perform(Action::MoveTo((line, column)));
+    }
+
+    /// Selects the text of the [`Content`] between two positions, given
+    /// as line and column, respectively.
+    ///
+    /// The cursor is placed at the `end` of the selection.
+    pub fn select_range(&mut self, start: (usize, usize), end: (usize, usize)) {
+        self.perform(Action::SelectRange { start, end });
+    }
+
+    /// Replaces the text of the [`Content`] between two positions, given
+    /// as line and column, respectively, with the given text.
+    pub fn replace_range(
+        &mut self,
+        start: (usize, usize),
+        end: (usize, usize),
+        text: impl Into<String>,
+    ) {
+        self.perform(Action::Edit(Edit::ReplaceRange {
+            start,
+            end,
+            text: Arc::new(text.into()),
+        }));
+    }
+
+    /// Deletes the text of the [`Content`] between two positions, given
+    /// as line and column, respectively.
+    pub fn delete_range(&mut self, start: (usize, usize), end: (usize, usize)) {
+        self.replace_range(start, end, String::new());
+    }
+
+    /// Reverts the last group of edits of the [`Content`].
+    ///
+    /// Consecutive typing is undone at once, and the cursor and selection
+    /// are restored to where they were before the edits.
+    pub fn undo(&mut self) {
+        self.perform(Action::Undo);
+    }
+
+    /// Reapplies the last group of edits reverted with [`Content::undo`].
+    pub fn redo(&mut self) {
+        self.perform(Action::Redo);
+    }
+
+    /// Returns whether the [`Content`] has edits that can be undone.
+    pub fn can_undo(&self) -> bool {
+        self.0.borrow().editor.can_undo()
+    }
+
+    /// Returns whether the [`Content`] has undone edits that can be redone.
+    pub fn can_redo(&self) -> bool {
+        self.0.borrow().editor.can_redo()
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -293,6 +574,14 @@ where
     pub fn cursor_position(&self) -> (usize, usize) {
         self.0.borrow().editor.cursor_position()
     }
+
+    /// Returns the start and end positions of the selection of the
+    /// [`Content`], if any.
+    ///
+    /// Positions are given as line and column, respectively.
+    pub fn selection_range(&self) -> Option<((usize, usize), (usize, usize))> {
+        self.0.borrow().editor.selection_range()
+    }
 }
 
 impl<Renderer> Default for Content<Renderer>
@@ -319,19 +608,42 @@ where
     }
 }
 
+/// A marker drawn in the gutter of a [`TextEditor`] next to a line.
+///
+/// See [`TextEditor::line_marker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Marker {
+    /// The [`Color`] of the [`Marker`].
+    pub color: Color,
+}
+
+impl Marker {
+    /// Creates a new [`Marker`] with the given [`Color`].
+    pub fn new(color: impl Into<Color>) -> Self {
+        Self {
+            color: color.into(),
+        }
+    }
+}
+
 /// The state of a [`TextEditor`].
 #[derive(Debug)]
-pub struct State<Highlighter: text::Highlighter> {
+pub struct State<Highlighter: text::Highlighter, P: text::Paragraph> {
     is_focused: bool,
+    gutter_width: f32,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
+    is_selecting_column: bool,
+    keyboard_modifiers: keyboard::Modifiers,
     partial_scroll: f32,
+    preedit: Option<input_method::Preedit>,
+    preedit_paragraph: P,
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
 }
 
-impl<Highlighter: text::Highlighter> State<Highlighter> {
+impl<Highlighter: text::Highlighter, P: text::Paragraph> State<Highlighter, P> {
     /// Returns whether the [`TextEditor`] is currently focused or not.
     pub fn is_focused(&self) -> bool {
         self.is_focused
@@ -346,15 +658,20 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> widget::tree::Tag {
-        widget::tree::Tag::of::<State<Highlighter>>()
+        widget::tree::Tag::of::<State<Highlighter, Renderer::Paragraph>>()
     }
 
     fn state(&self) -> widget::tree::State {
         widget::tree::State::new(State {
             is_focused: false,
+            gutter_width: 0.0,
             last_click: None,
             drag_click: None,
+            is_selecting_column: false,
+            keyboard_modifiers: keyboard::Modifiers::default(),
             partial_scroll: 0.0,
+            preedit: None,
+            preedit_paragraph: Renderer::Paragraph::default(),
             highlighter: RefCell::new(Highlighter::new(
                 &self.highlighter_settings,
             )),
@@ -377,7 +694,9 @@ where
         limits: &layout::Limits,
     ) -> iced_renderer::core::layout::Node {
         let mut internal = self.content.0.borrow_mut();
-        let state = tree.state.downcast_mut::<State<Highlighter>>();
+        let state = tree
+            .state
+            .downcast_mut::<State<Highlighter, Renderer::Paragraph>>();
 
         if state.highlighter_format_address != self.highlighter_format as usize
         {
@@ -396,12 +715,21 @@ where
         }
 
         let limits = limits.height(self.height);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        state.gutter_width =
+            self.gutter_width(internal.editor.line_count(), font, text_size);
+
+        let padding = self.text_padding(state);
 
         internal.editor.update(
-            limits.shrink(self.padding).max(),
-            self.font.unwrap_or_else(|| renderer.default_font()),
-            self.text_size.unwrap_or_else(|| renderer.default_size()),
+            limits.shrink(padding).max(),
+            font,
+            text_size,
             self.line_height,
+            self.wrapping,
             state.highlighter.borrow_mut().deref_mut(),
         );
 
@@ -416,7 +744,7 @@ where
                     limits
                         .height(min_bounds.height)
                         .max()
-                        .expand(Size::new(0.0, self.padding.vertical())),
+                        .expand(Size::new(0.0, padding.vertical())),
                 )
             }
         }
@@ -428,7 +756,7 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
@@ -437,21 +765,77 @@ where
             return event::Status::Ignored;
         };
 
-        let state = tree.state.downcast_mut::<State<Highlighter>>();
+        let state = tree
+            .state
+            .downcast_mut::<State<Highlighter, Renderer::Paragraph>>();
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            state.keyboard_modifiers = modifiers;
+        }
+
+        let bounds = layout.bounds();
+        let padding = self.text_padding(state);
+
+        if let Event::Window(_, window::Event::RedrawRequested(_)) = event {
+            if state.is_focused {
+                let internal = self.content.0.borrow();
+
+                if let Some(position) = caret_position(internal.editor.cursor())
+                {
+                    let text_size = self
+                        .text_size
+                        .unwrap_or_else(|| renderer.default_size());
+
+                    shell.request_input_method(&InputMethod::Enabled {
+                        cursor: Rectangle::new(
+                            position
+                                + Vector::new(
+                                    bounds.x + padding.left,
+                                    bounds.y + padding.top,
+                                ),
+                            Size::new(
+                                1.0,
+                                self.line_height.to_absolute(text_size).into(),
+                            ),
+                        ),
+                    });
+                }
+            }
+
+            return event::Status::Ignored;
+        }
+
+        let status = if state.is_focused {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
 
         let Some(update) = Update::from_event(
             event,
             state,
-            layout.bounds(),
-            self.padding,
+            status,
+            bounds,
+            padding,
             cursor,
+            self.key_binding.as_deref(),
         ) else {
             return event::Status::Ignored;
         };
 
         match update {
             Update::Click(click) => {
+                state.is_selecting_column = state.keyboard_modifiers.alt()
+                    && matches!(click.kind(), mouse::click::Kind::Single);
+
                 let action = match click.kind() {
+                    mouse::click::Kind::Single if state.is_selecting_column => {
+                        Action::AddCursor(click.position())
+                    }
                     mouse::click::Kind::Single => {
                         Action::Click(click.position())
                     }
@@ -483,26 +867,57 @@ where
             Update::Action(action) => {
                 shell.publish(on_edit(action));
             }
-            Update::Copy => {
-                if let Some(selection) = self.content.selection() {
-                    clipboard.write(clipboard::Kind::Standard, selection);
-                }
+            Update::Binding(binding) => {
+                self.apply_binding(binding, state, on_edit, clipboard, shell);
             }
-            Update::Cut => {
-                if let Some(selection) = self.content.selection() {
-                    clipboard.write(clipboard::Kind::Standard, selection);
-                    shell.publish(on_edit(Action::Edit(Edit::Delete)));
+            Update::InputMethod(event) => match event {
+                input_method::Event::Opened | input_method::Event::Closed => {
+                    state.preedit = None;
                 }
-            }
-            Update::Paste => {
-                if let Some(contents) =
-                    clipboard.read(clipboard::Kind::Standard)
-                {
-                    shell.publish(on_edit(Action::Edit(Edit::Paste(
-                        Arc::new(contents),
-                    ))));
+                input_method::Event::Preedit(content, cursor) => {
+                    if content.is_empty() {
+                        state.preedit = None;
+                    } else {
+                        let text_size = self
+                            .text_size
+                            .unwrap_or_else(|| renderer.default_size());
+
+                        state.preedit_paragraph =
+                            Renderer::Paragraph::with_text(text::Text {
+                                content: &content,
+                                bounds: Size::new(
+                                    f32::INFINITY,
+                                    self.line_height
+                                        .to_absolute(text_size)
+                                        .into(),
+                                ),
+                                size: text_size,
+                                line_height: self.line_height,
+                                font: self
+                                    .font
+                                    .unwrap_or_else(|| renderer.default_font()),
+                                horizontal_alignment:
+                                    alignment::Horizontal::Left,
+                                vertical_alignment: alignment::Vertical::Center,
+                                shaping: text::Shaping::Advanced,
+                                wrapping: Wrapping::None,
+                                overflow: text::Overflow::default(),
+                            });
+
+                        state.preedit =
+                            Some(input_method::Preedit { content, cursor });
+                    }
                 }
-            }
+                input_method::Event::Commit(content) => {
+                    state.preedit = None;
+
+                    if !content.is_empty() {
+                        shell.publish(on_edit(Action::Edit(Edit::Paste(
+                            Arc::new(content),
+                        ))));
+                    }
+                }
+            },
         }
 
         event::Status::Captured
@@ -521,7 +936,9 @@ where
         let bounds = layout.bounds();
 
         let mut internal = self.content.0.borrow_mut();
-        let state = tree.state.downcast_ref::<State<Highlighter>>();
+        let state = tree
+            .state
+            .downcast_ref::<State<Highlighter, Renderer::Paragraph>>();
 
         internal.editor.highlight(
             self.font.unwrap_or_else(|| renderer.default_font()),
@@ -553,24 +970,147 @@ where
             style.background,
         );
 
+        let padding = self.text_padding(state);
+        let translation =
+            Vector::new(bounds.x + padding.left, bounds.y + padding.top);
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = f32::from(self.line_height.to_absolute(text_size));
+
+        let (current_line, _) = internal.editor.cursor_position();
+
+        let visible_lines =
+            if self.highlight_current_line || state.gutter_width > 0.0 {
+                internal.editor.visible_lines()
+            } else {
+                Vec::new()
+            };
+
+        if self.highlight_current_line {
+            if let Some(line) =
+                visible_lines.iter().find(|line| line.index == current_line)
+            {
+                let highlight = Rectangle {
+                    x: bounds.x + state.gutter_width,
+                    y: translation.y + line.y,
+                    width: bounds.width - state.gutter_width,
+                    height: line.height,
+                };
+
+                if let Some(highlight) =
+                    bounds.expand(-style.border.width).intersection(&highlight)
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: highlight,
+                            ..renderer::Quad::default()
+                        },
+                        style.current_line,
+                    );
+                }
+            }
+        }
+
+        if state.gutter_width > 0.0 {
+            let gutter = Rectangle {
+                width: state.gutter_width,
+                ..bounds
+            }
+            .expand(-style.border.width);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: gutter,
+                    border: Border {
+                        radius: style.border.radius,
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.gutter,
+            );
+
+            // Lines are clipped like the text of the editor,
+            // so they scroll out of view together
+            let clip_bounds = Rectangle {
+                y: bounds.y + padding.top,
+                height: bounds.height - padding.vertical(),
+                ..gutter
+            };
+
+            let spacing = gutter_spacing(text_size);
+            let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+            for line in &visible_lines {
+                let y = translation.y + line.y;
+
+                if let Some(marker) = self
+                    .line_marker
+                    .as_ref()
+                    .and_then(|line_marker| line_marker(line.index))
+                {
+                    let size = line_height / 2.0;
+
+                    let dot = Rectangle {
+                        x: bounds.x + spacing + (line_height - size) / 2.0,
+                        y: y + (line_height - size) / 2.0,
+                        width: size,
+                        height: size,
+                    };
+
+                    if let Some(dot) = clip_bounds.intersection(&dot) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: dot,
+                                border: Border::rounded(size / 2.0),
+                                ..renderer::Quad::default()
+                            },
+                            marker.color,
+                        );
+                    }
+                }
+
+                if self.line_numbers {
+                    let color = if line.index == current_line {
+                        style.current_line_number
+                    } else {
+                        style.line_number
+                    };
+
+                    renderer.fill_text(
+                        text::Text {
+                            content: (line.index + 1).to_string(),
+                            bounds: Size::new(f32::INFINITY, line_height),
+                            size: text_size,
+                            line_height: self.line_height,
+                            font,
+                            horizontal_alignment: alignment::Horizontal::Right,
+                            vertical_alignment: alignment::Vertical::Top,
+                            shaping: text::Shaping::Basic,
+                            wrapping: Wrapping::None,
+                            overflow: text::Overflow::default(),
+                        },
+                        Point::new(bounds.x + state.gutter_width - spacing, y),
+                        color,
+                        clip_bounds,
+                    );
+                }
+            }
+        }
+
         renderer.fill_editor(
             &internal.editor,
-            bounds.position()
-                + Vector::new(self.padding.left, self.padding.top),
+            bounds.position() + Vector::new(padding.left, padding.top),
             defaults.text_color,
             *viewport,
         );
 
-        let translation = Vector::new(
-            bounds.x + self.padding.left,
-            bounds.y + self.padding.top,
-        );
-
         if state.is_focused {
-            match internal.editor.cursor() {
-                Cursor::Caret(position) => {
-                    let cursor =
-                        Rectangle::new(
+            for editor_cursor in internal.editor.cursors() {
+                match editor_cursor {
+                    Cursor::Caret(position) => {
+                        let cursor = Rectangle::new(
                             position + translation,
                             Size::new(
                                 1.0,
@@ -582,35 +1122,57 @@ where
                             ),
                         );
 
-                    if let Some(clipped_cursor) = bounds.intersection(&cursor) {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: Rectangle {
-                                    x: clipped_cursor.x.floor(),
-                                    y: clipped_cursor.y,
-                                    width: clipped_cursor.width,
-                                    height: clipped_cursor.height,
+                        if let Some(clipped_cursor) =
+                            bounds.intersection(&cursor)
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x: clipped_cursor.x.floor(),
+                                        y: clipped_cursor.y,
+                                        width: clipped_cursor.width,
+                                        height: clipped_cursor.height,
+                                    },
+                                    ..renderer::Quad::default()
                                 },
-                                ..renderer::Quad::default()
-                            },
-                            style.value,
-                        );
+                                style.value,
+                            );
+                        }
                     }
-                }
-                Cursor::Selection(ranges) => {
-                    for range in ranges.into_iter().filter_map(|range| {
-                        bounds.intersection(&(range + translation))
-                    }) {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: range,
-                                ..renderer::Quad::default()
-                            },
-                            style.selection,
-                        );
+                    Cursor::Selection(ranges) => {
+                        for range in ranges.into_iter().filter_map(|range| {
+                            bounds.intersection(&(range + translation))
+                        }) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: range,
+                                    ..renderer::Quad::default()
+                                },
+                                style.selection,
+                            );
+                        }
                     }
                 }
             }
+
+            if let Some((preedit, position)) = state
+                .preedit
+                .as_ref()
+                .zip(caret_position(internal.editor.cursor()))
+            {
+                renderer.with_layer(bounds, |renderer| {
+                    text_input::draw_preedit(
+                        renderer,
+                        preedit,
+                        &state.preedit_paragraph,
+                        position + translation,
+                        line_height,
+                        style.background,
+                        style.value,
+                        bounds,
+                    );
+                });
+            }
         }
     }
 
@@ -652,34 +1214,172 @@ where
     }
 }
 
-enum Update {
+/// A binding to an action in the [`TextEditor`].
+///
+/// Key presses are turned into bindings with [`TextEditor::key_binding`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Binding<Message> {
+    /// Unfocus the [`TextEditor`].
+    Unfocus,
+    /// Copy the selection of the [`TextEditor`].
+    Copy,
+    /// Cut the selection of the [`TextEditor`].
+    Cut,
+    /// Paste the clipboard contents in the [`TextEditor`].
+    Paste,
+    /// Apply a [`Motion`].
+    Move(Motion),
+    /// Select text with a given [`Motion`].
+    Select(Motion),
+    /// Select the word at the current cursor.
+    SelectWord,
+    /// Select the line at the current cursor.
+    SelectLine,
+    /// Insert the given character.
+    Insert(char),
+    /// Break the current line.
+    Enter,
+    /// Delete the previous character.
+    Backspace,
+    /// Delete the next character.
+    Delete,
+    /// Indent the selected lines with the [`Indentation`] of the
+    /// [`TextEditor`].
+    Indent,
+    /// Remove a level of indentation from the selected lines.
+    Unindent,
+    /// Revert the last group of edits.
+    Undo,
+    /// Reapply the last group of reverted edits.
+    Redo,
+    /// A sequence of bindings to execute in order.
+    Sequence(Vec<Self>),
+    /// Produce the given message.
+    Custom(Message),
+}
+
+/// A key press in a [`TextEditor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPress {
+    /// The key pressed.
+    pub key: keyboard::Key,
+    /// The state of the keyboard modifiers.
+    pub modifiers: keyboard::Modifiers,
+    /// The text produced by the key press, if any.
+    pub text: Option<SmolStr>,
+    /// The current [`Status`] of the [`TextEditor`].
+    pub status: Status,
+}
+
+impl<Message> Binding<Message> {
+    /// Returns the default [`Binding`] for the given key press.
+    ///
+    /// Key presses are only bound while the [`TextEditor`] is focused.
+    pub fn from_key_press(event: KeyPress) -> Option<Self> {
+        let KeyPress {
+            key,
+            modifiers,
+            text,
+            status,
+        } = event;
+
+        if status != Status::Focused {
+            return None;
+        }
+
+        match key.as_ref() {
+            keyboard::Key::Named(key::Named::Enter) => Some(Self::Enter),
+            keyboard::Key::Named(key::Named::Backspace) => {
+                Some(Self::Backspace)
+            }
+            keyboard::Key::Named(key::Named::Delete) => Some(Self::Delete),
+            keyboard::Key::Named(key::Named::Tab) => {
+                Some(if modifiers.shift() {
+                    Self::Unindent
+                } else {
+                    Self::Indent
+                })
+            }
+            keyboard::Key::Named(key::Named::Escape) => Some(Self::Unfocus),
+            keyboard::Key::Character("c") if modifiers.command() => {
+                Some(Self::Copy)
+            }
+            keyboard::Key::Character("x") if modifiers.command() => {
+                Some(Self::Cut)
+            }
+            keyboard::Key::Character("v")
+                if modifiers.command() && !modifiers.alt() =>
+            {
+                Some(Self::Paste)
+            }
+            keyboard::Key::Character("z" | "Z") if modifiers.command() => {
+                Some(if modifiers.shift() {
+                    Self::Redo
+                } else {
+                    Self::Undo
+                })
+            }
+            keyboard::Key::Character("y") if modifiers.command() => {
+                Some(Self::Redo)
+            }
+            _ => {
+                if let Some(c) = text
+                    .as_deref()
+                    .and_then(|text| text.chars().find(|c| !c.is_control()))
+                {
+                    return Some(Self::Insert(c));
+                }
+
+                let keyboard::Key::Named(named_key) = key.as_ref() else {
+                    return None;
+                };
+
+                let motion = motion(named_key)?;
+
+                let motion = if platform::is_jump_modifier_pressed(modifiers) {
+                    motion.widen()
+                } else {
+                    motion
+                };
+
+                Some(if modifiers.shift() {
+                    Self::Select(motion)
+                } else {
+                    Self::Move(motion)
+                })
+            }
+        }
+    }
+}
+
+enum Update<Message> {
     Click(mouse::Click),
     Scroll(f32),
     Unfocus,
     Release,
     Action(Action),
-    Copy,
-    Cut,
-    Paste,
+    Binding(Binding<Message>),
+    InputMethod(input_method::Event),
 }
 
-impl Update {
-    fn from_event<H: Highlighter>(
+impl<Message> Update<Message> {
+    fn from_event<H: Highlighter, P: text::Paragraph>(
         event: Event,
-        state: &State<H>,
+        state: &State<H, P>,
+        status: Status,
         bounds: Rectangle,
         padding: Padding,
         cursor: mouse::Cursor,
+        key_binding: Option<&dyn Fn(KeyPress) -> Option<Binding<Message>>>,
     ) -> Option<Self> {
         let action = |action| Some(Update::Action(action));
-        let edit = |edit| action(Action::Edit(edit));
 
         match event {
             Event::Mouse(event) => match event {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if let Some(cursor_position) = cursor.position_in(bounds) {
                         let cursor_position = cursor_position
-                            - Vector::new(padding.top, padding.left);
+                            - Vector::new(padding.left, padding.top);
 
                         let click = mouse::Click::new(
                             cursor_position,
@@ -699,9 +1399,17 @@ impl Update {
                 mouse::Event::CursorMoved { .. } => match state.drag_click {
                     Some(mouse::click::Kind::Single) => {
                         let cursor_position = cursor.position_in(bounds)?
-                            - Vector::new(padding.top, padding.left);
-
-                        action(Action::Drag(cursor_position))
+                            - Vector::new(padding.left, padding.top);
+
+                        match state.last_click {
+                            Some(click) if state.is_selecting_column => {
+                                action(Action::SelectColumn {
+                                    from: click.position(),
+                                    to: cursor_position,
+                                })
+                            }
+                            _ => action(Action::Drag(cursor_position)),
+                        }
                     }
                     _ => None,
                 },
@@ -721,78 +1429,45 @@ impl Update {
                 }
                 _ => None,
             },
-            Event::Keyboard(event) => match event {
-                keyboard::Event::KeyPressed {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) => {
+                let key_press = KeyPress {
                     key,
                     modifiers,
                     text,
-                    ..
-                } if state.is_focused => {
-                    match key.as_ref() {
-                        keyboard::Key::Named(key::Named::Enter) => {
-                            return edit(Edit::Enter);
-                        }
-                        keyboard::Key::Named(key::Named::Backspace) => {
-                            return edit(Edit::Backspace);
-                        }
-                        keyboard::Key::Named(key::Named::Delete) => {
-                            return edit(Edit::Delete);
-                        }
-                        keyboard::Key::Named(key::Named::Escape) => {
-                            return Some(Self::Unfocus);
-                        }
-                        keyboard::Key::Character("c")
-                            if modifiers.command() =>
-                        {
-                            return Some(Self::Copy);
-                        }
-                        keyboard::Key::Character("x")
-                            if modifiers.command() =>
-                        {
-                            return Some(Self::Cut);
-                        }
-                        keyboard::Key::Character("v")
-                            if modifiers.command() && !modifiers.alt() =>
-                        {
-                            return Some(Self::Paste);
-                        }
-                        _ => {}
-                    }
-
-                    if let Some(text) = text {
-                        if let Some(c) = text.chars().find(|c| !c.is_control())
-                        {
-                            return edit(Edit::Insert(c));
-                        }
-                    }
-
-                    if let keyboard::Key::Named(named_key) = key.as_ref() {
-                        if let Some(motion) = motion(named_key) {
-                            let motion = if platform::is_jump_modifier_pressed(
-                                modifiers,
-                            ) {
-                                motion.widen()
-                            } else {
-                                motion
-                            };
-
-                            return action(if modifiers.shift() {
-                                Action::Select(motion)
-                            } else {
-                                Action::Move(motion)
-                            });
-                        }
-                    }
+                    status,
+                };
 
-                    None
+                if let Some(key_binding) = key_binding {
+                    key_binding(key_press)
+                } else {
+                    Binding::from_key_press(key_press)
                 }
-                _ => None,
-            },
+                .map(Self::Binding)
+            }
+            Event::InputMethod(event) if state.is_focused => {
+                Some(Update::InputMethod(event))
+            }
             _ => None,
         }
     }
 }
 
+/// Returns the position of the caret of the given [`Cursor`], relative
+/// to the text of the editor.
+fn caret_position(cursor: Cursor) -> Option<Point> {
+    match cursor {
+        Cursor::Caret(position) => Some(position),
+        Cursor::Selection(ranges) => ranges
+            .last()
+            .map(|range| Point::new(range.x + range.width, range.y)),
+    }
+}
+
 fn motion(key: key::Named) -> Option<Motion> {
     match key {
         key::Named::ArrowLeft => Some(Motion::Left),
@@ -847,6 +1522,14 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Background`] of the gutter of the text input.
+    pub gutter: Background,
+    /// The [`Color`] of the line numbers of the text input.
+    pub line_number: Color,
+    /// The [`Color`] of the number of the line of the cursor.
+    pub current_line_number: Color,
+    /// The [`Color`] of the highlight of the line of the cursor.
+    pub current_line: Color,
 }
 
 /// The theme catalog of a [`TextEditor`].
@@ -891,6 +1574,10 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder: palette.background.strong.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        gutter: Background::Color(palette.background.weak.color),
+        line_number: palette.background.strong.color,
+        current_line_number: palette.background.base.text,
+        current_line: palette.background.weak.color.scale_alpha(0.5),
     };
 
     match status {
@@ -916,3 +1603,195 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Renderer;
+
+    use iced_test::{simulator, Simulator};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Act(Action),
+        Save,
+    }
+
+    type Editor<'a> =
+        TextEditor<'a, highlighter::PlainText, Message, Theme, Renderer>;
+
+    fn editor(content: &Content) -> Editor<'_> {
+        TextEditor::new(content).on_action(Message::Act)
+    }
+
+    /// Creates a [`Simulator`] for the given [`TextEditor`] and focuses it.
+    fn focus(editor: Editor<'_>) -> Simulator<'_, Message, Theme, Renderer> {
+        let mut ui = simulator(editor);
+
+        ui.point_at(Point::new(10.0, 10.0));
+
+        let _ = ui.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        ui
+    }
+
+    /// Returns the messages produced by the [`Simulator`], ignoring any
+    /// actions that are not edits.
+    fn edits(ui: Simulator<'_, Message, Theme, Renderer>) -> Vec<Message> {
+        ui.into_messages()
+            .filter(|message| {
+                !matches!(message, Message::Act(action) if !action.is_edit())
+            })
+            .collect()
+    }
+
+    /// Focuses the given [`TextEditor`], presses the given keys, and returns
+    /// the produced edits.
+    fn press(
+        editor: Editor<'_>,
+        keys: impl IntoIterator<Item = keyboard::Key>,
+    ) -> Vec<Message> {
+        let mut ui = focus(editor);
+
+        for key in keys {
+            let _ = ui.press_key(key);
+        }
+
+        edits(ui)
+    }
+
+    fn edit(edit: Edit) -> Message {
+        Message::Act(Action::Edit(edit))
+    }
+
+    #[test]
+    fn it_breaks_lines_without_indentation_when_disabled() {
+        let content = Content::with_text("    a");
+        let enter = || keyboard::Key::Named(key::Named::Enter);
+
+        assert_eq!(press(editor(&content), [enter()]), [edit(Edit::Enter)]);
+
+        assert_eq!(
+            press(editor(&content).auto_indent(false), [enter()]),
+            [edit(Edit::LineBreak)]
+        );
+    }
+
+    #[test]
+    fn it_produces_custom_bindings() {
+        let content = Content::new();
+
+        let editor = editor(&content).key_binding(|key_press| match key_press
+            .key
+            .as_ref()
+        {
+            keyboard::Key::Character("s") => {
+                Some(Binding::Custom(Message::Save))
+            }
+            _ => Binding::from_key_press(key_press),
+        });
+
+        assert_eq!(
+            press(
+                editor,
+                ["a", "s"].map(|c| keyboard::Key::Character(c.into()))
+            ),
+            [edit(Edit::Insert('a')), Message::Save]
+        );
+    }
+
+    #[test]
+    fn it_applies_sequences_of_bindings() {
+        let content = Content::new();
+
+        let editor = editor(&content).key_binding(|key_press| match key_press
+            .key
+            .as_ref()
+        {
+            keyboard::Key::Named(key::Named::Enter) => {
+                Some(Binding::Sequence(vec![
+                    Binding::Enter,
+                    Binding::Insert('-'),
+                    Binding::Insert(' '),
+                ]))
+            }
+            _ => Binding::from_key_press(key_press),
+        });
+
+        assert_eq!(
+            press(editor, [keyboard::Key::Named(key::Named::Enter)]),
+            [
+                edit(Edit::Enter),
+                edit(Edit::Insert('-')),
+                edit(Edit::Insert(' ')),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_composes_text_with_input_methods() {
+        let content = Content::new();
+        let mut ui = focus(editor(&content));
+
+        let statuses = ui.simulate([
+            Event::InputMethod(input_method::Event::Opened),
+            Event::InputMethod(input_method::Event::Preedit(
+                String::from("にほん"),
+                Some(0..9),
+            )),
+            Event::InputMethod(input_method::Event::Preedit(
+                String::from("日本"),
+                None,
+            )),
+            Event::InputMethod(input_method::Event::Commit(String::from(
+                "日本",
+            ))),
+        ]);
+
+        assert!(statuses
+            .iter()
+            .all(|status| *status == event::Status::Captured));
+
+        assert_eq!(
+            edits(ui),
+            [edit(Edit::Paste(Arc::new(String::from("日本"))))]
+        );
+    }
+
+    #[test]
+    fn it_ignores_empty_input_method_commits() {
+        let content = Content::new();
+        let mut ui = focus(editor(&content));
+
+        let _ = ui.simulate([
+            Event::InputMethod(input_method::Event::Preedit(
+                String::from("にほん"),
+                Some(0..9),
+            )),
+            Event::InputMethod(input_method::Event::Preedit(
+                String::new(),
+                None,
+            )),
+            Event::InputMethod(input_method::Event::Commit(String::new())),
+        ]);
+
+        assert_eq!(edits(ui), []);
+    }
+
+    #[test]
+    fn it_ignores_input_methods_when_unfocused() {
+        let content = Content::new();
+        let mut ui = simulator(editor(&content));
+
+        let statuses = ui.simulate([Event::InputMethod(
+            input_method::Event::Commit(String::from("日本")),
+        )]);
+
+        assert_eq!(statuses, [event::Status::Ignored]);
+        assert_eq!(edits(ui), []);
+    }
+}
@@ -0,0 +1,622 @@
+//! Find text in the [`Content`] of a [`TextEditor`].
+//!
+//! [`Content`]: super::Content
+//! [`TextEditor`]: super::TextEditor
+use crate::core::text;
+use crate::core::text::highlighter::{self, Format};
+use crate::core::{Font, Theme};
+use crate::text_editor::{Action, Content, Edit};
+
+use std::ops::Range;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// A query to find text in a [`Content`](super::Content).
+///
+/// By default, a [`Search`] matches its pattern literally and
+/// case-sensitively. Matches never span multiple lines.
+#[derive(Debug, Clone)]
+pub struct Search {
+    pattern: String,
+    is_regex: bool,
+    is_case_sensitive: bool,
+    is_whole_word: bool,
+    regex: regex::Regex,
+}
+
+impl Search {
+    /// Creates a new [`Search`] that matches the given text literally.
+    pub fn new(text: impl Into<String>) -> Self {
+        let pattern = text.into();
+        let regex = build(&regex::escape(&pattern), true)
+            .expect("Escaped pattern should be valid");
+
+        Self {
+            pattern,
+            is_regex: false,
+            is_case_sensitive: true,
+            is_whole_word: false,
+            regex,
+        }
+    }
+
+    /// Creates a new [`Search`] that matches the given regular expression.
+    ///
+    /// The syntax is documented in the [`regex`] crate.
+    ///
+    /// [`regex`]: https://docs.rs/regex
+    pub fn regex(pattern: impl Into<String>) -> Result<Self, Error> {
+        let pattern = pattern.into();
+        let regex = build(&pattern, true)?;
+
+        Ok(Self {
+            pattern,
+            is_regex: true,
+            is_case_sensitive: true,
+            is_whole_word: false,
+            regex,
+        })
+    }
+
+    /// Sets whether the [`Search`] distinguishes uppercase from lowercase.
+    pub fn case_sensitive(mut self, is_case_sensitive: bool) -> Self {
+        if is_case_sensitive != self.is_case_sensitive {
+            let pattern = if self.is_regex {
+                self.pattern.clone()
+            } else {
+                regex::escape(&self.pattern)
+            };
+
+            self.regex = build(&pattern, is_case_sensitive)
+                .expect("Pattern should remain valid");
+            self.is_case_sensitive = is_case_sensitive;
+        }
+
+        self
+    }
+
+    /// Sets whether the [`Search`] only matches whole words.
+    pub fn whole_word(mut self, is_whole_word: bool) -> Self {
+        self.is_whole_word = is_whole_word;
+        self
+    }
+
+    /// Returns the pattern of the [`Search`].
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns whether the pattern of the [`Search`] is a regular expression.
+    pub fn is_regex(&self) -> bool {
+        self.is_regex
+    }
+
+    /// Returns the byte ranges of the matches of the [`Search`] in the
+    /// given line.
+    ///
+    /// Empty matches are ignored.
+    pub fn find_in<'a>(
+        &'a self,
+        line: &'a str,
+    ) -> impl Iterator<Item = Range<usize>> + 'a {
+        self.regex
+            .find_iter(line)
+            .filter(|found| !found.is_empty())
+            .map(|found| found.range())
+            .filter(move |range| {
+                !self.is_whole_word || is_whole_word(line, range.clone())
+            })
+    }
+
+    /// Returns the text that replaces the match of the [`Search`] at the
+    /// given range of a line.
+    ///
+    /// The replacement of a regular expression may reference its capture
+    /// groups (e.g. `$1` or `${name}`). Otherwise, it is used literally.
+    pub fn replacement(
+        &self,
+        line: &str,
+        range: Range<usize>,
+        replacement: &str,
+    ) -> String {
+        if !self.is_regex {
+            return replacement.to_owned();
+        }
+
+        let mut expanded = String::new();
+
+        if let Some(captures) = self
+            .regex
+            .captures_at(line, range.start)
+            .filter(|captures| {
+                captures.get(0).map(|found| found.range()) == Some(range)
+            })
+        {
+            captures.expand(replacement, &mut expanded);
+        } else {
+            expanded.push_str(replacement);
+        }
+
+        expanded
+    }
+}
+
+impl PartialEq for Search {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+            && self.is_regex == other.is_regex
+            && self.is_case_sensitive == other.is_case_sensitive
+            && self.is_whole_word == other.is_whole_word
+    }
+}
+
+/// A match of a [`Search`] in a [`Content`](super::Content).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// The index of the line of the [`Match`].
+    pub line: usize,
+    /// The byte range of the [`Match`] in its line.
+    pub range: Range<usize>,
+}
+
+/// An error that occurred when building a [`Search`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Error {
+    /// The regular expression of the [`Search`] is invalid.
+    #[error("The regular expression is invalid: {0}")]
+    InvalidRegex(String),
+}
+
+impl From<regex::Error> for Error {
+    fn from(error: regex::Error) -> Self {
+        Error::InvalidRegex(error.to_string())
+    }
+}
+
+impl<R> Content<R>
+where
+    R: text::Renderer,
+{
+    /// Returns all the matches of the given [`Search`] in the [`Content`].
+    pub fn search(&self, search: &Search) -> Vec<Match> {
+        self.lines()
+            .enumerate()
+            .flat_map(|(line, text)| {
+                search
+                    .find_in(&text)
+                    .map(|range| Match { line, range })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Selects the next match of the given [`Search`] after the cursor,
+    /// wrapping around the end of the [`Content`].
+    ///
+    /// Returns the selected [`Match`], if any.
+    pub fn find_next(&mut self, search: &Search) -> Option<Match> {
+        let cursor = self.cursor_position();
+        let matches = self.search(search);
+
+        let next = matches
+            .iter()
+            .find(|next| (next.line, next.range.start) >= cursor)
+            .or(matches.first())?
+            .clone();
+
+        self.select_match(&next);
+
+        Some(next)
+    }
+
+    /// Selects the previous match of the given [`Search`] before the cursor,
+    /// wrapping around the start of the [`Content`].
+    ///
+    /// Returns the selected [`Match`], if any.
+    pub fn find_previous(&mut self, search: &Search) -> Option<Match> {
+        let cursor = self.cursor_position();
+        let matches = self.search(search);
+
+        let previous = matches
+            .iter()
+            .rev()
+            .find(|previous| (previous.line, previous.range.end) < cursor)
+            .or(matches.last())?
+            .clone();
+
+        self.select_match(&previous);
+
+        Some(previous)
+    }
+
+    /// Replaces the selected match of the given [`Search`] and selects the
+    /// next one.
+    ///
+    /// If the current selection is not a match, the next match is selected
+    /// without replacing anything, so it can be reviewed first. The
+    /// replacement is a single edit that can be undone.
+    ///
+    /// Returns the selected [`Match`], if any.
+    pub fn replace(
+        &mut self,
+        search: &Search,
+        replacement: &str,
+    ) -> Option<Match> {
+        let replacement = self.selection_range().and_then(|(start, end)| {
+            if start.0 != end.0 {
+                return None;
+            }
+
+            let text = self.line(start.0)?;
+            let range = search
+                .find_in(&text)
+                .find(|range| *range == (start.1..end.1))?;
+
+            Some(search.replacement(&text, range, replacement))
+        });
+
+        if let Some(replacement) = replacement {
+            self.perform(Action::Edit(Edit::Paste(Arc::new(replacement))));
+        }
+
+        self.find_next(search)
+    }
+
+    /// Replaces all the matches of the given [`Search`] in a single edit
+    /// that can be undone.
+    ///
+    /// Returns the amount of replaced matches.
+    pub fn replace_all(&mut self, search: &Search, replacement: &str) -> usize {
+        let matches = self.search(search);
+
+        let (Some(first), Some(last)) = (matches.first(), matches.last())
+        else {
+            return 0;
+        };
+
+        let (first, last) = (first.line, last.line);
+        let count = matches.len();
+        let mut matches = matches.iter().peekable();
+        let mut replaced = String::new();
+        let mut last_length = 0;
+
+        for i in first..=last {
+            let text = self.line(i).expect("Matched line should exist");
+            let mut end = 0;
+
+            while let Some(next) = matches.next_if(|next| next.line == i) {
+                replaced.push_str(&text[end..next.range.start]);
+                replaced.push_str(&search.replacement(
+                    &text,
+                    next.range.clone(),
+                    replacement,
+                ));
+
+                end = next.range.end;
+            }
+
+            replaced.push_str(&text[end..]);
+
+            if i < last {
+                replaced.push('\n');
+            }
+
+            last_length = text.len();
+        }
+
+        self.perform(Action::SelectRange {
+            start: (first, 0),
+            end: (last, last_length),
+        });
+        self.perform(Action::Edit(Edit::Paste(Arc::new(replaced))));
+
+        count
+    }
+
+    fn select_match(&mut self, found: &Match) {
+        self.perform(Action::SelectRange {
+            start: (found.line, found.range.start),
+            end: (found.line, found.range.end),
+        });
+    }
+}
+
+/// A [`Highlighter`](highlighter::Highlighter) that highlights the
+/// matches of a [`Search`].
+///
+/// It can be used with [`TextEditor::highlight`](super::TextEditor::highlight).
+/// Use a [`Layered`] highlighter to keep the highlights of another
+/// highlighter while searching.
+#[derive(Debug)]
+pub struct Highlighter {
+    search: Search,
+    current_line: usize,
+}
+
+/// A match highlighted by a search [`Highlighter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Highlight;
+
+impl Highlight {
+    /// Returns the [`Format`] of the [`Highlight`] with the given [`Theme`].
+    pub fn to_format(&self, theme: &Theme) -> Format<Font> {
+        Format {
+            color: Some(theme.extended_palette().primary.strong.color),
+            font: None,
+        }
+    }
+}
+
+impl highlighter::Highlighter for Highlighter {
+    type Settings = Search;
+    type Highlight = Highlight;
+
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Highlight)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        Self {
+            search: settings.clone(),
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        self.search = new_settings.clone();
+        self.current_line = 0;
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = self.current_line.min(line);
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        self.current_line += 1;
+
+        self.search
+            .find_in(line)
+            .map(|range| (range, Highlight))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+/// A [`Highlighter`](highlighter::Highlighter) that highlights the matches
+/// of a [`Search`] on top of the highlights of an inner highlighter.
+///
+/// For instance, it can layer the matches of a search over the syntax
+/// highlighting of some code.
+#[derive(Debug)]
+pub struct Layered<H> {
+    inner: H,
+    search: Option<Search>,
+    current_line: usize,
+}
+
+/// The settings of a [`Layered`] highlighter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layers<S> {
+    /// The settings of the inner highlighter.
+    pub inner: S,
+    /// The [`Search`] whose matches are highlighted, if any.
+    pub search: Option<Search>,
+}
+
+/// A highlight produced by a [`Layered`] highlighter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layer<T> {
+    /// A highlight of the inner highlighter.
+    Inner(T),
+    /// A match of the [`Search`].
+    Match(Highlight),
+}
+
+impl<T> Layer<T> {
+    /// Returns the [`Format`] of the [`Layer`] with the given [`Theme`],
+    /// using the given function to format the highlights of the inner
+    /// highlighter.
+    pub fn to_format(
+        &self,
+        theme: &Theme,
+        inner: impl Fn(&T, &Theme) -> Format<Font>,
+    ) -> Format<Font> {
+        match self {
+            Layer::Inner(highlight) => inner(highlight, theme),
+            Layer::Match(highlight) => highlight.to_format(theme),
+        }
+    }
+}
+
+impl<H: highlighter::Highlighter> highlighter::Highlighter for Layered<H> {
+    type Settings = Layers<H::Settings>;
+    type Highlight = Layer<H::Highlight>;
+
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Self::Highlight)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        Self {
+            inner: H::new(&settings.inner),
+            search: settings.search.clone(),
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        self.inner.update(&new_settings.inner);
+
+        if self.search != new_settings.search {
+            self.search.clone_from(&new_settings.search);
+            self.current_line = 0;
+
+            // The inner highlights must be produced again alongside
+            // the new matches
+            self.inner.change_line(0);
+        }
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = self.current_line.min(line);
+
+        // Both layers are fed the same lines, so the inner highlighter
+        // cannot be ahead of pending matches
+        self.inner.change_line(self.current_line);
+    }
+
+    fn sync<'a>(&mut self, lines: impl Iterator<Item = &'a str>) {
+        // The inner highlighter may start at a later line
+        let skipped = self.inner.current_line() - self.current_line();
+
+        self.inner.sync(lines.skip(skipped));
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        self.current_line = self.current_line() + 1;
+
+        // Later highlights take precedence, so matches go last
+        let mut highlights: Vec<_> = self
+            .inner
+            .highlight_line(line)
+            .map(|(range, highlight)| (range, Layer::Inner(highlight)))
+            .collect();
+
+        if let Some(search) = &self.search {
+            highlights.extend(
+                search
+                    .find_in(line)
+                    .map(|range| (range, Layer::Match(Highlight))),
+            );
+        }
+
+        highlights.into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line.min(self.inner.current_line())
+    }
+}
+
+fn build(
+    pattern: &str,
+    is_case_sensitive: bool,
+) -> Result<regex::Regex, regex::Error> {
+    regex::RegexBuilder::new(pattern)
+        .case_insensitive(!is_case_sensitive)
+        .build()
+}
+
+fn is_whole_word(line: &str, range: Range<usize>) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    !line[..range.start].chars().next_back().is_some_and(is_word)
+        && !line[range.end..].chars().next().is_some_and(is_word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::highlighter::{Highlighter as _, PlainText};
+
+    fn find(search: &Search, line: &str) -> Vec<Range<usize>> {
+        search.find_in(line).collect()
+    }
+
+    #[test]
+    fn it_finds_literal_text() {
+        let search = Search::new("a.b");
+
+        assert_eq!(find(&search, "a.b axb A.B"), vec![0..3]);
+        assert_eq!(
+            find(&search.case_sensitive(false), "a.b axb A.B"),
+            vec![0..3, 8..11]
+        );
+    }
+
+    #[test]
+    fn it_finds_whole_words() {
+        let search = Search::new("cat").whole_word(true);
+
+        assert_eq!(
+            find(&search, "cat concat cats cat_ (cat)"),
+            vec![0..3, 22..25]
+        );
+    }
+
+    #[test]
+    fn it_ignores_empty_matches() {
+        let search = Search::regex("x*").expect("Build regex");
+
+        assert_eq!(find(&search, "axxb"), vec![1..3]);
+    }
+
+    #[test]
+    fn it_rejects_invalid_regexes() {
+        assert!(matches!(Search::regex("("), Err(Error::InvalidRegex(_))));
+    }
+
+    #[test]
+    fn it_expands_capture_groups() {
+        let search = Search::regex(r"(?<key>\w+)=(\w+)").expect("Build regex");
+        let line = "a=1, b=2";
+
+        assert_eq!(search.replacement(line, 5..8, "$2=${key}"), "2=b");
+
+        let literal = Search::new("a=1");
+
+        assert_eq!(literal.replacement(line, 0..3, "$2"), "$2");
+    }
+
+    #[test]
+    fn it_layers_matches_over_inner_highlights() {
+        let mut highlighter = Layered::<PlainText>::new(&Layers {
+            inner: (),
+            search: Some(Search::new("b")),
+        });
+
+        let highlights: Vec<_> = highlighter.highlight_line("abc").collect();
+
+        assert_eq!(highlights, vec![(1..2, Layer::Match(Highlight))]);
+        assert_eq!(highlighter.current_line(), 1);
+
+        highlighter.update(&Layers {
+            inner: (),
+            search: None,
+        });
+
+        assert_eq!(highlighter.current_line(), 0);
+        assert_eq!(highlighter.highlight_line("abc").count(), 0);
+    }
+
+    #[test]
+    fn it_replaces_backward_selected_matches() {
+        let mut content = Content::<crate::Renderer>::with_text("foo foo");
+        let search = Search::new("foo");
+
+        content.perform(Action::SelectRange {
+            start: (0, 3),
+            end: (0, 0),
+        });
+
+        let next = content.replace(&search, "bar");
+
+        assert_eq!(content.text(), "bar foo\n");
+        assert_eq!(next.map(|found| found.range), Some(4..7));
+    }
+
+    #[test]
+    fn it_replaces_all_matches() {
+        let mut content =
+            Content::<crate::Renderer>::with_text("foo bar\nbaz\nfoo foo");
+        let search = Search::regex(r"f(o+)").expect("Build regex");
+
+        assert_eq!(content.replace_all(&search, "g$1"), 3);
+        assert_eq!(content.text(), "goo bar\nbaz\ngoo goo\n");
+
+        content.perform(Action::Undo);
+
+        assert_eq!(content.text(), "foo bar\nbaz\nfoo foo\n");
+        assert_eq!(content.replace_all(&Search::new("qux"), "x"), 0);
+    }
+}
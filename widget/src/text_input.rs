@@ -2,18 +2,22 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
 
+pub use crate::core::text::editor::Motion;
 pub use cursor::Cursor;
 pub use value::Value;
 
 use editor::Editor;
+use history::History;
 
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod};
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout;
@@ -28,7 +32,7 @@ use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
     Background, Border, Color, Element, Layout, Length, Padding, Pixels, Point,
-    Rectangle, Shell, Size, Theme, Vector, Widget,
+    Rectangle, Shell, Size, SmolStr, Theme, Vector, Widget,
 };
 use crate::runtime::Command;
 
@@ -76,6 +80,8 @@ pub struct TextInput<
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
     icon: Option<Icon<Renderer::Font>>,
+    has_history: bool,
+    key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
     class: Theme::Class<'a>,
 }
 
@@ -105,6 +111,8 @@ where
             on_paste: None,
             on_submit: None,
             icon: None,
+            has_history: true,
+            key_binding: None,
             class: Theme::default(),
         }
     }
@@ -150,6 +158,29 @@ where
         self
     }
 
+    /// Sets whether the [`TextInput`] keeps a history of its edits, which
+    /// can be undone and redone with the standard shortcuts.
+    ///
+    /// The history is enabled by default.
+    pub fn history(mut self, has_history: bool) -> Self {
+        self.has_history = has_history;
+        self
+    }
+
+    /// Sets the closure that turns key presses into [`Binding`]s, replacing
+    /// the default ones.
+    ///
+    /// The default bindings are available with [`Binding::from_key_press`],
+    /// so they can be extended or remapped. Key presses without a
+    /// [`Binding`] are ignored.
+    pub fn key_binding(
+        mut self,
+        key_binding: impl Fn(KeyPress) -> Option<Binding<Message>> + 'a,
+    ) -> Self {
+        self.key_binding = Some(Box::new(key_binding));
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -238,6 +269,8 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
             shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::default(),
+            overflow: text::Overflow::default(),
         };
 
         state.placeholder.update(placeholder_text);
@@ -262,6 +295,8 @@ where
                 horizontal_alignment: alignment::Horizontal::Center,
                 vertical_alignment: alignment::Vertical::Center,
                 shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::default(),
+                overflow: text::Overflow::default(),
             };
 
             state.icon.update(icon_text);
@@ -454,6 +489,24 @@ where
             (None, 0.0, false)
         };
 
+        let preedit = state
+            .preedit
+            .as_ref()
+            .filter(|_| {
+                state
+                    .is_focused
+                    .is_some_and(|focus| focus.is_window_focused)
+            })
+            .map(|preedit| {
+                let (position, _) = measure_cursor_and_scroll_offset(
+                    &state.value,
+                    text_bounds,
+                    state.cursor.end(value),
+                );
+
+                (preedit, text_bounds.x + position - offset)
+            });
+
         let draw = |renderer: &mut Renderer, viewport| {
             if let Some((cursor, color)) = cursor {
                 renderer.with_translation(
@@ -489,6 +542,520 @@ where
         } else {
             draw(renderer, text_bounds);
         }
+
+        if let Some((preedit, x)) = preedit {
+            renderer.with_layer(text_bounds, |renderer| {
+                draw_preedit(
+                    renderer,
+                    preedit,
+                    &state.preedit_paragraph,
+                    Point::new(x, text_bounds.y),
+                    text_bounds.height,
+                    style.background,
+                    style.value,
+                    text_bounds,
+                );
+            });
+        }
+    }
+
+    fn apply_binding(
+        &mut self,
+        binding: Binding<Message>,
+        state: &mut State<Renderer::Paragraph>,
+        renderer: &Renderer,
+        layout: Layout<'_>,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(on_input) = self.on_input.as_ref() else {
+            return;
+        };
+
+        let update_cache = |state, value| {
+            replace_paragraph(
+                renderer,
+                state,
+                layout,
+                value,
+                self.font,
+                self.size,
+                self.line_height,
+            );
+        };
+
+        if !matches!(
+            binding,
+            Binding::Insert(_)
+                | Binding::Backspace
+                | Binding::Delete
+                | Binding::BackspaceWord
+                | Binding::DeleteWord
+                | Binding::Copy
+                | Binding::Sequence(_)
+                | Binding::Capture
+                | Binding::Custom(_)
+        ) {
+            state.history.break_group();
+        }
+
+        match binding {
+            Binding::Unfocus => {
+                state.is_focused = None;
+                state.is_dragging = false;
+                state.is_pasting = None;
+
+                state.keyboard_modifiers = keyboard::Modifiers::default();
+            }
+            Binding::Copy => {
+                if self.is_secure {
+                    return;
+                }
+
+                if let Some((start, end)) = state.cursor.selection(&self.value)
+                {
+                    clipboard.write(
+                        clipboard::Kind::Standard,
+                        self.value.select(start, end).to_string(),
+                    );
+                }
+            }
+            Binding::Cut => {
+                if self.is_secure {
+                    return;
+                }
+
+                let Some((start, end)) = state.cursor.selection(&self.value)
+                else {
+                    return;
+                };
+
+                clipboard.write(
+                    clipboard::Kind::Standard,
+                    self.value.select(start, end).to_string(),
+                );
+
+                let before = self
+                    .has_history
+                    .then(|| (self.value.clone(), state.cursor));
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut state.cursor);
+                editor.delete();
+
+                let message = (on_input)(editor.contents());
+                shell.publish(message);
+
+                if let Some((value, cursor)) = before {
+                    state.history.record(
+                        history::Kind::Other,
+                        value,
+                        cursor,
+                        &self.value,
+                    );
+                }
+
+                update_cache(state, &self.value);
+            }
+            Binding::Paste => {
+                let content = match state.is_pasting.take() {
+                    Some(content) => content,
+                    None => {
+                        let content: String = clipboard
+                            .read(clipboard::Kind::Standard)
+                            .unwrap_or_default()
+                            .chars()
+                            .filter(|c| !c.is_control())
+                            .collect();
+
+                        Value::new(&content)
+                    }
+                };
+
+                let before = self
+                    .has_history
+                    .then(|| (self.value.clone(), state.cursor));
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut state.cursor);
+
+                editor.paste(content.clone());
+
+                let message = if let Some(paste) = &self.on_paste {
+                    (paste)(editor.contents())
+                } else {
+                    (on_input)(editor.contents())
+                };
+                shell.publish(message);
+
+                if let Some((value, cursor)) = before {
+                    state.history.record(
+                        history::Kind::Other,
+                        value,
+                        cursor,
+                        &self.value,
+                    );
+                }
+
+                state.is_pasting = Some(content);
+
+                update_cache(state, &self.value);
+            }
+            Binding::Move(motion) => {
+                apply_motion(
+                    &mut state.cursor,
+                    &self.value,
+                    motion,
+                    self.is_secure,
+                    false,
+                );
+            }
+            Binding::Select(motion) => {
+                apply_motion(
+                    &mut state.cursor,
+                    &self.value,
+                    motion,
+                    self.is_secure,
+                    true,
+                );
+            }
+            Binding::SelectAll => {
+                state.cursor.select_all(&self.value);
+            }
+            Binding::Insert(c) => {
+                state.is_pasting = None;
+
+                let before = self
+                    .has_history
+                    .then(|| (self.value.clone(), state.cursor));
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut state.cursor);
+
+                editor.insert(c);
+
+                let message = (on_input)(editor.contents());
+                shell.publish(message);
+
+                if let Some((value, cursor)) = before {
+                    state.history.record(
+                        history::Kind::Insert {
+                            is_whitespace: c.is_whitespace(),
+                        },
+                        value,
+                        cursor,
+                        &self.value,
+                    );
+                }
+
+                update_cache(state, &self.value);
+            }
+            Binding::Backspace | Binding::BackspaceWord => {
+                if matches!(binding, Binding::BackspaceWord)
+                    && state.cursor.selection(&self.value).is_none()
+                {
+                    if self.is_secure {
+                        let cursor_pos = state.cursor.end(&self.value);
+                        state.cursor.select_range(0, cursor_pos);
+                    } else {
+                        state.cursor.select_left_by_words(&self.value);
+                    }
+                }
+
+                let before = self
+                    .has_history
+                    .then(|| (self.value.clone(), state.cursor));
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut state.cursor);
+                editor.backspace();
+
+                let message = (on_input)(editor.contents());
+                shell.publish(message);
+
+                if let Some((value, cursor)) = before {
+                    state.history.record(
+                        history::Kind::Backspace,
+                        value,
+                        cursor,
+                        &self.value,
+                    );
+                }
+
+                update_cache(state, &self.value);
+            }
+            Binding::Delete | Binding::DeleteWord => {
+                if matches!(binding, Binding::DeleteWord)
+                    && state.cursor.selection(&self.value).is_none()
+                {
+                    if self.is_secure {
+                        let cursor_pos = state.cursor.end(&self.value);
+                        state.cursor.select_range(cursor_pos, self.value.len());
+                    } else {
+                        state.cursor.select_right_by_words(&self.value);
+                    }
+                }
+
+                let before = self
+                    .has_history
+                    .then(|| (self.value.clone(), state.cursor));
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut state.cursor);
+                editor.delete();
+
+                let message = (on_input)(editor.contents());
+                shell.publish(message);
+
+                if let Some((value, cursor)) = before {
+                    state.history.record(
+                        history::Kind::Delete,
+                        value,
+                        cursor,
+                        &self.value,
+                    );
+                }
+
+                update_cache(state, &self.value);
+            }
+            Binding::Submit => {
+                if let Some(on_submit) = self.on_submit.clone() {
+                    shell.publish(on_submit);
+                }
+            }
+            Binding::Undo | Binding::Redo => {
+                if !self.has_history {
+                    return;
+                }
+
+                let is_restored = if matches!(binding, Binding::Redo) {
+                    state.history.redo(&mut self.value, &mut state.cursor)
+                } else {
+                    state.history.undo(&mut self.value, &mut state.cursor)
+                };
+
+                if is_restored {
+                    shell.publish((on_input)(self.value.to_string()));
+
+                    update_cache(state, &self.value);
+                }
+
+                state.is_pasting = None;
+            }
+            Binding::Sequence(sequence) => {
+                for binding in sequence {
+                    self.apply_binding(
+                        binding, state, renderer, layout, clipboard, shell,
+                    );
+                }
+            }
+            Binding::Capture => {}
+            Binding::Custom(message) => {
+                shell.publish(message);
+            }
+        }
+    }
+}
+
+/// A binding to an action in a [`TextInput`].
+///
+/// Key presses are turned into bindings with [`TextInput::key_binding`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Binding<Message> {
+    /// Unfocus the [`TextInput`].
+    Unfocus,
+    /// Copy the selection of the [`TextInput`].
+    Copy,
+    /// Cut the selection of the [`TextInput`].
+    Cut,
+    /// Paste the clipboard contents in the [`TextInput`].
+    Paste,
+    /// Apply a [`Motion`].
+    ///
+    /// Since a [`TextInput`] only has a single line, vertical motions
+    /// move to its start or end.
+    Move(Motion),
+    /// Select text with a given [`Motion`].
+    Select(Motion),
+    /// Select all the text of the [`TextInput`].
+    SelectAll,
+    /// Insert the given character.
+    Insert(char),
+    /// Delete the selection or the previous character.
+    Backspace,
+    /// Delete the selection or the next character.
+    Delete,
+    /// Delete the selection or the previous word.
+    BackspaceWord,
+    /// Delete the selection or the next word.
+    DeleteWord,
+    /// Produce the message set with [`TextInput::on_submit`], if any.
+    Submit,
+    /// Revert the last group of edits.
+    Undo,
+    /// Reapply the last group of reverted edits.
+    Redo,
+    /// A sequence of bindings to execute in order.
+    Sequence(Vec<Self>),
+    /// Capture the key press without doing anything else.
+    Capture,
+    /// Produce the given message.
+    Custom(Message),
+}
+
+/// A key press in a [`TextInput`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPress {
+    /// The key pressed.
+    pub key: keyboard::Key,
+    /// The state of the keyboard modifiers.
+    pub modifiers: keyboard::Modifiers,
+    /// The text produced by the key press, if any.
+    pub text: Option<SmolStr>,
+    /// The current [`Status`] of the [`TextInput`].
+    pub status: Status,
+}
+
+impl<Message> Binding<Message> {
+    /// Returns the default [`Binding`] for the given key press.
+    ///
+    /// Key presses are only bound while the [`TextInput`] is focused. Tab
+    /// and the vertical arrows are left unbound, so they can be used to
+    /// change focus.
+    pub fn from_key_press(event: KeyPress) -> Option<Self> {
+        let KeyPress {
+            key,
+            modifiers,
+            text,
+            status,
+        } = event;
+
+        if status != Status::Focused {
+            return None;
+        }
+
+        let is_jump_modifier_pressed =
+            platform::is_jump_modifier_pressed(modifiers);
+
+        match key.as_ref() {
+            keyboard::Key::Named(key::Named::Enter) => Some(Self::Submit),
+            keyboard::Key::Named(key::Named::Backspace) => {
+                Some(if is_jump_modifier_pressed {
+                    Self::BackspaceWord
+                } else {
+                    Self::Backspace
+                })
+            }
+            keyboard::Key::Named(key::Named::Delete) => {
+                Some(if is_jump_modifier_pressed {
+                    Self::DeleteWord
+                } else {
+                    Self::Delete
+                })
+            }
+            keyboard::Key::Named(key::Named::Escape) => Some(Self::Unfocus),
+            keyboard::Key::Named(
+                key::Named::Tab | key::Named::ArrowUp | key::Named::ArrowDown,
+            ) => None,
+            keyboard::Key::Character("c") if modifiers.command() => {
+                Some(Self::Copy)
+            }
+            keyboard::Key::Character("x") if modifiers.command() => {
+                Some(Self::Cut)
+            }
+            keyboard::Key::Character("v")
+                if modifiers.command() && !modifiers.alt() =>
+            {
+                Some(Self::Paste)
+            }
+            keyboard::Key::Character("a") if modifiers.command() => {
+                Some(Self::SelectAll)
+            }
+            keyboard::Key::Character("z" | "Z") if modifiers.command() => {
+                Some(if modifiers.shift() {
+                    Self::Redo
+                } else {
+                    Self::Undo
+                })
+            }
+            keyboard::Key::Character("y") if modifiers.command() => {
+                Some(Self::Redo)
+            }
+            _ => {
+                if let Some(c) = text
+                    .as_deref()
+                    .and_then(|text| text.chars().next())
+                    .filter(|c| !c.is_control())
+                {
+                    return Some(Self::Insert(c));
+                }
+
+                let motion = match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowLeft) => Motion::Left,
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        Motion::Right
+                    }
+                    keyboard::Key::Named(key::Named::Home) => Motion::Home,
+                    keyboard::Key::Named(key::Named::End) => Motion::End,
+                    keyboard::Key::Named(_) => return Some(Self::Capture),
+                    _ => return None,
+                };
+
+                let motion = if is_jump_modifier_pressed {
+                    motion.widen()
+                } else {
+                    motion
+                };
+
+                Some(if modifiers.shift() {
+                    Self::Select(motion)
+                } else {
+                    Self::Move(motion)
+                })
+            }
+        }
+    }
+}
+
+fn apply_motion(
+    cursor: &mut Cursor,
+    value: &Value,
+    motion: Motion,
+    is_secure: bool,
+    is_selecting: bool,
+) {
+    // The words of a secure value are hidden
+    let motion = match motion {
+        Motion::WordLeft if is_secure => Motion::Left,
+        Motion::WordRight if is_secure => Motion::Right,
+        motion => motion,
+    };
+
+    match motion {
+        Motion::Left if is_selecting => cursor.select_left(value),
+        Motion::Left => cursor.move_left(value),
+        Motion::Right if is_selecting => cursor.select_right(value),
+        Motion::Right => cursor.move_right(value),
+        Motion::WordLeft if is_selecting => cursor.select_left_by_words(value),
+        Motion::WordLeft => cursor.move_left_by_words(value),
+        Motion::WordRight if is_selecting => {
+            cursor.select_right_by_words(value);
+        }
+        Motion::WordRight => cursor.move_right_by_words(value),
+        Motion::Home | Motion::Up | Motion::PageUp | Motion::DocumentStart => {
+            if is_selecting {
+                cursor.select_range(cursor.start(value), 0);
+            } else {
+                cursor.move_to(0);
+            }
+        }
+        Motion::End | Motion::Down | Motion::PageDown | Motion::DocumentEnd => {
+            if is_selecting {
+                cursor.select_range(cursor.start(value), value.len());
+            } else {
+                cursor.move_to(value.len());
+            }
+        }
     }
 }
 
@@ -538,14 +1105,15 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let id = self.id.as_ref().map(|id| &id.0);
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.focusable(state, id, layout.bounds());
+        operation.text_input(state, id, layout.bounds());
     }
 
     fn on_event(
@@ -559,18 +1127,6 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
-        let update_cache = |state, value| {
-            replace_paragraph(
-                renderer,
-                state,
-                layout,
-                value,
-                self.font,
-                self.size,
-                self.line_height,
-            );
-        };
-
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -597,6 +1153,8 @@ where
                 };
 
                 if let Some(cursor_position) = click_position {
+                    state.history.break_group();
+
                     let text_layout = layout.children().next().unwrap();
                     let target = cursor_position.x - text_layout.bounds().x;
 
@@ -703,254 +1261,37 @@ where
             }) => {
                 let state = state::<Renderer>(tree);
 
-                if let Some(focus) = &mut state.is_focused {
-                    let Some(on_input) = &self.on_input else {
-                        return event::Status::Ignored;
-                    };
+                if self.on_input.is_none() {
+                    return event::Status::Ignored;
+                }
 
-                    let modifiers = state.keyboard_modifiers;
+                let status = if let Some(focus) = &mut state.is_focused {
                     focus.updated_at = Instant::now();
 
-                    match key.as_ref() {
-                        keyboard::Key::Character("c")
-                            if state.keyboard_modifiers.command()
-                                && !self.is_secure =>
-                        {
-                            if let Some((start, end)) =
-                                state.cursor.selection(&self.value)
-                            {
-                                clipboard.write(
-                                    clipboard::Kind::Standard,
-                                    self.value.select(start, end).to_string(),
-                                );
-                            }
-
-                            return event::Status::Captured;
-                        }
-                        keyboard::Key::Character("x")
-                            if state.keyboard_modifiers.command()
-                                && !self.is_secure =>
-                        {
-                            if let Some((start, end)) =
-                                state.cursor.selection(&self.value)
-                            {
-                                clipboard.write(
-                                    clipboard::Kind::Standard,
-                                    self.value.select(start, end).to_string(),
-                                );
-                            }
-
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
-
-                            update_cache(state, &self.value);
-
-                            return event::Status::Captured;
-                        }
-                        keyboard::Key::Character("v")
-                            if state.keyboard_modifiers.command()
-                                && !state.keyboard_modifiers.alt() =>
-                        {
-                            let content = match state.is_pasting.take() {
-                                Some(content) => content,
-                                None => {
-                                    let content: String = clipboard
-                                        .read(clipboard::Kind::Standard)
-                                        .unwrap_or_default()
-                                        .chars()
-                                        .filter(|c| !c.is_control())
-                                        .collect();
-
-                                    Value::new(&content)
-                                }
-                            };
-
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-
-                            editor.paste(content.clone());
-
-                            let message = if let Some(paste) = &self.on_paste {
-                                (paste)(editor.contents())
-                            } else {
-                                (on_input)(editor.contents())
-                            };
-                            shell.publish(message);
-
-                            state.is_pasting = Some(content);
-
-                            update_cache(state, &self.value);
-
-                            return event::Status::Captured;
-                        }
-                        keyboard::Key::Character("a")
-                            if state.keyboard_modifiers.command() =>
-                        {
-                            state.cursor.select_all(&self.value);
-
-                            return event::Status::Captured;
-                        }
-                        _ => {}
-                    }
-
-                    if let Some(text) = text {
-                        state.is_pasting = None;
-
-                        if let Some(c) =
-                            text.chars().next().filter(|c| !c.is_control())
-                        {
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-
-                            editor.insert(c);
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
-
-                            focus.updated_at = Instant::now();
-
-                            update_cache(state, &self.value);
-
-                            return event::Status::Captured;
-                        }
-                    }
-
-                    match key.as_ref() {
-                        keyboard::Key::Named(key::Named::Enter) => {
-                            if let Some(on_submit) = self.on_submit.clone() {
-                                shell.publish(on_submit);
-                            }
-                        }
-                        keyboard::Key::Named(key::Named::Backspace) => {
-                            if platform::is_jump_modifier_pressed(modifiers)
-                                && state.cursor.selection(&self.value).is_none()
-                            {
-                                if self.is_secure {
-                                    let cursor_pos =
-                                        state.cursor.end(&self.value);
-                                    state.cursor.select_range(0, cursor_pos);
-                                } else {
-                                    state
-                                        .cursor
-                                        .select_left_by_words(&self.value);
-                                }
-                            }
-
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.backspace();
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
-
-                            update_cache(state, &self.value);
-                        }
-                        keyboard::Key::Named(key::Named::Delete) => {
-                            if platform::is_jump_modifier_pressed(modifiers)
-                                && state.cursor.selection(&self.value).is_none()
-                            {
-                                if self.is_secure {
-                                    let cursor_pos =
-                                        state.cursor.end(&self.value);
-                                    state.cursor.select_range(
-                                        cursor_pos,
-                                        self.value.len(),
-                                    );
-                                } else {
-                                    state
-                                        .cursor
-                                        .select_right_by_words(&self.value);
-                                }
-                            }
-
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
+                    Status::Focused
+                } else if cursor.is_over(layout.bounds()) {
+                    Status::Hovered
+                } else {
+                    Status::Active
+                };
 
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                let key_press = KeyPress {
+                    key,
+                    modifiers: state.keyboard_modifiers,
+                    text,
+                    status,
+                };
 
-                            update_cache(state, &self.value);
-                        }
-                        keyboard::Key::Named(key::Named::ArrowLeft) => {
-                            if platform::is_jump_modifier_pressed(modifiers)
-                                && !self.is_secure
-                            {
-                                if modifiers.shift() {
-                                    state
-                                        .cursor
-                                        .select_left_by_words(&self.value);
-                                } else {
-                                    state
-                                        .cursor
-                                        .move_left_by_words(&self.value);
-                                }
-                            } else if modifiers.shift() {
-                                state.cursor.select_left(&self.value);
-                            } else {
-                                state.cursor.move_left(&self.value);
-                            }
-                        }
-                        keyboard::Key::Named(key::Named::ArrowRight) => {
-                            if platform::is_jump_modifier_pressed(modifiers)
-                                && !self.is_secure
-                            {
-                                if modifiers.shift() {
-                                    state
-                                        .cursor
-                                        .select_right_by_words(&self.value);
-                                } else {
-                                    state
-                                        .cursor
-                                        .move_right_by_words(&self.value);
-                                }
-                            } else if modifiers.shift() {
-                                state.cursor.select_right(&self.value);
-                            } else {
-                                state.cursor.move_right(&self.value);
-                            }
-                        }
-                        keyboard::Key::Named(key::Named::Home) => {
-                            if modifiers.shift() {
-                                state.cursor.select_range(
-                                    state.cursor.start(&self.value),
-                                    0,
-                                );
-                            } else {
-                                state.cursor.move_to(0);
-                            }
-                        }
-                        keyboard::Key::Named(key::Named::End) => {
-                            if modifiers.shift() {
-                                state.cursor.select_range(
-                                    state.cursor.start(&self.value),
-                                    self.value.len(),
-                                );
-                            } else {
-                                state.cursor.move_to(self.value.len());
-                            }
-                        }
-                        keyboard::Key::Named(key::Named::Escape) => {
-                            state.is_focused = None;
-                            state.is_dragging = false;
-                            state.is_pasting = None;
+                let binding = if let Some(key_binding) = &self.key_binding {
+                    key_binding(key_press)
+                } else {
+                    Binding::from_key_press(key_press)
+                };
 
-                            state.keyboard_modifiers =
-                                keyboard::Modifiers::default();
-                        }
-                        keyboard::Key::Named(
-                            key::Named::Tab
-                            | key::Named::ArrowUp
-                            | key::Named::ArrowDown,
-                        ) => {
-                            return event::Status::Ignored;
-                        }
-                        _ => {}
-                    }
+                if let Some(binding) = binding {
+                    self.apply_binding(
+                        binding, state, renderer, layout, clipboard, shell,
+                    );
 
                     return event::Status::Captured;
                 }
@@ -983,6 +1324,91 @@ where
 
                 state.keyboard_modifiers = modifiers;
             }
+            Event::InputMethod(event) => {
+                let state = state::<Renderer>(tree);
+
+                let Some(on_input) = &self.on_input else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(focus) = &mut state.is_focused else {
+                    return event::Status::Ignored;
+                };
+
+                match event {
+                    input_method::Event::Opened
+                    | input_method::Event::Closed => {
+                        state.preedit = None;
+                    }
+                    input_method::Event::Preedit(content, cursor) => {
+                        focus.updated_at = Instant::now();
+
+                        if content.is_empty() {
+                            state.preedit = None;
+                        } else {
+                            replace_preedit(
+                                renderer,
+                                state,
+                                layout,
+                                input_method::Preedit { content, cursor },
+                                self.font,
+                                self.size,
+                                self.line_height,
+                            );
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    input_method::Event::Commit(content) => {
+                        focus.updated_at = Instant::now();
+
+                        state.preedit = None;
+                        state.is_pasting = None;
+
+                        let content: String = content
+                            .chars()
+                            .filter(|c| !c.is_control())
+                            .collect();
+
+                        if content.is_empty() {
+                            return event::Status::Captured;
+                        }
+
+                        let before = self
+                            .has_history
+                            .then(|| (self.value.clone(), state.cursor));
+
+                        let mut editor =
+                            Editor::new(&mut self.value, &mut state.cursor);
+
+                        editor.paste(Value::new(&content));
+
+                        let message = (on_input)(editor.contents());
+                        shell.publish(message);
+
+                        if let Some((value, cursor)) = before {
+                            state.history.record(
+                                history::Kind::Other,
+                                value,
+                                cursor,
+                                &self.value,
+                            );
+                        }
+
+                        replace_paragraph(
+                            renderer,
+                            state,
+                            layout,
+                            &self.value,
+                            self.font,
+                            self.size,
+                            self.line_height,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
             Event::Window(_, window::Event::Unfocused) => {
                 let state = state::<Renderer>(tree);
 
@@ -1018,6 +1444,33 @@ where
                         ));
                     }
                 }
+
+                let is_window_focused = state
+                    .is_focused
+                    .is_some_and(|focus| focus.is_window_focused);
+
+                if is_window_focused
+                    && self.on_input.is_some()
+                    && !self.is_secure
+                {
+                    let text_bounds =
+                        layout.children().next().unwrap().bounds();
+
+                    let (position, offset) = measure_cursor_and_scroll_offset(
+                        &state.value,
+                        text_bounds,
+                        state.cursor.end(&self.value),
+                    );
+
+                    shell.request_input_method(&InputMethod::Enabled {
+                        cursor: Rectangle {
+                            x: text_bounds.x + position - offset,
+                            y: text_bounds.y,
+                            width: 1.0,
+                            height: text_bounds.height,
+                        },
+                    });
+                }
             }
             _ => {}
         }
@@ -1162,7 +1615,10 @@ pub struct State<P: text::Paragraph> {
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    history: History,
     keyboard_modifiers: keyboard::Modifiers,
+    preedit: Option<input_method::Preedit>,
+    preedit_paragraph: P,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1196,7 +1652,10 @@ impl<P: text::Paragraph> State<P> {
             is_pasting: None,
             last_click: None,
             cursor: Cursor::default(),
+            history: History::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            preedit: None,
+            preedit_paragraph: P::default(),
         }
     }
 
@@ -1332,6 +1791,75 @@ fn measure_cursor_and_scroll_offset(
     (grapheme_position.x, offset)
 }
 
+/// Draws the pre-edit text of an input method at the given position,
+/// underlining it and its cursor range.
+pub(crate) fn draw_preedit<Renderer>(
+    renderer: &mut Renderer,
+    preedit: &input_method::Preedit,
+    paragraph: &Renderer::Paragraph,
+    position: Point,
+    line_height: f32,
+    background: impl Into<Background>,
+    color: Color,
+    viewport: Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let width = paragraph.min_width();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: position.x,
+                y: position.y,
+                width,
+                height: line_height,
+            },
+            ..renderer::Quad::default()
+        },
+        background,
+    );
+
+    renderer.fill_paragraph(
+        paragraph,
+        Point::new(position.x, position.y + line_height / 2.0),
+        color,
+        viewport,
+    );
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: position.x,
+                y: position.y + line_height - 1.0,
+                width,
+                height: 1.0,
+            },
+            ..renderer::Quad::default()
+        },
+        color,
+    );
+
+    if let Some(cursor) = preedit.cursor.clone().filter(|cursor| {
+        !cursor.is_empty() && cursor.end <= preedit.content.len()
+    }) {
+        for bounds in paragraph.range_bounds(cursor) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: position.x + bounds.x,
+                        y: position.y + line_height - 2.0,
+                        width: bounds.width,
+                        height: 2.0,
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+}
+
 /// Computes the position of the text cursor at the given X coordinate of
 /// a [`TextInput`].
 fn find_cursor_position<P: text::Paragraph>(
@@ -1383,9 +1911,44 @@ fn replace_paragraph<Renderer>(
         horizontal_alignment: alignment::Horizontal::Left,
         vertical_alignment: alignment::Vertical::Top,
         shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::default(),
+        overflow: text::Overflow::default(),
     });
 }
 
+fn replace_preedit<Renderer>(
+    renderer: &Renderer,
+    state: &mut State<Renderer::Paragraph>,
+    layout: Layout<'_>,
+    preedit: input_method::Preedit,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    line_height: text::LineHeight,
+) where
+    Renderer: text::Renderer,
+{
+    let font = font.unwrap_or_else(|| renderer.default_font());
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+
+    let mut children_layout = layout.children();
+    let text_bounds = children_layout.next().unwrap().bounds();
+
+    state.preedit_paragraph = Renderer::Paragraph::with_text(Text {
+        font,
+        line_height,
+        content: &preedit.content,
+        bounds: Size::new(f32::INFINITY, text_bounds.height),
+        size: text_size,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Center,
+        shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::default(),
+        overflow: text::Overflow::default(),
+    });
+
+    state.preedit = Some(preedit);
+}
+
 const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
 
 /// The possible status of a [`TextInput`].
@@ -1487,3 +2050,155 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_test::selector::Kind;
+    use iced_test::simulator;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        NameChanged(String),
+        Save,
+    }
+
+    fn name_input() -> TextInput<'static, Message, Theme, crate::Renderer> {
+        TextInput::new("", "").on_input(Message::NameChanged)
+    }
+
+    fn last_name(messages: impl Iterator<Item = Message>) -> Option<String> {
+        messages
+            .filter_map(|message| match message {
+                Message::NameChanged(name) => Some(name),
+                Message::Save => None,
+            })
+            .last()
+    }
+
+    #[test]
+    fn it_undoes_and_redoes_typed_text() {
+        let mut ui = simulator(name_input());
+
+        let _ = ui.click(Kind::TextInput).expect("Find text input");
+        let _ = ui.type_text("Hi there");
+
+        ui.modifiers(keyboard::Modifiers::CTRL);
+        let _ = ui.press_key(keyboard::Key::Character("z".into()));
+
+        ui.modifiers(keyboard::Modifiers::CTRL | keyboard::Modifiers::SHIFT);
+        let _ = ui.press_key(keyboard::Key::Character("Z".into()));
+
+        let messages: Vec<_> = ui.into_messages().collect();
+
+        assert_eq!(
+            messages[messages.len() - 2..],
+            [
+                Message::NameChanged(String::from("Hi")),
+                Message::NameChanged(String::from("Hi there")),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_undoes_past_edits_without_changes() {
+        let mut ui = simulator(name_input());
+
+        let _ = ui.click(Kind::TextInput).expect("Find text input");
+        let _ = ui.type_text("Hi");
+        let _ = ui.press_key(key::Named::Delete);
+
+        ui.modifiers(keyboard::Modifiers::CTRL);
+        let _ = ui.press_key(keyboard::Key::Character("z".into()));
+
+        assert_eq!(last_name(ui.into_messages()), Some(String::new()));
+    }
+
+    #[test]
+    fn it_remaps_key_bindings() {
+        let input = name_input().key_binding(|key_press| {
+            match key_press.key.as_ref() {
+                keyboard::Key::Character("s")
+                    if key_press.modifiers.command() =>
+                {
+                    Some(Binding::Custom(Message::Save))
+                }
+                keyboard::Key::Character("!") => Some(Binding::Sequence(vec![
+                    Binding::Insert('!'),
+                    Binding::Move(Motion::Home),
+                    Binding::Insert('¡'),
+                ])),
+                _ => Binding::from_key_press(key_press),
+            }
+        });
+
+        let mut ui = simulator(input);
+
+        let _ = ui.click(Kind::TextInput).expect("Find text input");
+        let _ = ui.type_text("Hi!");
+
+        ui.modifiers(keyboard::Modifiers::CTRL);
+        let _ = ui.press_key(keyboard::Key::Character("s".into()));
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [
+                Message::NameChanged(String::from("H")),
+                Message::NameChanged(String::from("Hi")),
+                Message::NameChanged(String::from("Hi!")),
+                Message::NameChanged(String::from("¡Hi!")),
+                Message::Save,
+            ]
+        );
+    }
+
+    #[test]
+    fn it_captures_other_named_keys() {
+        let key_press = |key: key::Named| KeyPress {
+            key: keyboard::Key::Named(key),
+            modifiers: keyboard::Modifiers::default(),
+            text: None,
+            status: Status::Focused,
+        };
+
+        assert_eq!(
+            Binding::<()>::from_key_press(key_press(key::Named::F5)),
+            Some(Binding::Capture)
+        );
+        assert_eq!(
+            Binding::<()>::from_key_press(key_press(key::Named::ArrowUp)),
+            None
+        );
+
+        let mut ui = simulator(name_input());
+
+        let _ = ui.click(Kind::TextInput).expect("Find text input");
+
+        assert_eq!(ui.press_key(key::Named::F5), event::Status::Captured);
+        assert_eq!(ui.into_messages().count(), 0);
+    }
+
+    #[test]
+    fn it_commits_input_method_text() {
+        let mut ui = simulator(name_input());
+
+        let _ = ui.click(Kind::TextInput).expect("Find text input");
+
+        let statuses = ui.simulate([
+            Event::InputMethod(input_method::Event::Opened),
+            Event::InputMethod(input_method::Event::Preedit(
+                String::from("にほん"),
+                Some(0..9),
+            )),
+            Event::InputMethod(input_method::Event::Commit(String::from(
+                "日本",
+            ))),
+        ]);
+
+        assert_eq!(statuses[1], event::Status::Captured);
+        assert_eq!(statuses[2], event::Status::Captured);
+
+        assert_eq!(last_name(ui.into_messages()), Some(String::from("日本")));
+    }
+}
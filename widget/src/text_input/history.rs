@@ -0,0 +1,254 @@
+use crate::text_input::{Cursor, Value};
+
+use std::collections::VecDeque;
+
+/// The maximum amount of groups of edits that can be undone.
+///
+/// Every group keeps a full copy of the [`Value`], so once reached, the
+/// oldest group is forgotten.
+const LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Entry>,
+    redo: VecDeque<Entry>,
+    last: Option<Kind>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Insert { is_whitespace: bool },
+    Backspace,
+    Delete,
+    Other,
+}
+
+impl Kind {
+    fn joins(self, next: Self) -> bool {
+        match (self, next) {
+            // Typing a word right after some whitespace joins them,
+            // but whitespace after a word starts a new group.
+            (
+                Kind::Insert { is_whitespace },
+                Kind::Insert {
+                    is_whitespace: next_is_whitespace,
+                },
+            ) => is_whitespace || !next_is_whitespace,
+            (Kind::Backspace, Kind::Backspace)
+            | (Kind::Delete, Kind::Delete) => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    cursor: Cursor,
+}
+
+impl History {
+    /// Records an edit of the given [`Kind`] that turned the `before`
+    /// [`Value`] into the `after` one, alongside the [`Cursor`] before the
+    /// edit took place.
+    ///
+    /// Consecutive edits of the same [`Kind`] are grouped together, so
+    /// they can be undone at once. Edits that leave the [`Value`] unchanged
+    /// are ignored.
+    pub fn record(
+        &mut self,
+        kind: Kind,
+        before: Value,
+        cursor: Cursor,
+        after: &Value,
+    ) {
+        if before == *after {
+            return;
+        }
+
+        self.redo.clear();
+
+        let joins = self.last.is_some_and(|last| last.joins(kind));
+
+        if !joins {
+            push(
+                &mut self.undo,
+                Entry {
+                    value: before,
+                    cursor,
+                },
+            );
+        }
+
+        self.last = Some(kind);
+    }
+
+    /// Ensures the next recorded edit starts a new group.
+    pub fn break_group(&mut self) {
+        self.last = None;
+    }
+
+    /// Restores the [`Value`] and [`Cursor`] saved before the last group
+    /// of edits.
+    ///
+    /// Returns whether anything was restored.
+    pub fn undo(&mut self, value: &mut Value, cursor: &mut Cursor) -> bool {
+        self.last = None;
+
+        restore(&mut self.undo, &mut self.redo, value, cursor)
+    }
+
+    /// Reapplies the last group of edits reverted with [`History::undo`].
+    ///
+    /// Returns whether anything was restored.
+    pub fn redo(&mut self, value: &mut Value, cursor: &mut Cursor) -> bool {
+        self.last = None;
+
+        restore(&mut self.redo, &mut self.undo, value, cursor)
+    }
+}
+
+fn push(stack: &mut VecDeque<Entry>, entry: Entry) {
+    if stack.len() == LIMIT {
+        let _ = stack.pop_front();
+    }
+
+    stack.push_back(entry);
+}
+
+fn restore(
+    from: &mut VecDeque<Entry>,
+    to: &mut VecDeque<Entry>,
+    value: &mut Value,
+    cursor: &mut Cursor,
+) -> bool {
+    let Some(entry) = from.pop_back() else {
+        return false;
+    };
+
+    push(
+        to,
+        Entry {
+            value: std::mem::replace(value, entry.value),
+            cursor: std::mem::replace(cursor, entry.cursor),
+        },
+    );
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(history: &mut History, value: &mut Value, text: &str) {
+        for c in text.chars() {
+            let before = value.clone();
+            value.insert(value.len(), c);
+
+            history.record(
+                Kind::Insert {
+                    is_whitespace: c.is_whitespace(),
+                },
+                before,
+                Cursor::default(),
+                value,
+            );
+        }
+    }
+
+    fn backspace(history: &mut History, value: &mut Value) {
+        let before = value.clone();
+
+        if !value.is_empty() {
+            value.remove(value.len() - 1);
+        }
+
+        history.record(Kind::Backspace, before, Cursor::default(), value);
+    }
+
+    fn undo(history: &mut History, value: &mut Value) -> bool {
+        history.undo(value, &mut Cursor::default())
+    }
+
+    #[test]
+    fn it_groups_words_and_their_whitespace() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+
+        insert(&mut history, &mut value, "hello world");
+
+        assert!(undo(&mut history, &mut value));
+        assert_eq!(value.to_string(), "hello");
+
+        assert!(undo(&mut history, &mut value));
+        assert_eq!(value.to_string(), "");
+
+        assert!(!undo(&mut history, &mut value));
+    }
+
+    #[test]
+    fn it_groups_consecutive_backspaces() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+
+        insert(&mut history, &mut value, "abc");
+        history.break_group();
+
+        backspace(&mut history, &mut value);
+        backspace(&mut history, &mut value);
+
+        assert_eq!(value.to_string(), "a");
+
+        assert!(undo(&mut history, &mut value));
+        assert_eq!(value.to_string(), "abc");
+    }
+
+    #[test]
+    fn it_ignores_edits_without_changes() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+
+        backspace(&mut history, &mut value);
+
+        assert!(!undo(&mut history, &mut value));
+
+        insert(&mut history, &mut value, "a");
+        history.break_group();
+        backspace(&mut history, &mut value);
+        backspace(&mut history, &mut value);
+
+        assert!(undo(&mut history, &mut value));
+        assert_eq!(value.to_string(), "a");
+    }
+
+    #[test]
+    fn it_forgets_redone_edits_after_a_new_one() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+
+        insert(&mut history, &mut value, "a");
+
+        assert!(undo(&mut history, &mut value));
+
+        insert(&mut history, &mut value, "b");
+
+        assert!(!history.redo(&mut value, &mut Cursor::default()));
+        assert_eq!(value.to_string(), "b");
+    }
+
+    #[test]
+    fn it_forgets_the_oldest_groups() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+
+        for _ in 0..LIMIT + 1 {
+            insert(&mut history, &mut value, "a");
+            history.break_group();
+        }
+
+        while undo(&mut history, &mut value) {}
+
+        assert_eq!(value.to_string(), "a");
+    }
+}
@@ -1,8 +1,19 @@
 //! Create interactive, native cross-platform applications.
 mod state;
 
+// Input sessions can only be recorded and replayed when the `session` feature
+// is enabled.
+#[cfg(feature = "session")]
+#[path = "application/session/env.rs"]
+pub(crate) mod session;
+#[cfg(not(feature = "session"))]
+#[path = "application/session/null.rs"]
+pub(crate) mod session;
+
 pub use state::State;
 
+pub use crate::runtime::program::{default, Appearance, DefaultStyle};
+
 use crate::conversion;
 use crate::core;
 use crate::core::mouse;
@@ -10,7 +21,7 @@ use crate::core::renderer;
 use crate::core::time::Instant;
 use crate::core::widget::operation;
 use crate::core::window;
-use crate::core::{Color, Event, Point, Size, Theme};
+use crate::core::{Event, InputMethod, Point, Size};
 use crate::futures::futures;
 use crate::futures::{Executor, Runtime, Subscription};
 use crate::graphics;
@@ -23,6 +34,7 @@ use crate::{Clipboard, Error, Proxy, Settings};
 
 use futures::channel::mpsc;
 
+use std::any::Any;
 use std::mem::ManuallyDrop;
 use std::sync::Arc;
 
@@ -36,7 +48,11 @@ use std::sync::Arc;
 /// [`Command`] in some of its methods.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. While visible, `F9` pauses and resumes the
+/// processing of messages, `F10` steps through paused messages, `F7` and `F8`
+/// travel back and forth in time (see [`snapshot`](Self::snapshot)), and `F6`
+/// exports the message log to `iced_messages.log`. `F11` toggles the layout
+/// inspector, which overlays the bounds of every widget.
 pub trait Application: Program
 where
     Self::Theme: DefaultStyle,
@@ -93,38 +109,21 @@ where
     fn scale_factor(&self) -> f64 {
         1.0
     }
-}
-
-/// The appearance of an application.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Appearance {
-    /// The background [`Color`] of the application.
-    pub background_color: Color,
-
-    /// The default text [`Color`] of the application.
-    pub text_color: Color,
-}
-
-/// The default style of an [`Application`].
-pub trait DefaultStyle {
-    /// Returns the default style of an [`Application`].
-    fn default_style(&self) -> Appearance;
-}
 
-impl DefaultStyle for Theme {
-    fn default_style(&self) -> Appearance {
-        default(self)
+    /// Returns a snapshot of the current state of the [`Application`], if
+    /// it supports time travel.
+    ///
+    /// When the `debug` feature is enabled, snapshots are taken after every
+    /// message and can be restored from the debug view.
+    ///
+    /// By default, it returns `None`.
+    fn snapshot(&self) -> Option<Box<dyn Any>> {
+        None
     }
-}
-
-/// The default [`Appearance`] of an [`Application`] with the built-in [`Theme`].
-pub fn default(theme: &Theme) -> Appearance {
-    let palette = theme.extended_palette();
 
-    Appearance {
-        background_color: palette.background.base.color,
-        text_color: palette.background.base.text,
-    }
+    /// Restores the [`Application`] to a snapshot previously returned by
+    /// [`snapshot`](Self::snapshot).
+    fn restore(&mut self, _snapshot: &dyn Any) {}
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -143,6 +142,8 @@ where
     use futures::Future;
     use winit::event_loop::EventLoopBuilder;
 
+    crate::trace::init();
+
     let mut debug = Debug::new();
     debug.startup_started();
 
@@ -326,6 +327,9 @@ async fn run_instance<A, E, C>(
     use winit::event;
     use winit::event_loop::ControlFlow;
 
+    let startup =
+        tracing::info_span!("startup", window = ?window::Id::MAIN).entered();
+
     let mut state = State::new(&application, &window);
     let mut viewport_version = state.viewport_version();
     let physical_size = state.physical_size();
@@ -369,12 +373,16 @@ async fn run_instance<A, E, C>(
     ));
 
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut input_method = InputMethod::Disabled;
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut user_events = 0;
     let mut redraw_pending = false;
+    let mut session = session::Session::from_env();
 
+    debug.log_snapshot(|| application.snapshot());
     debug.startup_finished();
+    drop(startup);
 
     while let Some(event) = event_receiver.next().await {
         match event {
@@ -390,11 +398,13 @@ async fn run_instance<A, E, C>(
             )) => {
                 use crate::core::event;
 
-                events.push(Event::PlatformSpecific(
-                    event::PlatformSpecific::MacOS(event::MacOS::ReceivedUrl(
-                        url,
-                    )),
-                ));
+                let event =
+                    Event::PlatformSpecific(event::PlatformSpecific::MacOS(
+                        event::MacOS::ReceivedUrl(url),
+                    ));
+
+                session.record(None, &event);
+                events.push(event);
             }
             event::Event::UserEvent(message) => {
                 messages.push(message);
@@ -410,11 +420,16 @@ async fn run_instance<A, E, C>(
                     continue;
                 }
 
+                let _frame =
+                    tracing::info_span!("frame", window = ?window::Id::MAIN)
+                        .entered();
+
                 let current_viewport_version = state.viewport_version();
 
                 if viewport_version != current_viewport_version {
                     let logical_size = state.logical_size();
 
+                    let _span = tracing::info_span!("layout").entered();
                     debug.layout_started();
                     user_interface = ManuallyDrop::new(
                         ManuallyDrop::into_inner(user_interface)
@@ -449,9 +464,23 @@ async fn run_instance<A, E, C>(
                     &mut messages,
                 );
 
+                if let user_interface::State::Updated {
+                    input_method: requested,
+                    ..
+                } = interface_state
+                {
+                    conversion::update_input_method(
+                        &window,
+                        &mut input_method,
+                        requested,
+                        state.scale_factor(),
+                    );
+                }
+
                 let _ = control_sender.start_send(match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
+                        ..
                     } => match redraw_request {
                         window::RedrawRequest::NextFrame => {
                             window.request_redraw();
@@ -467,6 +496,7 @@ async fn run_instance<A, E, C>(
 
                 runtime.broadcast(redraw_event, core::event::Status::Ignored);
 
+                let draw = tracing::info_span!("draw").entered();
                 debug.draw_started();
                 let new_mouse_interaction = user_interface.draw(
                     &mut renderer,
@@ -476,8 +506,14 @@ async fn run_instance<A, E, C>(
                     },
                     state.cursor(),
                 );
+
+                if debug.is_inspecting() {
+                    user_interface.inspect(&mut renderer, state.cursor());
+                }
+
                 redraw_pending = false;
                 debug.draw_finished();
+                drop(draw);
 
                 if new_mouse_interaction != mouse_interaction {
                     window.set_cursor_icon(conversion::mouse_interaction(
@@ -487,6 +523,7 @@ async fn run_instance<A, E, C>(
                     mouse_interaction = new_mouse_interaction;
                 }
 
+                let _span = tracing::info_span!("render").entered();
                 debug.render_started();
                 match compositor.present(
                     &mut renderer,
@@ -527,20 +564,56 @@ async fn run_instance<A, E, C>(
 
                 state.update(&window, &window_event, &mut debug);
 
+                if let Some(snapshot) = debug.time_travel() {
+                    let cache =
+                        ManuallyDrop::into_inner(user_interface).into_cache();
+
+                    application.restore(snapshot);
+                    state.synchronize(&application, &window);
+                    runtime.track(application.subscription().into_recipes());
+
+                    user_interface = ManuallyDrop::new(build_user_interface(
+                        &application,
+                        cache,
+                        &mut renderer,
+                        state.logical_size(),
+                        &mut debug,
+                    ));
+
+                    window.request_redraw();
+                }
+
                 if let Some(event) = conversion::window_event(
                     window::Id::MAIN,
                     window_event,
                     state.scale_factor(),
                     state.modifiers(),
                 ) {
+                    session.record(None, &event);
                     events.push(event);
                 }
             }
             event::Event::AboutToWait => {
+                for (_, event) in session.replay() {
+                    state.replay(&event);
+                    events.push(event);
+                }
+
+                if let Some(next_at) = session.next_at() {
+                    let _ = control_sender
+                        .start_send(ControlFlow::WaitUntil(next_at));
+                }
+
                 if events.is_empty() && messages.is_empty() {
                     continue;
                 }
 
+                let span = tracing::info_span!(
+                    "event_processing",
+                    window = ?window::Id::MAIN,
+                    events = events.len(),
+                )
+                .entered();
                 debug.event_processing_started();
 
                 let (interface_state, statuses) = user_interface.update(
@@ -552,6 +625,7 @@ async fn run_instance<A, E, C>(
                 );
 
                 debug.event_processing_finished();
+                drop(span);
 
                 for (event, status) in
                     events.drain(..).zip(statuses.into_iter())
@@ -612,6 +686,8 @@ async fn run_instance<A, E, C>(
         }
     }
 
+    session.finish();
+
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
 }
@@ -652,9 +728,19 @@ pub fn build_user_interface<'a, A: Application>(
 where
     A::Theme: DefaultStyle,
 {
-    debug.view_started();
-    let view = application.view();
-    debug.view_finished();
+    let view = {
+        let _span =
+            tracing::info_span!("view", window = ?window::Id::MAIN).entered();
+
+        debug.view_started();
+        let view = application.view();
+        debug.view_finished();
+
+        view
+    };
+
+    let _span =
+        tracing::info_span!("layout", window = ?window::Id::MAIN).entered();
 
     debug.layout_started();
     let user_interface = UserInterface::build(view, size, cache, renderer);
@@ -683,12 +769,17 @@ pub fn update<A: Application, C, E: Executor>(
     C: Compositor<Renderer = A::Renderer> + 'static,
     A::Theme: DefaultStyle,
 {
-    for message in messages.drain(..) {
+    let allowed = debug.allowed_messages(messages.len());
+
+    for message in messages.drain(..allowed) {
         debug.log_message(&message);
 
+        let span = tracing::info_span!("update").entered();
         debug.update_started();
         let command = runtime.enter(|| application.update(message));
         debug.update_finished();
+        drop(span);
+        debug.log_snapshot(|| application.snapshot());
 
         run_command(
             application,
@@ -735,6 +826,7 @@ pub fn run_command<A, C, E>(
     A::Theme: DefaultStyle,
 {
     use crate::runtime::command;
+    use crate::runtime::font;
     use crate::runtime::system;
     use crate::runtime::window;
 
@@ -936,6 +1028,20 @@ pub fn run_command<A, C, E>(
 
                 proxy.send(tagger(Ok(())));
             }
+            command::Action::LoadFontFile { path, tagger } => {
+                let result =
+                    compositor.load_font_file(&path).map_err(|error| {
+                        font::Error::Io {
+                            path,
+                            error: Arc::new(error),
+                        }
+                    });
+
+                proxy.send(tagger(result));
+            }
+            command::Action::ListFonts(tagger) => {
+                proxy.send(tagger(compositor.font_faces()));
+            }
             command::Action::Custom(_) => {
                 log::warn!("Unsupported custom action in `iced_winit` shell");
             }
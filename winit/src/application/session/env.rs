@@ -0,0 +1,63 @@
+use crate::core::time::Instant;
+use crate::core::window;
+use crate::core::Event;
+use crate::runtime::session::{self, Recorder, Replay};
+
+use std::env;
+use std::path::PathBuf;
+
+/// Records or replays the input session of an application, as configured by
+/// the `ICED_RECORD` and `ICED_REPLAY` environment variables.
+#[derive(Debug, Default)]
+pub struct Session {
+    recording: Option<(Recorder, PathBuf)>,
+    replay: Option<Replay>,
+}
+
+impl Session {
+    pub fn from_env() -> Self {
+        let recording = env::var_os("ICED_RECORD")
+            .map(|path| (Recorder::new(), PathBuf::from(path)));
+
+        let replay = env::var_os("ICED_REPLAY").and_then(|path| {
+            match session::Session::load(&path) {
+                Ok(session) => Some(Replay::new(session)),
+                Err(error) => {
+                    log::error!(
+                        "Failed to load session from {path:?}: {error}"
+                    );
+
+                    None
+                }
+            }
+        });
+
+        Self { recording, replay }
+    }
+
+    pub fn record(&mut self, window: Option<window::Id>, event: &Event) {
+        if let Some((recorder, _)) = &mut self.recording {
+            recorder.record(window, event);
+        }
+    }
+
+    pub fn replay(
+        &mut self,
+    ) -> impl Iterator<Item = (Option<window::Id>, Event)> + '_ {
+        self.replay
+            .iter_mut()
+            .flat_map(|replay| replay.due(Instant::now()))
+    }
+
+    pub fn next_at(&self) -> Option<Instant> {
+        self.replay.as_ref().and_then(Replay::next_at)
+    }
+
+    pub fn finish(self) {
+        if let Some((recorder, path)) = self.recording {
+            if let Err(error) = recorder.finish().save(&path) {
+                log::error!("Failed to save session to {path:?}: {error}");
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::core::time::Instant;
+use crate::core::window;
+use crate::core::Event;
+
+#[derive(Debug, Default)]
+pub struct Session;
+
+impl Session {
+    pub fn from_env() -> Self {
+        Self
+    }
+
+    pub fn record(&mut self, _window: Option<window::Id>, _event: &Event) {}
+
+    pub fn replay(
+        &mut self,
+    ) -> impl Iterator<Item = (Option<window::Id>, Event)> + '_ {
+        std::iter::empty()
+    }
+
+    pub fn next_at(&self) -> Option<Instant> {
+        None
+    }
+
+    pub fn finish(self) {}
+}
@@ -2,11 +2,12 @@
 //!
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_runtime`]: https://github.com/iced-rs/iced/tree/0.12/runtime
+use crate::core::input_method;
 use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::touch;
 use crate::core::window;
-use crate::core::{Event, Point, Size};
+use crate::core::{Event, InputMethod, Point, Rectangle, Size};
 
 /// Converts some [`window::Settings`] into a `WindowBuilder` from `winit`.
 pub fn window_settings(
@@ -294,10 +295,74 @@ pub fn window_event(
 
             Some(Event::Window(id, window::Event::Moved { x, y }))
         }
+        WindowEvent::Ime(ime) => {
+            Some(Event::InputMethod(self::input_method_event(ime)))
+        }
         _ => None,
     }
 }
 
+/// Converts a winit `Ime` event into an iced input method event.
+pub fn input_method_event(ime: winit::event::Ime) -> input_method::Event {
+    use winit::event::Ime;
+
+    match ime {
+        Ime::Enabled => input_method::Event::Opened,
+        Ime::Preedit(content, cursor) => input_method::Event::Preedit(
+            content,
+            cursor.map(|(start, end)| start..end),
+        ),
+        Ime::Commit(content) => input_method::Event::Commit(content),
+        Ime::Disabled => input_method::Event::Closed,
+    }
+}
+
+/// Applies the given [`InputMethod`] request to a winit window.
+///
+/// The `current` request is updated and the window is only touched when the
+/// request changes.
+pub fn update_input_method(
+    window: &winit::window::Window,
+    current: &mut InputMethod,
+    requested: InputMethod,
+    scale_factor: f64,
+) {
+    if *current == requested {
+        return;
+    }
+
+    match requested {
+        InputMethod::Disabled => {
+            window.set_ime_allowed(false);
+        }
+        InputMethod::Enabled { cursor } => {
+            if let InputMethod::Disabled = current {
+                window.set_ime_allowed(true);
+            }
+
+            let Rectangle {
+                x,
+                y,
+                width,
+                height,
+            } = cursor;
+
+            window.set_ime_cursor_area(
+                winit::dpi::PhysicalPosition::new(
+                    f64::from(x) * scale_factor,
+                    f64::from(y) * scale_factor,
+                ),
+                winit::dpi::PhysicalSize::new(
+                    f64::from(width) * scale_factor,
+                    f64::from(height) * scale_factor,
+                ),
+            );
+        }
+    }
+
+    *current = requested;
+}
+
 /// Converts a [`window::Level`] to a [`winit`] window level.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -38,6 +38,7 @@ pub mod system;
 
 mod error;
 mod proxy;
+mod trace;
 
 #[cfg(feature = "application")]
 pub use application::Application;
@@ -4,6 +4,7 @@ mod window_manager;
 
 pub use state::State;
 
+use crate::application::session;
 use crate::conversion;
 use crate::core;
 use crate::core::mouse;
@@ -28,6 +29,7 @@ use crate::{Clipboard, Error, Proxy, Settings};
 pub use crate::application::{default, Appearance, DefaultStyle};
 
 use rustc_hash::FxHashMap;
+use std::any::Any;
 use std::mem::ManuallyDrop;
 use std::sync::Arc;
 use std::time::Instant;
@@ -42,7 +44,10 @@ use std::time::Instant;
 /// [`Command`] in some of its methods.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. While visible, `F9` pauses and resumes the
+/// processing of messages, `F10` steps through paused messages, `F7` and `F8`
+/// travel back and forth in time (see [`snapshot`](Self::snapshot)), and `F6`
+/// exports the message log to `iced_messages.log`.
 pub trait Application: Program
 where
     Self::Theme: DefaultStyle,
@@ -100,6 +105,21 @@ where
     fn scale_factor(&self, window: window::Id) -> f64 {
         1.0
     }
+
+    /// Returns a snapshot of the current state of the [`Application`], if
+    /// it supports time travel.
+    ///
+    /// When the `debug` feature is enabled, snapshots are taken after every
+    /// message and can be restored from the debug view.
+    ///
+    /// By default, it returns `None`.
+    fn snapshot(&self) -> Option<Box<dyn Any>> {
+        None
+    }
+
+    /// Restores the [`Application`] to a snapshot previously returned by
+    /// [`snapshot`](Self::snapshot).
+    fn restore(&mut self, _snapshot: &dyn Any) {}
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -116,6 +136,8 @@ where
 {
     use winit::event_loop::EventLoopBuilder;
 
+    crate::trace::init();
+
     let mut debug = Debug::new();
     debug.startup_started();
 
@@ -359,6 +381,8 @@ async fn run_instance<A, E, C>(
     use winit::event;
     use winit::event_loop::ControlFlow;
 
+    let startup = tracing::info_span!("startup").entered();
+
     let main_window = window_manager
         .get_mut(window::Id::MAIN)
         .expect("Get main window");
@@ -409,8 +433,10 @@ async fn run_instance<A, E, C>(
 
     let mut messages = Vec::new();
     let mut user_events = 0;
+    let mut session = session::Session::from_env();
 
     debug.startup_finished();
+    drop(startup);
 
     'main: while let Some(event) = event_receiver.next().await {
         match event {
@@ -472,14 +498,14 @@ async fn run_instance<A, E, C>(
                     ) => {
                         use crate::core::event;
 
-                        events.push((
-                            None,
-                            event::Event::PlatformSpecific(
-                                event::PlatformSpecific::MacOS(
-                                    event::MacOS::ReceivedUrl(url),
-                                ),
+                        let event = event::Event::PlatformSpecific(
+                            event::PlatformSpecific::MacOS(
+                                event::MacOS::ReceivedUrl(url),
                             ),
-                        ));
+                        );
+
+                        session.record(None, &event);
+                        events.push((None, event));
                     }
                     event::Event::UserEvent(message) => {
                         messages.push(message);
@@ -496,6 +522,9 @@ async fn run_instance<A, E, C>(
                             continue;
                         };
 
+                        let _frame = tracing::info_span!("frame", window = ?id)
+                            .entered();
+
                         // TODO: Avoid redrawing all the time by forcing widgets to
                         // request redraws on state changes
                         //
@@ -520,6 +549,7 @@ async fn run_instance<A, E, C>(
                             &mut messages,
                         );
 
+                        let draw = tracing::info_span!("draw").entered();
                         debug.draw_started();
                         let new_mouse_interaction = ui.draw(
                             &mut window.renderer,
@@ -529,7 +559,13 @@ async fn run_instance<A, E, C>(
                             },
                             cursor,
                         );
+
+                        if debug.is_inspecting() {
+                            ui.inspect(&mut window.renderer, cursor);
+                        }
+
                         debug.draw_finished();
+                        drop(draw);
 
                         if new_mouse_interaction != window.mouse_interaction {
                             window.raw.set_cursor_icon(
@@ -546,10 +582,24 @@ async fn run_instance<A, E, C>(
                             core::event::Status::Ignored,
                         );
 
+                        if let user_interface::State::Updated {
+                            input_method,
+                            ..
+                        } = ui_state
+                        {
+                            conversion::update_input_method(
+                                &window.raw,
+                                &mut window.input_method,
+                                input_method,
+                                window.state.scale_factor(),
+                            );
+                        }
+
                         let _ = control_sender.start_send(Control::ChangeFlow(
                             match ui_state {
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
+                                    ..
                                 } => match redraw_request {
                                     window::RedrawRequest::NextFrame => {
                                         window.raw.request_redraw();
@@ -576,6 +626,8 @@ async fn run_instance<A, E, C>(
                         {
                             let logical_size = window.state.logical_size();
 
+                            let layout =
+                                tracing::info_span!("layout").entered();
                             debug.layout_started();
                             let ui = user_interfaces
                                 .remove(&id)
@@ -586,7 +638,9 @@ async fn run_instance<A, E, C>(
                                 ui.relayout(logical_size, &mut window.renderer),
                             );
                             debug.layout_finished();
+                            drop(layout);
 
+                            let draw = tracing::info_span!("draw").entered();
                             debug.draw_started();
                             let new_mouse_interaction = user_interfaces
                                 .get_mut(&id)
@@ -600,6 +654,7 @@ async fn run_instance<A, E, C>(
                                     window.state.cursor(),
                                 );
                             debug.draw_finished();
+                            drop(draw);
 
                             if new_mouse_interaction != window.mouse_interaction
                             {
@@ -623,6 +678,7 @@ async fn run_instance<A, E, C>(
                                 window.state.viewport_version();
                         }
 
+                        let _span = tracing::info_span!("render").entered();
                         debug.render_started();
                         match compositor.present(
                             &mut window.renderer,
@@ -700,15 +756,71 @@ async fn run_instance<A, E, C>(
                                 window.state.scale_factor(),
                                 window.state.modifiers(),
                             ) {
+                                session.record(Some(id), &event);
                                 events.push((Some(id), event));
                             }
                         }
+
+                        if let Some(snapshot) = debug.time_travel() {
+                            let cached_interfaces: FxHashMap<
+                                window::Id,
+                                user_interface::Cache,
+                            > = ManuallyDrop::into_inner(user_interfaces)
+                                .drain()
+                                .map(|(id, ui)| (id, ui.into_cache()))
+                                .collect();
+
+                            application.restore(snapshot);
+                            runtime.track(
+                                application.subscription().into_recipes(),
+                            );
+
+                            for (id, window) in window_manager.iter_mut() {
+                                window.state.synchronize(
+                                    &application,
+                                    id,
+                                    &window.raw,
+                                );
+
+                                window.raw.request_redraw();
+                            }
+
+                            user_interfaces =
+                                ManuallyDrop::new(build_user_interfaces(
+                                    &application,
+                                    &mut debug,
+                                    &mut window_manager,
+                                    cached_interfaces,
+                                ));
+                        }
                     }
                     event::Event::AboutToWait => {
+                        for (id, event) in session.replay() {
+                            if let Some(window) =
+                                id.and_then(|id| window_manager.get_mut(id))
+                            {
+                                window.state.replay(&event);
+                            }
+
+                            events.push((id, event));
+                        }
+
+                        if let Some(next_at) = session.next_at() {
+                            let _ =
+                                control_sender.start_send(Control::ChangeFlow(
+                                    ControlFlow::WaitUntil(next_at),
+                                ));
+                        }
+
                         if events.is_empty() && messages.is_empty() {
                             continue;
                         }
 
+                        let span = tracing::info_span!(
+                            "event_processing",
+                            events = events.len(),
+                        )
+                        .entered();
                         debug.event_processing_started();
                         let mut uis_stale = false;
 
@@ -729,6 +841,10 @@ async fn run_instance<A, E, C>(
                                 continue;
                             }
 
+                            let _span =
+                                tracing::info_span!("window", window = ?id)
+                                    .entered();
+
                             let (ui_state, statuses) = user_interfaces
                                 .get_mut(&id)
                                 .expect("Get user interface")
@@ -758,6 +874,7 @@ async fn run_instance<A, E, C>(
                         }
 
                         debug.event_processing_finished();
+                        drop(span);
 
                         // TODO mw application update returns which window IDs to update
                         if !messages.is_empty() || uis_stale {
@@ -818,6 +935,8 @@ async fn run_instance<A, E, C>(
         }
     }
 
+    session.finish();
+
     let _ = ManuallyDrop::into_inner(user_interfaces);
 }
 
@@ -833,9 +952,17 @@ fn build_user_interface<'a, A: Application>(
 where
     A::Theme: DefaultStyle,
 {
-    debug.view_started();
-    let view = application.view(id);
-    debug.view_finished();
+    let view = {
+        let _span = tracing::info_span!("view", window = ?id).entered();
+
+        debug.view_started();
+        let view = application.view(id);
+        debug.view_finished();
+
+        view
+    };
+
+    let _span = tracing::info_span!("layout", window = ?id).entered();
 
     debug.layout_started();
     let user_interface = UserInterface::build(view, size, cache, renderer);
@@ -861,12 +988,18 @@ fn update<A: Application, C, E: Executor>(
     C: Compositor<Renderer = A::Renderer> + 'static,
     A::Theme: DefaultStyle,
 {
-    for message in messages.drain(..) {
+    let allowed = debug.allowed_messages(messages.len());
+
+    for message in messages.drain(..allowed) {
         debug.log_message(&message);
+
+        let span = tracing::info_span!("update").entered();
         debug.update_started();
 
         let command = runtime.enter(|| application.update(message));
         debug.update_finished();
+        debug.log_snapshot(|| application.snapshot());
+        drop(span);
 
         run_command(
             application,
@@ -905,6 +1038,7 @@ fn run_command<A, C, E>(
     A::Theme: DefaultStyle,
 {
     use crate::runtime::clipboard;
+    use crate::runtime::font;
     use crate::runtime::system;
     use crate::runtime::window;
 
@@ -1182,6 +1316,20 @@ fn run_command<A, C, E>(
 
                 proxy.send(tagger(Ok(())));
             }
+            command::Action::LoadFontFile { path, tagger } => {
+                let result =
+                    compositor.load_font_file(&path).map_err(|error| {
+                        font::Error::Io {
+                            path,
+                            error: Arc::new(error),
+                        }
+                    });
+
+                proxy.send(tagger(result));
+            }
+            command::Action::ListFonts(tagger) => {
+                proxy.send(tagger(compositor.font_faces()));
+            }
             command::Action::Custom(_) => {
                 log::warn!("Unsupported custom action in `iced_winit` shell");
             }
@@ -182,15 +182,75 @@ where
             WindowEvent::KeyboardInput {
                 event:
                     winit::event::KeyEvent {
-                        logical_key:
-                            winit::keyboard::Key::Named(
-                                winit::keyboard::NamedKey::F12,
-                            ),
+                        logical_key: winit::keyboard::Key::Named(key),
                         state: winit::event::ElementState::Pressed,
                         ..
                     },
                 ..
-            } => _debug.toggle(),
+            } => {
+                use winit::keyboard::NamedKey;
+
+                match key {
+                    NamedKey::F12 => _debug.toggle(),
+                    NamedKey::F11 => _debug.toggle_inspector(),
+                    NamedKey::F9 => _debug.toggle_pause(),
+                    NamedKey::F10 => _debug.step(),
+                    NamedKey::F7 => _debug.travel_back(),
+                    NamedKey::F8 => _debug.travel_forward(),
+                    NamedKey::F6 => {
+                        if let Err(error) = _debug.export("iced_messages.log") {
+                            log::error!(
+                                "Failed to export message log: {error}"
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Processes an [`Event`] replayed from a recorded session and updates
+    /// the [`State`] accordingly.
+    ///
+    /// [`Event`]: crate::core::Event
+    pub fn replay(&mut self, event: &crate::core::Event) {
+        use crate::core::keyboard;
+        use crate::core::touch;
+        use crate::core::Event;
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(
+                touch::Event::FingerPressed { position, .. }
+                | touch::Event::FingerMoved { position, .. }
+                | touch::Event::FingerLifted { position, .. }
+                | touch::Event::FingerLost { position, .. },
+            ) => {
+                self.cursor_position = Some(
+                    winit::dpi::LogicalPosition::new(
+                        f64::from(position.x),
+                        f64::from(position.y),
+                    )
+                    .to_physical(self.viewport.scale_factor()),
+                );
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                self.cursor_position = None;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                use winit::keyboard::ModifiersState;
+
+                let mut state = ModifiersState::empty();
+
+                state.set(ModifiersState::SHIFT, modifiers.shift());
+                state.set(ModifiersState::CONTROL, modifiers.control());
+                state.set(ModifiersState::ALT, modifiers.alt());
+                state.set(ModifiersState::SUPER, modifiers.logo());
+
+                self.modifiers = state;
+            }
             _ => {}
         }
     }
@@ -1,6 +1,6 @@
 use crate::core::mouse;
 use crate::core::window::Id;
-use crate::core::{Point, Size};
+use crate::core::{InputMethod, Point, Size};
 use crate::graphics::Compositor;
 use crate::multi_window::{Application, DefaultStyle, State};
 
@@ -61,6 +61,7 @@ where
                 surface,
                 renderer,
                 mouse_interaction: mouse::Interaction::None,
+                input_method: InputMethod::Disabled,
             },
         );
 
@@ -127,6 +128,7 @@ where
     pub viewport_version: u64,
     pub exit_on_close_request: bool,
     pub mouse_interaction: mouse::Interaction,
+    pub input_method: InputMethod,
     pub surface: C::Surface,
     pub renderer: A::Renderer,
 }
@@ -0,0 +1,34 @@
+//! Write the runtime spans of an application to a Chrome trace file, as
+//! configured by the `ICED_TRACE` environment variable.
+//!
+//! The spans are emitted regardless; any [`tracing`] subscriber installed by
+//! the application will receive them.
+
+/// Installs a [`Chrome`](crate::runtime::trace::Chrome) subscriber writing to
+/// the path in the `ICED_TRACE` environment variable, if present.
+#[cfg(feature = "trace")]
+pub fn init() {
+    use crate::runtime::trace::Chrome;
+
+    let Some(path) = std::env::var_os("ICED_TRACE") else {
+        return;
+    };
+
+    match Chrome::create(&path) {
+        Ok(chrome) => {
+            if tracing::subscriber::set_global_default(chrome).is_err() {
+                log::warn!(
+                    "Failed to trace to {path:?}: \
+                    a global subscriber is already installed"
+                );
+            }
+        }
+        Err(error) => {
+            log::error!("Failed to create trace file {path:?}: {error}");
+        }
+    }
+}
+
+/// Does nothing, since the `trace` feature is disabled.
+#[cfg(not(feature = "trace"))]
+pub fn init() {}